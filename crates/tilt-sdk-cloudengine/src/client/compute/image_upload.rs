@@ -0,0 +1,468 @@
+use super::ComputeClient;
+use crate::client::request::ServiceClient;
+use crate::client::resource::CloudResource;
+use crate::client::responses::{ImageUploadOffsetResponse, ImageUploadWrapper};
+use crate::error::{ComputeError, Result, Service};
+use crate::models::{ImageUpload, ImageUploadId, ImageUploadStatus};
+use crate::operations::{OperationError, WaitConfig, WaitStatus, wait_until};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tilt_sdk::SdkError;
+use tilt_sdk::error::UnexpectedError;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt};
+use tracing::{Instrument, debug, info_span, warn};
+
+const IMAGE_UPLOAD_SERVICE: Service = Service::ComputeApi;
+
+const DEFAULT_CHUNK_SIZE: usize = 8 * 1024 * 1024;
+const DEFAULT_MAX_RETRIES: u32 = 5;
+const DEFAULT_RETRY_DELAY: Duration = Duration::from_secs(2);
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(5);
+const DEFAULT_POLL_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DiskFormat {
+    #[serde(rename = "qcow2")]
+    Qcow2,
+    #[serde(rename = "raw")]
+    Raw,
+    #[serde(rename = "vmdk")]
+    Vmdk,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageUploadRequest {
+    pub name: String,
+    pub disk_format: DiskFormat,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub os_distro: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub os_version: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct CreateImageUploadRequest<'r> {
+    #[serde(flatten)]
+    image: &'r ImageUploadRequest,
+    size: u64,
+    checksum: &'r str,
+    checksum_algorithm: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+struct ImportImageRequest<'r> {
+    #[serde(flatten)]
+    image: &'r ImageUploadRequest,
+    source_url: &'r str,
+}
+
+#[derive(Debug, Serialize)]
+struct CompleteImageUploadRequest<'r> {
+    checksum: &'r str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UploadProgress {
+    pub uploaded_bytes: u64,
+    pub total_bytes: u64,
+}
+
+pub trait UploadSource: AsyncRead + AsyncSeek + Send + Unpin {}
+
+impl<T: AsyncRead + AsyncSeek + Send + Unpin> UploadSource for T {}
+
+pub enum ImageSource {
+    Path(PathBuf),
+    Reader(Box<dyn UploadSource>),
+}
+
+impl ImageSource {
+    pub fn reader<R: UploadSource + 'static>(reader: R) -> Self {
+        ImageSource::Reader(Box::new(reader))
+    }
+}
+
+impl From<PathBuf> for ImageSource {
+    fn from(path: PathBuf) -> Self {
+        ImageSource::Path(path)
+    }
+}
+
+impl From<&Path> for ImageSource {
+    fn from(path: &Path) -> Self {
+        ImageSource::Path(path.to_path_buf())
+    }
+}
+
+impl From<&str> for ImageSource {
+    fn from(path: &str) -> Self {
+        ImageSource::Path(PathBuf::from(path))
+    }
+}
+
+#[derive(Clone)]
+pub struct ImageUploadOptions {
+    chunk_size: usize,
+    max_retries: u32,
+    retry_delay: Duration,
+    wait: WaitConfig,
+    progress: Option<Arc<dyn Fn(UploadProgress) + Send + Sync>>,
+}
+
+impl ImageUploadOptions {
+    pub fn new() -> Self {
+        Self {
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            max_retries: DEFAULT_MAX_RETRIES,
+            retry_delay: DEFAULT_RETRY_DELAY,
+            wait: WaitConfig::new()
+                .poll_interval(DEFAULT_POLL_INTERVAL)
+                .timeout(DEFAULT_POLL_TIMEOUT),
+            progress: None,
+        }
+    }
+
+    pub fn chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size;
+        self
+    }
+
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    pub fn retry_delay(mut self, retry_delay: Duration) -> Self {
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.wait = self.wait.poll_interval(poll_interval);
+        self
+    }
+
+    pub fn poll_timeout(mut self, poll_timeout: Duration) -> Self {
+        self.wait = self.wait.timeout(poll_timeout);
+        self
+    }
+
+    /// Replaces the whole polling policy used while the server finalizes the upload.
+    pub fn wait_config(mut self, wait: WaitConfig) -> Self {
+        self.wait = wait;
+        self
+    }
+
+    pub fn on_progress<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(UploadProgress) + Send + Sync + 'static,
+    {
+        self.progress = Some(Arc::new(callback));
+        self
+    }
+
+    fn report(&self, uploaded_bytes: u64, total_bytes: u64) {
+        if let Some(progress) = &self.progress {
+            progress(UploadProgress {
+                uploaded_bytes,
+                total_bytes,
+            });
+        }
+    }
+}
+
+impl Default for ImageUploadOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn io_error(e: std::io::Error, endpoint: &str) -> ComputeError {
+    ComputeError::from_sdk_error(SdkError::Io(e), IMAGE_UPLOAD_SERVICE, Some(endpoint))
+}
+
+/// The server answered, but with a state the upload cannot continue from.
+fn server_error(endpoint: &str, message: String) -> ComputeError {
+    let error = UnexpectedError {
+        message,
+        request_id: None,
+    };
+    ComputeError::from_sdk_error(error.into(), IMAGE_UPLOAD_SERVICE, Some(endpoint))
+}
+
+async fn read_chunk<R: UploadSource + ?Sized>(
+    reader: &mut R,
+    buf: &mut [u8],
+) -> std::io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        let n = reader.read(&mut buf[filled..]).await?;
+        if n == 0 {
+            break;
+        }
+        filled += n;
+    }
+    Ok(filled)
+}
+
+async fn checksum_source<R: UploadSource + ?Sized>(
+    reader: &mut R,
+    chunk_size: usize,
+) -> std::io::Result<(u64, String)> {
+    reader.seek(std::io::SeekFrom::Start(0)).await?;
+    let mut hasher = Sha256::new();
+    let mut buf = vec![0u8; chunk_size];
+    let mut size = 0u64;
+    loop {
+        let n = read_chunk(reader, &mut buf).await?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+        size += n as u64;
+    }
+    let checksum = hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    Ok((size, checksum))
+}
+
+impl ComputeClient {
+    fn image_uploads_path(&self) -> String {
+        format!(
+            "/image-uploader/api/v1/projects/{}/image-uploads",
+            self.client.project()
+        )
+    }
+
+    async fn put_chunk(
+        &self,
+        path: &str,
+        content_range: &str,
+        chunk: Vec<u8>,
+    ) -> Result<Option<ImageUploadOffsetResponse>> {
+        let span = info_span!("compute_put_chunk", path, content_range);
+        async move {
+            debug!(path, content_range, "Uploading chunk");
+            self.client
+                .http()
+                .put_bytes(path, &[("Content-Range", content_range)], chunk)
+                .await
+                .map_err(|e| ComputeError::from_sdk_error(e, IMAGE_UPLOAD_SERVICE, Some(path)))
+        }
+        .instrument(span)
+        .await
+    }
+
+    pub async fn get_image_upload(&self, upload_id: ImageUploadId) -> Result<ImageUpload> {
+        ImageUpload::get(&self.client, &upload_id).await
+    }
+
+    pub async fn import_image_from_url(
+        &self,
+        source_url: &str,
+        request: ImageUploadRequest,
+    ) -> Result<ImageUpload> {
+        let base = self.image_uploads_path();
+
+        if request.name.trim().is_empty() {
+            return Err(ComputeError::validation(
+                IMAGE_UPLOAD_SERVICE,
+                Some(&base),
+                "image name must not be empty".to_string(),
+            ));
+        }
+        match url::Url::parse(source_url) {
+            Ok(url) if matches!(url.scheme(), "http" | "https") => {}
+            _ => {
+                return Err(ComputeError::validation(
+                    IMAGE_UPLOAD_SERVICE,
+                    Some(&base),
+                    format!("invalid image source URL: {}", source_url),
+                ));
+            }
+        }
+
+        let import = ImportImageRequest {
+            image: &request,
+            source_url,
+        };
+        let response: ImageUploadWrapper = self.post(&base, &import).await?;
+        Ok(response.into())
+    }
+
+    pub async fn upload_image(
+        &self,
+        source: impl Into<ImageSource>,
+        request: ImageUploadRequest,
+        options: ImageUploadOptions,
+    ) -> Result<ImageUpload> {
+        let base = self.image_uploads_path();
+
+        if request.name.trim().is_empty() {
+            return Err(ComputeError::validation(
+                IMAGE_UPLOAD_SERVICE,
+                Some(&base),
+                "image name must not be empty".to_string(),
+            ));
+        }
+        if options.chunk_size == 0 {
+            return Err(ComputeError::validation(
+                IMAGE_UPLOAD_SERVICE,
+                Some(&base),
+                "chunk size must be greater than zero".to_string(),
+            ));
+        }
+
+        let mut reader: Box<dyn UploadSource> = match source.into() {
+            ImageSource::Path(path) => {
+                let file = tokio::fs::File::open(&path)
+                    .await
+                    .map_err(|e| io_error(e, &path.display().to_string()))?;
+                Box::new(file)
+            }
+            ImageSource::Reader(reader) => reader,
+        };
+
+        let (total_bytes, checksum) = checksum_source(reader.as_mut(), options.chunk_size)
+            .await
+            .map_err(|e| io_error(e, &base))?;
+        debug!(total_bytes, %checksum, "Computed image checksum");
+
+        let create = CreateImageUploadRequest {
+            image: &request,
+            size: total_bytes,
+            checksum: &checksum,
+            checksum_algorithm: "sha256",
+        };
+        let created: ImageUploadWrapper = self.post(&base, &create).await?;
+        let upload_path = format!("{}/{}", base, created.id);
+        let data_path = format!("{}/data", upload_path);
+
+        let mut offset = 0u64;
+        let mut failures = 0u32;
+        let mut buf = vec![0u8; options.chunk_size];
+        options.report(offset, total_bytes);
+
+        while offset < total_bytes {
+            reader
+                .seek(std::io::SeekFrom::Start(offset))
+                .await
+                .map_err(|e| io_error(e, &data_path))?;
+            let n = read_chunk(reader.as_mut(), &mut buf)
+                .await
+                .map_err(|e| io_error(e, &data_path))?;
+            if n == 0 {
+                return Err(ComputeError::validation(
+                    IMAGE_UPLOAD_SERVICE,
+                    Some(&data_path),
+                    format!("image source ended at {} of {} bytes", offset, total_bytes),
+                ));
+            }
+
+            let content_range =
+                format!("bytes {}-{}/{}", offset, offset + n as u64 - 1, total_bytes);
+            match self
+                .put_chunk(&data_path, &content_range, buf[..n].to_vec())
+                .await
+            {
+                Ok(confirmed) => {
+                    let next = confirmed
+                        .and_then(|c| c.offset)
+                        .unwrap_or(offset + n as u64)
+                        .min(total_bytes);
+                    if next <= offset {
+                        return Err(server_error(
+                            &data_path,
+                            format!(
+                                "server confirmed offset {} after chunk at offset {}",
+                                next, offset
+                            ),
+                        ));
+                    }
+                    failures = 0;
+                    offset = next;
+                    options.report(offset, total_bytes);
+                }
+                Err(e) if e.is_retryable() && failures < options.max_retries => {
+                    failures += 1;
+                    warn!(
+                        attempt = failures,
+                        offset,
+                        error = %e,
+                        "Chunk upload failed, resuming"
+                    );
+                    let delay = e.retry_after().unwrap_or(options.retry_delay * failures);
+                    tokio::time::sleep(delay).await;
+                    let offset_path = format!("{}/offset", upload_path);
+                    match self.get::<ImageUploadOffsetResponse>(&offset_path).await {
+                        Ok(ImageUploadOffsetResponse {
+                            offset: Some(confirmed),
+                        }) => offset = confirmed.min(total_bytes),
+                        Ok(_) => debug!(offset, "Server reported no upload offset"),
+                        Err(e) => debug!(error = %e, "Failed to query upload offset"),
+                    }
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        let complete_path = format!("{}/complete", upload_path);
+        let completed: ImageUploadWrapper = self
+            .post(
+                &complete_path,
+                &CompleteImageUploadRequest {
+                    checksum: &checksum,
+                },
+            )
+            .await?;
+        if let Some(remote) = &completed.checksum
+            && !remote.eq_ignore_ascii_case(&checksum)
+        {
+            return Err(server_error(
+                &complete_path,
+                format!("checksum mismatch: local {}, server {}", checksum, remote),
+            ));
+        }
+
+        self.wait_image_upload(&upload_path, &options).await
+    }
+
+    async fn wait_image_upload(
+        &self,
+        upload_path: &str,
+        options: &ImageUploadOptions,
+    ) -> Result<ImageUpload> {
+        wait_until(
+            || async move {
+                let response: ImageUploadWrapper = self.get(upload_path).await?;
+                Ok(ImageUpload::from(response))
+            },
+            |upload| match upload.status {
+                ImageUploadStatus::Active => WaitStatus::Ready,
+                ImageUploadStatus::Failed | ImageUploadStatus::Deleted => WaitStatus::Failed(
+                    format!("image upload {} ended as {:?}", upload.id, upload.status),
+                ),
+                _ => WaitStatus::Pending,
+            },
+            options.wait.clone(),
+        )
+        .await
+        .map_err(|e| match e {
+            OperationError::PollFailed(e) => e,
+            OperationError::Timeout(timeout) => ComputeError::from_sdk_error(
+                SdkError::Timeout {
+                    timeout: timeout.as_secs(),
+                },
+                IMAGE_UPLOAD_SERVICE,
+                Some(upload_path),
+            ),
+            e => server_error(upload_path, e.to_string()),
+        })
+    }
+}
@@ -1,395 +1,719 @@
-use crate::client::paginate::paginate;
+use crate::client::paginate::{ListOptions, PrefetchOptions};
+use crate::client::query::ResourceQuery;
+use crate::client::request::{self, ServiceClient};
+use crate::client::resource::{CloudResource, DeletableResource};
 use crate::models::{
-    AvailabilityZone, Backups, Flavors, ImageUpload, Images, Instances, PlacementPolicy, Regions,
-    Tasks,
+    AvailabilityZone, AvailabilityZoneId, BackupId, Backups, FlavorId, Flavors, ImageId,
+    ImageUpload, Images, InstanceId, Instances, PlacementPolicy, PlacementPolicyId,
+    PlacementPolicyType, RegionId, Regions, TaskId, TaskStatus, Tasks, VolumeId,
 };
+use chrono::{DateTime, Utc};
+use futures::stream::{BoxStream, StreamExt, TryStreamExt};
+use serde::{Deserialize, Serialize};
 use tilt_sdk::Client;
-use tracing::{Instrument, debug, info_span};
 use uuid::Uuid;
 
-use super::responses::{
-    AvailabilityZoneWrapper, AvailabilityZonesResponse, BackupsResponse, FlavorWrapper,
-    FlavorsResponse, ImageUploadsResponse, ImageWrapper, InstanceWrapper, InstancesResponse,
-    PlacementPoliciesResponse, PlacementPolicyWrapper, ProjectImagesResponse, RegionsResponse,
-    SharedImagesResponse, TaskResponse, TasksResponse,
-};
+use super::responses::{ImageWrapper, OrderItemRef, PlacementPolicyWrapper, TaskResponse};
 use crate::error::{ComputeError, Result, Service};
+use crate::operations::{DeleteResponse, MutationOutcome, Operation};
+
+mod image_upload;
+
+pub use image_upload::{
+    DiskFormat, ImageSource, ImageUploadOptions, ImageUploadRequest, UploadProgress, UploadSource,
+};
 
 const COMPUTE_SERVICE: Service = Service::ComputeApi;
 
-pub struct ComputeClient<'a> {
-    client: &'a Client,
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderActionRequest<A> {
+    pub project_name: String,
+    pub id: Uuid,
+    pub item_id: Uuid,
+    pub order: OrderActionAttrs<A>,
 }
 
-impl<'a> ComputeClient<'a> {
-    pub fn new(client: &'a Client) -> Self {
-        Self { client }
-    }
-
-    async fn get<T: serde::de::DeserializeOwned>(&self, path: &str) -> Result<T> {
-        let span = info_span!("compute_get", path);
-        async move {
-            debug!(path, "Fetching resource");
-            match self.client.http().get(path).await {
-                Ok(response) => {
-                    debug!(path, "Successfully fetched resource");
-                    Ok(response)
-                }
-                Err(e) => Err(ComputeError::from_sdk_error(e, COMPUTE_SERVICE, Some(path))),
-            }
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderActionAttrs<A> {
+    pub attrs: A,
+}
+
+impl<A> OrderActionRequest<A> {
+    pub fn new(project_name: &str, order_id: Uuid, item_id: Uuid, attrs: A) -> Self {
+        Self {
+            project_name: project_name.to_string(),
+            id: order_id,
+            item_id,
+            order: OrderActionAttrs { attrs },
         }
-        .instrument(span)
-        .await
     }
+}
 
-    async fn get_with_query<T: serde::de::DeserializeOwned>(
-        &self,
-        path: &str,
-        query: &[(&str, &str)],
-    ) -> Result<T> {
-        let span = info_span!("compute_get_with_query", path);
-        async move {
-            debug!(path, ?query, "Fetching resources with query");
-            match self.client.http().get_with_query(path, query).await {
-                Ok(response) => {
-                    debug!(path, ?query, "Successfully fetched resources");
-                    Ok(response)
-                }
-                Err(e) => Err(ComputeError::from_sdk_error(e, COMPUTE_SERVICE, Some(path))),
+pub(crate) async fn order_action<A: Serialize>(
+    client: &Client,
+    order_id: Uuid,
+    item_id: Uuid,
+    action: &str,
+    attrs: A,
+) -> Result<Tasks> {
+    let path = format!(
+        "/order-service/api/v1/projects/{}/orders/{}/actions/{}",
+        client.project(),
+        order_id,
+        action
+    );
+    let request = OrderActionRequest::new(client.project(), order_id, item_id, attrs);
+    let response: TaskResponse =
+        request::patch(client, Service::OrderService, &path, &request).await?;
+    Ok(response.data.into())
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateImageRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub os_distro: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub os_version: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShareImageRequest {
+    pub image_id: ImageId,
+    pub projects: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TaskFilter {
+    pub status: Option<TaskStatus>,
+    pub object_type: Option<String>,
+    pub object_id: Option<Uuid>,
+    pub initiator: Option<String>,
+    pub created_after: Option<DateTime<Utc>>,
+    pub created_before: Option<DateTime<Utc>>,
+    object_aliases: &'static [&'static str],
+}
+
+impl TaskFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn status(mut self, status: TaskStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn object_type(mut self, object_type: impl Into<String>) -> Self {
+        self.object_type = Some(object_type.into());
+        self
+    }
+
+    /// Restricts the filter to tasks on one resource, matching both its
+    /// object type and id.
+    pub fn object<T>(mut self, object_id: T::Id) -> Self
+    where
+        T: CloudResource,
+        T::Id: Into<Uuid>,
+    {
+        self.object_type = Some(T::KIND.to_string());
+        self.object_aliases = T::ALIASES;
+        self.object_id = Some(object_id.into());
+        self
+    }
+
+    pub fn initiator(mut self, initiator: impl Into<String>) -> Self {
+        self.initiator = Some(initiator.into());
+        self
+    }
+
+    pub fn created_between(
+        mut self,
+        after: Option<DateTime<Utc>>,
+        before: Option<DateTime<Utc>>,
+    ) -> Self {
+        self.created_after = after;
+        self.created_before = before;
+        self
+    }
+
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(status) = &self.status {
+            query.push(("status", status.as_str().to_string()));
+        }
+        if let Some(object_type) = &self.object_type {
+            query.push(("object_type", object_type.clone()));
+        }
+        if let Some(object_id) = self.object_id {
+            query.push(("object_id", object_id.to_string()));
+        }
+        if let Some(initiator) = &self.initiator {
+            query.push(("initiator", initiator.clone()));
+        }
+        if let Some(after) = self.created_after {
+            query.push(("created_after", after.to_rfc3339()));
+        }
+        if let Some(before) = self.created_before {
+            query.push(("created_before", before.to_rfc3339()));
+        }
+        query
+    }
+
+    /// Checks a task against every criterion. The filter is also sent to the
+    /// server, but listings recheck it in case the endpoint ignores a
+    /// parameter.
+    pub fn matches(&self, task: &Tasks) -> bool {
+        let normalize = |kind: &str| kind.replace('_', "").to_ascii_lowercase();
+        if let Some(status) = &self.status
+            && task.status != *status
+        {
+            return false;
+        }
+        if let Some(object_type) = &self.object_type {
+            let actual = normalize(&task.object_type);
+            let matched = std::iter::once(object_type.as_str())
+                .chain(self.object_aliases.iter().copied())
+                .any(|kind| normalize(kind) == actual);
+            if !matched {
+                return false;
             }
         }
-        .instrument(span)
-        .await
+        if let Some(object_id) = self.object_id
+            && task.object_id != object_id
+        {
+            return false;
+        }
+        if let Some(initiator) = &self.initiator
+            && task.initiator != *initiator
+        {
+            return false;
+        }
+        if let Some(after) = self.created_after
+            && task.created_at.is_none_or(|created| created < after)
+        {
+            return false;
+        }
+        if let Some(before) = self.created_before
+            && task.created_at.is_none_or(|created| created > before)
+        {
+            return false;
+        }
+        true
     }
+}
 
-    pub async fn list_instances(
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatePlacementPolicyRequest {
+    pub name: String,
+    pub policy_type: PlacementPolicyType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub availability_zone: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackupSource {
+    Instance(InstanceId),
+    Volume(VolumeId),
+}
+
+impl BackupSource {
+    fn object_id(&self) -> Uuid {
+        match *self {
+            BackupSource::Instance(id) => id.into(),
+            BackupSource::Volume(id) => id.into(),
+        }
+    }
+
+    fn path(&self, project: &str) -> String {
+        let collection = match self {
+            BackupSource::Instance(_) => "instances",
+            BackupSource::Volume(_) => "volumes",
+        };
+        let id = self.object_id();
+        format!(
+            "/order-service/api/v1/projects/{}/compute/{}/{}",
+            project, collection, id
+        )
+    }
+}
+
+/// Where a backup is restored to. `Existing` names the instance or volume to
+/// overwrite, which must be of the same kind as the backup's source.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(into = "RestoreTargetAttrs")]
+pub enum BackupRestoreTarget {
+    New { name: String },
+    Existing(BackupSource),
+}
+
+#[derive(Serialize)]
+#[serde(tag = "mode")]
+enum RestoreTargetAttrs {
+    #[serde(rename = "new")]
+    New { name: String },
+    #[serde(rename = "existing")]
+    Existing { object_id: Uuid },
+}
+
+impl From<BackupRestoreTarget> for RestoreTargetAttrs {
+    fn from(target: BackupRestoreTarget) -> Self {
+        match target {
+            BackupRestoreTarget::New { name } => RestoreTargetAttrs::New { name },
+            BackupRestoreTarget::Existing(source) => RestoreTargetAttrs::Existing {
+                object_id: source.object_id(),
+            },
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ComputeClient {
+    client: Client,
+    prefetch: Option<PrefetchOptions>,
+}
+
+impl ServiceClient for ComputeClient {
+    const SERVICE: Service = COMPUTE_SERVICE;
+
+    fn http_client(&self) -> &Client {
+        &self.client
+    }
+}
+
+impl ComputeClient {
+    pub fn new(client: &Client) -> Self {
+        Self {
+            client: client.clone(),
+            prefetch: None,
+        }
+    }
+
+    pub fn with_prefetch(mut self, prefetch: PrefetchOptions) -> Self {
+        self.prefetch = Some(prefetch);
+        self
+    }
+
+    pub async fn list_instances(&self, options: ListOptions) -> Result<Vec<Instances>> {
+        self.list_instances_stream(options).try_collect().await
+    }
+
+    pub fn list_instances_stream(&self, options: ListOptions) -> BoxStream<'_, Result<Instances>> {
+        Instances::list_stream(&self.client, self.prefetch, options)
+    }
+
+    pub async fn find_instances(
         &self,
-        limit: Option<u32>,
-        page: Option<u32>,
+        query: ResourceQuery<Instances>,
+        options: ListOptions,
     ) -> Result<Vec<Instances>> {
-        let path = format!(
-            "/order-service/api/v1/projects/{}/compute/instances",
-            self.client.project()
-        );
+        let path = Instances::collection_path(self.client.project());
+        query
+            .collect(&path, options, |query| {
+                self.find_instances_stream(query, options)
+            })
+            .await
+    }
 
-        let this = &self;
-        paginate(
-            limit,
-            page,
-            Service::OrderService,
-            &path,
-            |page: u32, limit: u32| {
-                let path = path.clone();
-                async move {
-                    let response: InstancesResponse = this
-                        .get_with_query(
-                            &path,
-                            &[
-                                ("include", "total_count"),
-                                ("page", &page.to_string()),
-                                ("per_page", &limit.to_string()),
-                            ],
-                        )
-                        .await?;
-                    let items: Vec<Instances> =
-                        response.list.into_iter().map(|w| w.into()).collect();
-                    Ok((items, response.meta.total_count))
-                }
-            },
-        )
-        .await
+    pub fn find_instances_stream(
+        &self,
+        query: ResourceQuery<Instances>,
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<Instances>> {
+        let path = Instances::collection_path(self.client.project());
+        query.filter_stream(&path, options, |params| {
+            Instances::list_stream_with(&self.client, self.prefetch, options, params)
+        })
     }
 
-    pub async fn get_instance(&self, instance_id: Uuid) -> Result<Instances> {
-        let path = format!(
-            "/order-service/api/v1/projects/{}/compute/instances/{}",
-            self.client.project(),
-            instance_id
-        );
-        let response: InstanceWrapper = self.get(&path).await?;
-        Ok(response.into())
+    pub async fn get_instance(&self, instance_id: InstanceId) -> Result<Instances> {
+        Instances::get(&self.client, &instance_id).await
     }
 
     pub async fn list_flavors(
         &self,
-        limit: Option<u32>,
-        page: Option<u32>,
-        az_id: Option<&str>,
+        options: ListOptions,
+        az_id: Option<&AvailabilityZoneId>,
     ) -> Result<Vec<Flavors>> {
-        let path = "/api/v1/flavors";
-        let az_id = az_id.map(|s| s.to_string());
-
-        let this = &self;
-        paginate(
-            limit,
-            page,
-            Service::ComputeApi,
-            path,
-            |page: u32, limit: u32| {
-                let path = path.to_string();
-                let az_id = az_id.clone();
-                async move {
-                    let page_str = page.to_string();
-                    let per_page_str = limit.to_string();
-                    let mut query: Vec<(&str, &str)> = vec![
-                        ("include", "total_count"),
-                        ("page", &page_str),
-                        ("per_page", &per_page_str),
-                    ];
-                    if let Some(az) = &az_id {
-                        query.push(("availability_zone_id", az));
-                    }
-
-                    let response: FlavorsResponse = this.get_with_query(&path, &query).await?;
-                    let items: Vec<Flavors> = response.list.into_iter().map(Into::into).collect();
-                    Ok((items, response.meta.total_count))
-                }
-            },
-        )
-        .await
+        self.list_flavors_stream(options, az_id).try_collect().await
     }
 
-    pub async fn get_flavor(&self, flavor_id: Uuid) -> Result<Flavors> {
-        let path = format!("/api/v1/flavors/{}", flavor_id);
-        let response: FlavorWrapper = self.get(&path).await?;
-        Ok(response.into())
+    pub fn list_flavors_stream(
+        &self,
+        options: ListOptions,
+        az_id: Option<&AvailabilityZoneId>,
+    ) -> BoxStream<'_, Result<Flavors>> {
+        let params = az_id
+            .map(|id| vec![("availability_zone_id", id.to_string())])
+            .unwrap_or_default();
+        Flavors::list_stream_with(&self.client, self.prefetch, options, params)
     }
 
-    pub async fn list_project_images(
+    pub async fn get_flavor(&self, flavor_id: FlavorId) -> Result<Flavors> {
+        Flavors::get(&self.client, &flavor_id).await
+    }
+
+    pub async fn list_project_images(&self, options: ListOptions) -> Result<Vec<Images>> {
+        self.list_project_images_stream(options).try_collect().await
+    }
+
+    pub fn list_project_images_stream(
         &self,
-        limit: Option<u32>,
-        page: Option<u32>,
-    ) -> Result<Vec<Images>> {
-        let path = format!("/compute/api/v1/projects/{}/images", self.client.project());
-
-        let this = &self;
-        paginate(
-            limit,
-            page,
-            Service::ComputeApi,
-            &path,
-            |page: u32, limit: u32| {
-                let path = path.clone();
-                async move {
-                    let response: ProjectImagesResponse = this
-                        .get_with_query(
-                            &path,
-                            &[
-                                ("include", "total_count"),
-                                ("page", &page.to_string()),
-                                ("per_page", &limit.to_string()),
-                            ],
-                        )
-                        .await?;
-                    let items: Vec<Images> = response.list.into_iter().map(|w| w.into()).collect();
-                    Ok((items, response.meta.total_count))
-                }
-            },
-        )
-        .await
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<Images>> {
+        Images::list_stream(&self.client, self.prefetch, options)
     }
 
-    pub async fn list_shared_images(
+    pub async fn find_project_images(
         &self,
-        limit: Option<u32>,
-        page: Option<u32>,
+        query: ResourceQuery<Images>,
+        options: ListOptions,
     ) -> Result<Vec<Images>> {
+        let path = Images::collection_path(self.client.project());
+        query
+            .collect(&path, options, |query| {
+                self.find_project_images_stream(query, options)
+            })
+            .await
+    }
+
+    pub fn find_project_images_stream(
+        &self,
+        query: ResourceQuery<Images>,
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<Images>> {
+        let path = Images::collection_path(self.client.project());
+        query.filter_stream(&path, options, |params| {
+            Images::list_stream_with(&self.client, self.prefetch, options, params)
+        })
+    }
+
+    pub async fn list_shared_images(&self, options: ListOptions) -> Result<Vec<Images>> {
+        self.list_shared_images_stream(options).try_collect().await
+    }
+
+    pub fn list_shared_images_stream(&self, options: ListOptions) -> BoxStream<'_, Result<Images>> {
         let path = format!(
             "/compute/api/v1/projects/{}/shared-images",
             self.client.project()
         );
-
-        let this = &self;
-        paginate(
-            limit,
-            page,
-            Service::ComputeApi,
-            &path,
-            |page: u32, limit: u32| {
-                let path = path.clone();
-                async move {
-                    let response: SharedImagesResponse = this
-                        .get_with_query(
-                            &path,
-                            &[
-                                ("include", "total_count"),
-                                ("page", &page.to_string()),
-                                ("per_page", &limit.to_string()),
-                            ],
-                        )
-                        .await?;
-                    let items: Vec<Images> = response.list.into_iter().map(|w| w.into()).collect();
-                    Ok((items, response.meta.total_count))
-                }
-            },
+        Images::list_items_at(
+            &self.client,
+            COMPUTE_SERVICE,
+            path,
+            self.prefetch,
+            options,
+            Vec::new(),
         )
-        .await
+        .map_ok(Into::into)
+        .boxed()
     }
 
-    pub async fn list_image_uploads(
-        &self,
-        limit: Option<u32>,
-        page: Option<u32>,
-    ) -> Result<Vec<ImageUpload>> {
-        let path = format!(
-            "/image-uploader/api/v1/projects/{}/image-uploads",
-            self.client.project()
-        );
+    pub async fn list_image_uploads(&self, options: ListOptions) -> Result<Vec<ImageUpload>> {
+        self.list_image_uploads_stream(options).try_collect().await
+    }
 
-        let this = &self;
-        paginate(
-            limit,
-            page,
-            Service::ComputeApi,
-            &path,
-            |page: u32, limit: u32| {
-                let path = path.clone();
-                async move {
-                    let response: ImageUploadsResponse = this
-                        .get_with_query(
-                            &path,
-                            &[
-                                ("include", "total_count"),
-                                ("page", &page.to_string()),
-                                ("per_page", &limit.to_string()),
-                            ],
-                        )
-                        .await?;
-                    let items: Vec<ImageUpload> =
-                        response.list.into_iter().map(|w| w.into()).collect();
-                    Ok((items, response.meta.total_count))
-                }
-            },
-        )
-        .await
+    pub fn list_image_uploads_stream(
+        &self,
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<ImageUpload>> {
+        ImageUpload::list_stream(&self.client, self.prefetch, options)
     }
 
-    pub async fn list_images(&self, include_shared: bool) -> Result<Vec<Images>> {
-        let project = self.list_project_images(None, None).await?;
+    /// With `include_shared` both collections are fetched in full and
+    /// `options` select from the combined list.
+    pub async fn list_images(
+        &self,
+        options: ListOptions,
+        include_shared: bool,
+    ) -> Result<Vec<Images>> {
         if !include_shared {
-            return Ok(project);
+            return self.list_project_images(options).await;
         }
-        let shared = self.list_shared_images(None, None).await?;
-        Ok(project.into_iter().chain(shared.into_iter()).collect())
+        let project = self.list_project_images(ListOptions::all()).await?;
+        let shared = self.list_shared_images(ListOptions::all()).await?;
+        Ok(options.window(project.into_iter().chain(shared).collect()))
     }
 
-    pub async fn get_image(&self, image_id: Uuid) -> Result<Images> {
+    pub async fn get_image(&self, image_id: ImageId) -> Result<Images> {
+        Images::get(&self.client, &image_id).await
+    }
+
+    pub async fn update_image(
+        &self,
+        image_id: ImageId,
+        request: UpdateImageRequest,
+    ) -> Result<Images> {
         let path = format!(
             "/compute/api/v1/projects/{}/images/{}",
             self.client.project(),
             image_id
         );
-        let response: ImageWrapper = self.get(&path).await?;
+        if request.name.as_deref().is_some_and(|n| n.trim().is_empty()) {
+            return Err(ComputeError::validation(
+                COMPUTE_SERVICE,
+                Some(&path),
+                "image name must not be empty".to_string(),
+            ));
+        }
+        let response: ImageWrapper = self.patch(&path, &request).await?;
         Ok(response.into())
     }
 
-    pub async fn list_tasks(&self) -> Result<Vec<Tasks>> {
+    pub async fn delete_image(&self, image_id: ImageId) -> Result<MutationOutcome> {
+        Images::delete(&self.client, &image_id).await
+    }
+
+    pub async fn share_image(
+        &self,
+        image_id: ImageId,
+        projects: &[&str],
+    ) -> Result<MutationOutcome> {
         let path = format!(
-            "/order-service/api/v1/projects/{}/tasks",
+            "/compute/api/v1/projects/{}/shared-images",
             self.client.project()
         );
-        let response: TasksResponse = self
-            .get_with_query(&path, &[("include", "total_count")])
-            .await?;
-        Ok(response.list.into_iter().map(Into::into).collect())
+        if projects.is_empty() {
+            return Err(ComputeError::validation(
+                COMPUTE_SERVICE,
+                Some(&path),
+                "at least one target project is required".to_string(),
+            ));
+        }
+        let request = ShareImageRequest {
+            image_id,
+            projects: projects.iter().map(|p| p.to_string()).collect(),
+        };
+        let body: serde_json::Value = self.post(&path, &request).await?;
+        MutationOutcome::parse(DeleteResponse::Empty, body).map_err(|e| {
+            ComputeError::from_sdk_error(tilt_sdk::SdkError::Json(e), COMPUTE_SERVICE, Some(&path))
+        })
     }
 
-    pub async fn get_task(&self, task_id: Uuid) -> Result<Tasks> {
+    pub async fn unshare_image(&self, image_id: ImageId, project: &str) -> Result<MutationOutcome> {
         let path = format!(
-            "/order-service/api/v1/projects/{}/tasks/{}",
+            "/compute/api/v1/projects/{}/shared-images/{}/projects/{}",
             self.client.project(),
-            task_id
+            image_id,
+            project
         );
-        let response: TaskResponse = self.get(&path).await?;
-        Ok(response.data.into())
+        self.delete(&path, DeleteResponse::Empty).await
     }
 
-    pub async fn get_region(&self, region_id: Uuid) -> Result<Regions> {
-        let path = format!("/api/v1/regions/{}", region_id);
-        let response: super::responses::RegionWrapper = self.get(&path).await?;
-        Ok(response.into())
+    pub async fn list_tasks(&self, filter: TaskFilter, options: ListOptions) -> Result<Vec<Tasks>> {
+        self.list_tasks_stream(filter, options).try_collect().await
     }
 
-    pub async fn get_availability_zone(&self, az_id: Uuid) -> Result<AvailabilityZone> {
-        let path = format!(
-            "/compute/api/v1/projects/{}/availability-zones/{}",
-            self.client.project(),
-            az_id
-        );
-        let response: AvailabilityZoneWrapper = self.get(&path).await?;
-        Ok(response.into())
+    pub fn list_tasks_stream(
+        &self,
+        filter: TaskFilter,
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<Tasks>> {
+        let params = filter.query();
+        Tasks::list_stream_with(&self.client, self.prefetch, options, params)
+            .try_filter(move |task| std::future::ready(filter.matches(task)))
+            .boxed()
     }
 
-    pub async fn list_regions(&self) -> Result<Vec<Regions>> {
-        let path = format!("/compute/api/v1/projects/{}/regions", self.client.project());
-        let response: RegionsResponse = self.get_with_query(&path, &[]).await?;
-        Ok(response.into_iter().map(Into::into).collect())
+    pub async fn get_task(&self, task_id: TaskId) -> Result<Tasks> {
+        Tasks::get(&self.client, &task_id).await
     }
 
-    pub async fn list_availability_zones(&self) -> Result<Vec<AvailabilityZone>> {
-        let path = format!(
-            "/compute/api/v1/projects/{}/availability-zones",
-            self.client.project()
-        );
-        let response: AvailabilityZonesResponse = self.get_with_query(&path, &[]).await?;
-        Ok(response.0.into_iter().map(Into::into).collect())
+    pub async fn get_region(&self, region_id: &RegionId) -> Result<Regions> {
+        Regions::get(&self.client, region_id).await
+    }
+
+    pub async fn get_availability_zone(
+        &self,
+        az_id: &AvailabilityZoneId,
+    ) -> Result<AvailabilityZone> {
+        AvailabilityZone::get(&self.client, az_id).await
+    }
+
+    pub async fn list_regions(&self, options: ListOptions) -> Result<Vec<Regions>> {
+        self.list_regions_stream(options).try_collect().await
+    }
+
+    pub fn list_regions_stream(&self, options: ListOptions) -> BoxStream<'_, Result<Regions>> {
+        Regions::list_stream(&self.client, self.prefetch, options)
+    }
+
+    pub async fn list_availability_zones(
+        &self,
+        options: ListOptions,
+    ) -> Result<Vec<AvailabilityZone>> {
+        self.list_availability_zones_stream(options)
+            .try_collect()
+            .await
+    }
+
+    pub fn list_availability_zones_stream(
+        &self,
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<AvailabilityZone>> {
+        AvailabilityZone::list_stream(&self.client, self.prefetch, options)
+    }
+
+    pub async fn list_placement_policies(
+        &self,
+        options: ListOptions,
+    ) -> Result<Vec<PlacementPolicy>> {
+        self.list_placement_policies_stream(options)
+            .try_collect()
+            .await
+    }
+
+    pub fn list_placement_policies_stream(
+        &self,
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<PlacementPolicy>> {
+        PlacementPolicy::list_stream(&self.client, self.prefetch, options)
+    }
+
+    pub async fn get_placement_policy(
+        &self,
+        placement_policy_id: PlacementPolicyId,
+    ) -> Result<PlacementPolicy> {
+        PlacementPolicy::get(&self.client, &placement_policy_id).await
     }
 
-    pub async fn list_placement_policies(&self) -> Result<Vec<PlacementPolicy>> {
+    pub async fn create_placement_policy(
+        &self,
+        request: CreatePlacementPolicyRequest,
+    ) -> Result<PlacementPolicy> {
         let path = format!(
             "/compute/api/v1/projects/{}/placement-policies",
             self.client.project()
         );
-        let response: PlacementPoliciesResponse = self.get(&path).await?;
-        Ok(response.list.into_iter().map(Into::into).collect())
+        let invalid = if request.name.trim().is_empty() {
+            Some("placement policy name must not be empty")
+        } else if request.policy_type == PlacementPolicyType::Unknown {
+            Some("placement policy type must be specified")
+        } else if request
+            .availability_zone
+            .as_deref()
+            .is_some_and(|az| az.trim().is_empty())
+        {
+            Some("availability zone must not be empty")
+        } else {
+            None
+        };
+        if let Some(message) = invalid {
+            return Err(ComputeError::validation(
+                COMPUTE_SERVICE,
+                Some(&path),
+                message.to_string(),
+            ));
+        }
+        let response: PlacementPolicyWrapper = self.post(&path, &request).await?;
+        Ok(response.into())
+    }
+
+    pub async fn delete_placement_policy(
+        &self,
+        placement_policy_id: PlacementPolicyId,
+    ) -> Result<MutationOutcome> {
+        PlacementPolicy::delete(&self.client, &placement_policy_id).await
+    }
+
+    pub async fn list_placement_policy_instances(
+        &self,
+        placement_policy_id: PlacementPolicyId,
+        options: ListOptions,
+    ) -> Result<Vec<Instances>> {
+        self.list_placement_policy_instances_stream(placement_policy_id, options)
+            .try_collect()
+            .await
     }
 
-    pub async fn get_placement_policy(&self, placement_policy_id: Uuid) -> Result<PlacementPolicy> {
+    pub fn list_placement_policy_instances_stream(
+        &self,
+        placement_policy_id: PlacementPolicyId,
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<Instances>> {
         let path = format!(
-            "/api/v1/projects/{}/placement-policies/{}",
+            "/compute/api/v1/projects/{}/placement-policies/{}/instances",
             self.client.project(),
             placement_policy_id
         );
-        let response: PlacementPolicyWrapper = self.get(&path).await?;
-        Ok(response.into())
+        Instances::list_items_at(
+            &self.client,
+            COMPUTE_SERVICE,
+            path,
+            self.prefetch,
+            options,
+            Vec::new(),
+        )
+        .map_ok(Into::into)
+        .boxed()
+    }
+
+    pub async fn list_backups(&self, options: ListOptions) -> Result<Vec<Backups>> {
+        self.list_backups_stream(options).try_collect().await
+    }
+
+    pub fn list_backups_stream(&self, options: ListOptions) -> BoxStream<'_, Result<Backups>> {
+        Backups::list_stream(&self.client, self.prefetch, options)
+    }
+
+    pub async fn get_backup(&self, backup_id: BackupId) -> Result<Backups> {
+        Backups::get(&self.client, &backup_id).await
     }
 
-    pub async fn list_backups(
+    pub async fn create_backup(&self, source: BackupSource) -> Result<Operation> {
+        let path = source.path(self.client.project());
+        let item: OrderItemRef = self.get(&path).await?;
+        let task = order_action(
+            &self.client,
+            item.order_id,
+            item.item_id,
+            "create_backup",
+            serde_json::json!({}),
+        )
+        .await?;
+        Ok(Operation::for_task(task, &self.client))
+    }
+
+    async fn backup_order(&self, backup_id: BackupId) -> Result<Backups> {
+        let backup = self.get_backup(backup_id).await?;
+        if backup.order_id.is_nil() {
+            return Err(ComputeError::validation(
+                Service::OrderService,
+                Some(&Backups::item_path(self.client.project(), &backup_id)),
+                format!("backup {} has no order reference", backup_id),
+            ));
+        }
+        Ok(backup)
+    }
+
+    pub async fn restore_backup(
         &self,
-        limit: Option<u32>,
-        page: Option<u32>,
-    ) -> Result<Vec<Backups>> {
-        let path = format!(
-            "/order-service/api/v1/projects/{}/compute/backup_containers",
-            self.client.project()
-        );
+        backup_id: BackupId,
+        target: BackupRestoreTarget,
+    ) -> Result<Operation> {
+        let backup = self.backup_order(backup_id).await?;
+        let task = order_action(
+            &self.client,
+            backup.order_id,
+            backup.id.into(),
+            "restore_backup",
+            target,
+        )
+        .await?;
+        Ok(Operation::for_task(task, &self.client))
+    }
 
-        let this = &self;
-        paginate(
-            limit,
-            page,
-            Service::OrderService,
-            &path,
-            |page: u32, limit: u32| {
-                let path = path.clone();
-                async move {
-                    let response: BackupsResponse = this
-                        .get_with_query(
-                            &path,
-                            &[
-                                ("with_children", "false"),
-                                ("with_all_children", "false"),
-                                ("include", "total_count"),
-                                ("page", &page.to_string()),
-                                ("per_page", &limit.to_string()),
-                            ],
-                        )
-                        .await?;
-                    let items: Vec<Backups> = response.list.into_iter().map(Into::into).collect();
-                    Ok((items, response.meta.total_count))
-                }
-            },
+    pub async fn delete_backup(&self, backup_id: BackupId) -> Result<MutationOutcome> {
+        let backup = self.backup_order(backup_id).await?;
+        let task = order_action(
+            &self.client,
+            backup.order_id,
+            backup.id.into(),
+            "delete_backup_container",
+            serde_json::json!({}),
         )
-        .await
+        .await?;
+        Ok(MutationOutcome::Accepted(task.into()))
     }
 }
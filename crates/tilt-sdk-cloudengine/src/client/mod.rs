@@ -1,10 +1,18 @@
 use crate::error::{ComputeError, Result, Service};
 use crate::models::{
-    AvailabilityZone, Backups, Flavors, FloatingIps, ImageUpload, Images, Instances,
-    NetworkInterface, NetworkItem, NetworkRouter, Networks, PlacementPolicy, Regions, RouteTables,
-    Routers, SecurityGroupRule, SecurityGroups, Snapshots, SshKeys, Subnets, Tasks, VirtualIps,
-    VolumeType, Volumes,
+    AvailabilityZone, AvailabilityZoneId, BackupId, Backups, DhcpSettings, FlavorId, Flavors,
+    FloatingIpId, FloatingIps, ImageId, ImageStatus, ImageUpload, ImageUploadId, Images,
+    InstanceId, InstanceStatus, Instances, NetworkId, NetworkInterface, NetworkItem, NetworkRouter,
+    NetworkRouterId, NetworkRouterInterface, Networks, NicPort, PlacementPolicy, PlacementPolicyId,
+    PortId, RegionId, Regions, RouteTableId, RouteTables, RouterInterfaceId, Routers,
+    SecurityGroupId, SecurityGroupRule, SecurityGroupRuleId, SecurityGroups, SnapshotId, Snapshots,
+    SnatRouterId, SshKeyId, SshKeys, SubnetId, Subnets, TaskId, Tasks, VipId, VirtualIps, VolumeId,
+    VolumeStatus, VolumeType, VolumeTypeId, Volumes,
 };
+use crate::operations::{
+    MutationOutcome, Operation, OperationError, WaitConfig, WaitStatus, wait_deleted, wait_until,
+};
+use futures::stream::{BoxStream, TryStreamExt};
 use tilt_sdk::Client;
 use uuid::Uuid;
 
@@ -15,13 +23,33 @@ pub mod network;
 pub mod paginate;
 #[macro_use]
 pub mod portal;
+pub mod query;
+pub(crate) mod request;
+pub mod resource;
 #[macro_use]
 pub mod responses;
 #[macro_use]
 pub mod storage;
 
-pub use network::PortFilter;
-pub use paginate::paginate;
+pub use compute::{
+    BackupRestoreTarget, BackupSource, CreatePlacementPolicyRequest, DiskFormat, ImageSource,
+    ImageUploadOptions, ImageUploadRequest, TaskFilter, UpdateImageRequest, UploadProgress,
+};
+pub use network::{
+    AddRouterInterfaceRequest, AllocateFloatingIpRequest, Cidr, CreateNetworkRequest,
+    CreateNetworkRouterRequest, CreatePortRequest, CreateRouteTableRequest,
+    CreateSecurityGroupRequest, CreateSnatRouterRequest, CreateSubnetRequest, CreateVipRequest,
+    EtherType, FloatingIpTarget, NextHopType, PortFilter, RouteSpec, RuleDiff, RuleDirection,
+    RuleProtocol, RuleSyncResult, SecurityGroupRuleBuilder, SecurityGroupRuleSpec,
+    UpdateNetworkRequest, UpdateSecurityGroupRequest, UpdateSubnetDhcpRequest, VipAddressMode,
+};
+pub use paginate::{ListOptions, PrefetchOptions, paginate, paginate_stream};
+pub use portal::SshKeyUpdate;
+pub use query::{
+    ImageQuery, InstanceQuery, NetworkQuery, QueryParam, Queryable, ResourceQuery,
+    SecurityGroupQuery, SnapshotQuery, SortDirection, SortField, SubnetQuery, VolumeQuery,
+};
+pub use resource::{CloudResource, DeletableResource};
 
 const MAX_PAGE_LIMIT: u32 = 100;
 
@@ -46,326 +74,1133 @@ pub enum Resource {
     Image(Images),
     VolumeType(VolumeType),
     Volume(Volumes),
+    Snapshot(Snapshots),
     Network(Networks),
+    FloatingIp(FloatingIps),
+    Vip(VirtualIps),
+    Port(NicPort),
+    Router(Routers),
+    NetworkRouter(NetworkRouter),
     SecurityGroup(SecurityGroups),
     PlacementPolicy(PlacementPolicy),
     Region(Regions),
     AvailabilityZone(AvailabilityZone),
     Task(Tasks),
+    Backup(Backups),
 }
 
-pub struct ComputeClient<'a> {
-    client: &'a Client,
-    compute: compute::ComputeClient<'a>,
-    storage: storage::StorageClient<'a>,
-    network: network::NetworkClient<'a>,
-    portal: portal::PortalClient<'a>,
+#[derive(Clone)]
+pub struct ComputeClient {
+    client: Client,
+    compute: compute::ComputeClient,
+    storage: storage::StorageClient,
+    network: network::NetworkClient,
+    portal: portal::PortalClient,
+    prefetch: Option<PrefetchOptions>,
 }
 
-impl<'a> ComputeClient<'a> {
-    pub fn new(client: &'a Client) -> Self {
+impl ComputeClient {
+    pub fn new(client: &Client) -> Self {
         let compute = compute::ComputeClient::new(client);
         let storage = storage::StorageClient::new(client);
         let network = network::NetworkClient::new(client);
         let portal = portal::PortalClient::new(client);
         Self {
-            client,
+            client: client.clone(),
             compute,
             storage,
             network,
             portal,
+            prefetch: None,
         }
     }
 
+    pub fn with_prefetch(mut self, prefetch: PrefetchOptions) -> Self {
+        self.compute = self.compute.with_prefetch(prefetch);
+        self.storage = self.storage.with_prefetch(prefetch);
+        self.network = self.network.with_prefetch(prefetch);
+        self.portal = self.portal.with_prefetch(prefetch);
+        self.prefetch = Some(prefetch);
+        self
+    }
+
     pub fn client(&self) -> &Client {
-        self.client
+        &self.client
+    }
+
+    pub fn compute(&self) -> &compute::ComputeClient {
+        &self.compute
+    }
+
+    pub fn network(&self) -> &network::NetworkClient {
+        &self.network
+    }
+
+    pub fn storage(&self) -> &storage::StorageClient {
+        &self.storage
+    }
+
+    pub fn portal(&self) -> &portal::PortalClient {
+        &self.portal
+    }
+
+    pub async fn get<T: CloudResource>(&self, id: T::Id) -> Result<T> {
+        T::get(&self.client, &id).await
+    }
+
+    pub async fn list<T: CloudResource>(&self, options: ListOptions) -> Result<Vec<T>> {
+        self.list_stream(options).try_collect().await
+    }
+
+    pub fn list_stream<T: CloudResource>(&self, options: ListOptions) -> BoxStream<'_, Result<T>> {
+        T::list_stream(&self.client, self.prefetch, options)
+    }
+
+    pub async fn delete<T: DeletableResource>(&self, id: T::Id) -> Result<MutationOutcome> {
+        T::delete(&self.client, &id).await
     }
 
-    pub async fn list_instances(
+    pub async fn list_instances(&self, options: ListOptions) -> Result<Vec<Instances>> {
+        self.compute.list_instances(options).await
+    }
+
+    pub fn list_instances_stream(&self, options: ListOptions) -> BoxStream<'_, Result<Instances>> {
+        self.compute.list_instances_stream(options)
+    }
+
+    pub async fn find_instances(
         &self,
-        limit: Option<u32>,
-        page: Option<u32>,
+        query: InstanceQuery,
+        options: ListOptions,
     ) -> Result<Vec<Instances>> {
-        self.compute.list_instances(limit, page).await
+        self.compute.find_instances(query, options).await
     }
 
-    pub async fn get_instance(&self, instance_id: Uuid) -> Result<Instances> {
+    pub fn find_instances_stream(
+        &self,
+        query: InstanceQuery,
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<Instances>> {
+        self.compute.find_instances_stream(query, options)
+    }
+
+    pub async fn get_instance(&self, instance_id: InstanceId) -> Result<Instances> {
         self.compute.get_instance(instance_id).await
     }
 
     pub async fn list_flavors(
         &self,
-        limit: Option<u32>,
-        page: Option<u32>,
-        az_id: Option<&str>,
+        options: ListOptions,
+        az_id: Option<&AvailabilityZoneId>,
     ) -> Result<Vec<Flavors>> {
-        self.compute.list_flavors(limit, page, az_id).await
+        self.compute.list_flavors(options, az_id).await
     }
 
-    pub async fn get_flavor(&self, flavor_id: Uuid) -> Result<Flavors> {
+    pub fn list_flavors_stream(
+        &self,
+        options: ListOptions,
+        az_id: Option<&AvailabilityZoneId>,
+    ) -> BoxStream<'_, Result<Flavors>> {
+        self.compute.list_flavors_stream(options, az_id)
+    }
+
+    pub async fn get_flavor(&self, flavor_id: FlavorId) -> Result<Flavors> {
         self.compute.get_flavor(flavor_id).await
     }
 
-    pub async fn list_project_images(
+    pub async fn list_project_images(&self, options: ListOptions) -> Result<Vec<Images>> {
+        self.compute.list_project_images(options).await
+    }
+
+    pub fn list_project_images_stream(
         &self,
-        limit: Option<u32>,
-        page: Option<u32>,
-    ) -> Result<Vec<Images>> {
-        self.compute.list_project_images(limit, page).await
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<Images>> {
+        self.compute.list_project_images_stream(options)
     }
 
-    pub async fn list_shared_images(
+    pub async fn find_project_images(
         &self,
-        limit: Option<u32>,
-        page: Option<u32>,
+        query: ImageQuery,
+        options: ListOptions,
     ) -> Result<Vec<Images>> {
-        self.compute.list_shared_images(limit, page).await
+        self.compute.find_project_images(query, options).await
+    }
+
+    pub fn find_project_images_stream(
+        &self,
+        query: ImageQuery,
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<Images>> {
+        self.compute.find_project_images_stream(query, options)
+    }
+
+    pub async fn list_shared_images(&self, options: ListOptions) -> Result<Vec<Images>> {
+        self.compute.list_shared_images(options).await
+    }
+
+    pub fn list_shared_images_stream(&self, options: ListOptions) -> BoxStream<'_, Result<Images>> {
+        self.compute.list_shared_images_stream(options)
+    }
+
+    pub async fn list_image_uploads(&self, options: ListOptions) -> Result<Vec<ImageUpload>> {
+        self.compute.list_image_uploads(options).await
     }
 
-    pub async fn list_image_uploads(
+    pub fn list_image_uploads_stream(
         &self,
-        limit: Option<u32>,
-        page: Option<u32>,
-    ) -> Result<Vec<ImageUpload>> {
-        self.compute.list_image_uploads(limit, page).await
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<ImageUpload>> {
+        self.compute.list_image_uploads_stream(options)
+    }
+
+    pub async fn get_image_upload(&self, upload_id: ImageUploadId) -> Result<ImageUpload> {
+        self.compute.get_image_upload(upload_id).await
     }
 
-    pub async fn list_images(&self, include_shared: bool) -> Result<Vec<Images>> {
-        self.compute.list_images(include_shared).await
+    pub async fn upload_image(
+        &self,
+        source: impl Into<ImageSource>,
+        request: ImageUploadRequest,
+        options: ImageUploadOptions,
+    ) -> Result<ImageUpload> {
+        self.compute.upload_image(source, request, options).await
     }
 
-    pub async fn get_image(&self, image_id: Uuid) -> Result<Images> {
+    pub async fn list_images(
+        &self,
+        options: ListOptions,
+        include_shared: bool,
+    ) -> Result<Vec<Images>> {
+        self.compute.list_images(options, include_shared).await
+    }
+
+    pub async fn get_image(&self, image_id: ImageId) -> Result<Images> {
         self.compute.get_image(image_id).await
     }
 
-    pub async fn list_volume_types(&self) -> Result<Vec<VolumeType>> {
-        self.storage.list_volume_types().await
+    pub async fn update_image(
+        &self,
+        image_id: ImageId,
+        request: UpdateImageRequest,
+    ) -> Result<Images> {
+        self.compute.update_image(image_id, request).await
+    }
+
+    pub async fn delete_image(&self, image_id: ImageId) -> Result<MutationOutcome> {
+        self.compute.delete_image(image_id).await
+    }
+
+    pub async fn share_image(
+        &self,
+        image_id: ImageId,
+        projects: &[&str],
+    ) -> Result<MutationOutcome> {
+        self.compute.share_image(image_id, projects).await
+    }
+
+    pub async fn unshare_image(&self, image_id: ImageId, project: &str) -> Result<MutationOutcome> {
+        self.compute.unshare_image(image_id, project).await
+    }
+
+    pub async fn import_image_from_url(
+        &self,
+        source_url: &str,
+        request: ImageUploadRequest,
+    ) -> Result<ImageUpload> {
+        self.compute
+            .import_image_from_url(source_url, request)
+            .await
+    }
+
+    pub async fn list_volume_types(&self, options: ListOptions) -> Result<Vec<VolumeType>> {
+        self.storage.list_volume_types(options).await
     }
 
-    pub async fn get_volume_type(&self, volume_type_id: Uuid) -> Result<VolumeType> {
+    pub fn list_volume_types_stream(
+        &self,
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<VolumeType>> {
+        self.storage.list_volume_types_stream(options)
+    }
+
+    pub async fn get_volume_type(&self, volume_type_id: VolumeTypeId) -> Result<VolumeType> {
         self.storage.get_volume_type(volume_type_id).await
     }
 
-    pub async fn list_volumes(
+    pub async fn list_volumes(&self, options: ListOptions) -> Result<Vec<Volumes>> {
+        self.storage.list_volumes(options).await
+    }
+
+    pub fn list_volumes_stream(&self, options: ListOptions) -> BoxStream<'_, Result<Volumes>> {
+        self.storage.list_volumes_stream(options)
+    }
+
+    pub async fn find_volumes(
         &self,
-        limit: Option<u32>,
-        page: Option<u32>,
+        query: VolumeQuery,
+        options: ListOptions,
     ) -> Result<Vec<Volumes>> {
-        self.storage.list_volumes(limit, page).await
+        self.storage.find_volumes(query, options).await
     }
 
-    pub async fn get_volume(&self, volume_id: Uuid) -> Result<Volumes> {
+    pub fn find_volumes_stream(
+        &self,
+        query: VolumeQuery,
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<Volumes>> {
+        self.storage.find_volumes_stream(query, options)
+    }
+
+    pub async fn get_volume(&self, volume_id: VolumeId) -> Result<Volumes> {
         self.storage.get_volume(volume_id).await
     }
 
-    pub async fn list_snapshots(
+    pub async fn list_snapshots(&self, options: ListOptions) -> Result<Vec<Snapshots>> {
+        self.storage.list_snapshots(options).await
+    }
+
+    pub fn list_snapshots_stream(&self, options: ListOptions) -> BoxStream<'_, Result<Snapshots>> {
+        self.storage.list_snapshots_stream(options)
+    }
+
+    pub async fn find_snapshots(
         &self,
-        limit: Option<u32>,
-        page: Option<u32>,
+        query: SnapshotQuery,
+        options: ListOptions,
     ) -> Result<Vec<Snapshots>> {
-        self.storage.list_snapshots(limit, page).await
+        self.storage.find_snapshots(query, options).await
+    }
+
+    pub fn find_snapshots_stream(
+        &self,
+        query: SnapshotQuery,
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<Snapshots>> {
+        self.storage.find_snapshots_stream(query, options)
+    }
+
+    pub async fn get_snapshot(&self, snapshot_id: SnapshotId) -> Result<Snapshots> {
+        self.storage.get_snapshot(snapshot_id).await
+    }
+
+    pub async fn create_snapshot(
+        &self,
+        volume_id: VolumeId,
+        name: &str,
+        description: Option<&str>,
+    ) -> Result<Operation> {
+        self.storage
+            .create_snapshot(volume_id, name, description)
+            .await
+    }
+
+    pub async fn delete_snapshot(&self, snapshot_id: SnapshotId) -> Result<MutationOutcome> {
+        self.storage.delete_snapshot(snapshot_id).await
+    }
+
+    pub async fn restore_volume_from_snapshot(
+        &self,
+        snapshot_id: SnapshotId,
+        volume_name: &str,
+    ) -> Result<Operation> {
+        self.storage
+            .restore_volume_from_snapshot(snapshot_id, volume_name)
+            .await
+    }
+
+    pub async fn list_regions(&self, options: ListOptions) -> Result<Vec<Regions>> {
+        self.compute.list_regions(options).await
+    }
+
+    pub fn list_regions_stream(&self, options: ListOptions) -> BoxStream<'_, Result<Regions>> {
+        self.compute.list_regions_stream(options)
+    }
+
+    pub async fn list_availability_zones(
+        &self,
+        options: ListOptions,
+    ) -> Result<Vec<AvailabilityZone>> {
+        self.compute.list_availability_zones(options).await
     }
 
-    pub async fn list_regions(&self) -> Result<Vec<Regions>> {
-        self.compute.list_regions().await
+    pub fn list_availability_zones_stream(
+        &self,
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<AvailabilityZone>> {
+        self.compute.list_availability_zones_stream(options)
     }
 
-    pub async fn list_availability_zones(&self) -> Result<Vec<AvailabilityZone>> {
-        self.compute.list_availability_zones().await
+    pub async fn list_networks(&self, options: ListOptions) -> Result<Vec<Networks>> {
+        self.network.list_networks(options).await
     }
 
-    pub async fn list_networks(&self) -> Result<Vec<Networks>> {
-        self.network.list_networks().await
+    pub fn list_networks_stream(&self, options: ListOptions) -> BoxStream<'_, Result<Networks>> {
+        self.network.list_networks_stream(options)
+    }
+
+    pub async fn find_networks(
+        &self,
+        query: NetworkQuery,
+        options: ListOptions,
+    ) -> Result<Vec<Networks>> {
+        self.network.find_networks(query, options).await
+    }
+
+    pub fn find_networks_stream(
+        &self,
+        query: NetworkQuery,
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<Networks>> {
+        self.network.find_networks_stream(query, options)
+    }
+
+    pub async fn create_network(
+        &self,
+        name: &str,
+        description: Option<&str>,
+        dhcp_settings: Option<DhcpSettings>,
+    ) -> Result<Networks> {
+        self.network
+            .create_network(name, description, dhcp_settings)
+            .await
+    }
+
+    pub async fn update_network(
+        &self,
+        network_id: NetworkId,
+        request: UpdateNetworkRequest,
+    ) -> Result<Networks> {
+        self.network.update_network(network_id, request).await
     }
 
     pub async fn list_network_interfaces(
         &self,
-        instance_id: Uuid,
+        instance_id: InstanceId,
+        options: ListOptions,
     ) -> Result<Vec<NetworkInterface>> {
-        self.network.list_network_interfaces(instance_id).await
+        self.network
+            .list_network_interfaces(instance_id, options)
+            .await
     }
 
-    pub async fn list_security_groups(
+    pub async fn list_security_groups(&self, options: ListOptions) -> Result<Vec<SecurityGroups>> {
+        self.network.list_security_groups(options).await
+    }
+
+    pub fn list_security_groups_stream(
         &self,
-        limit: Option<u32>,
-        page: Option<u32>,
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<SecurityGroups>> {
+        self.network.list_security_groups_stream(options)
+    }
+
+    pub async fn find_security_groups(
+        &self,
+        query: SecurityGroupQuery,
+        options: ListOptions,
     ) -> Result<Vec<SecurityGroups>> {
-        self.network.list_security_groups(limit, page).await
+        self.network.find_security_groups(query, options).await
+    }
+
+    pub fn find_security_groups_stream(
+        &self,
+        query: SecurityGroupQuery,
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<SecurityGroups>> {
+        self.network.find_security_groups_stream(query, options)
     }
 
     pub async fn list_security_group_rules(
         &self,
-        security_group_id: Uuid,
+        security_group_id: SecurityGroupId,
+        options: ListOptions,
     ) -> Result<Vec<SecurityGroupRule>> {
         self.network
-            .list_security_group_rules(security_group_id)
+            .list_security_group_rules(security_group_id, options)
+            .await
+    }
+
+    pub async fn create_security_group(
+        &self,
+        name: &str,
+        description: Option<&str>,
+    ) -> Result<SecurityGroups> {
+        self.network.create_security_group(name, description).await
+    }
+
+    pub async fn update_security_group(
+        &self,
+        security_group_id: SecurityGroupId,
+        request: UpdateSecurityGroupRequest,
+    ) -> Result<SecurityGroups> {
+        self.network
+            .update_security_group(security_group_id, request)
+            .await
+    }
+
+    pub async fn add_rule(
+        &self,
+        security_group_id: SecurityGroupId,
+        rule: SecurityGroupRuleSpec,
+    ) -> Result<SecurityGroupRule> {
+        self.network.add_rule(security_group_id, rule).await
+    }
+
+    pub async fn remove_rule(
+        &self,
+        security_group_id: SecurityGroupId,
+        rule_id: SecurityGroupRuleId,
+    ) -> Result<MutationOutcome> {
+        self.network.remove_rule(security_group_id, rule_id).await
+    }
+
+    pub async fn sync_rules(
+        &self,
+        security_group_id: SecurityGroupId,
+        desired_rules: Vec<SecurityGroupRuleSpec>,
+    ) -> Result<RuleSyncResult> {
+        self.network
+            .sync_rules(security_group_id, desired_rules)
             .await
     }
 
-    pub async fn list_vips(&self) -> Result<Vec<VirtualIps>> {
-        self.network.list_vips().await
+    pub async fn list_vips(&self, options: ListOptions) -> Result<Vec<VirtualIps>> {
+        self.network.list_vips(options).await
     }
 
-    pub async fn list_floating_ips(&self) -> Result<Vec<FloatingIps>> {
-        self.network.list_floating_ips().await
+    pub fn list_vips_stream(&self, options: ListOptions) -> BoxStream<'_, Result<VirtualIps>> {
+        self.network.list_vips_stream(options)
     }
 
-    pub async fn list_placement_policies(&self) -> Result<Vec<PlacementPolicy>> {
-        self.compute.list_placement_policies().await
+    pub async fn get_vip(&self, vip_id: VipId) -> Result<VirtualIps> {
+        self.network.get_vip(vip_id).await
     }
 
-    pub async fn list_backups(
+    pub async fn create_vip(&self, request: CreateVipRequest) -> Result<Operation> {
+        self.network.create_vip(request).await
+    }
+
+    pub async fn bind_vip_to_ports(&self, vip_id: VipId, port_ids: &[PortId]) -> Result<Operation> {
+        self.network.bind_vip_to_ports(vip_id, port_ids).await
+    }
+
+    pub async fn unbind_vip_from_ports(
         &self,
-        limit: Option<u32>,
-        page: Option<u32>,
-    ) -> Result<Vec<Backups>> {
-        self.compute.list_backups(limit, page).await
+        vip_id: VipId,
+        port_ids: &[PortId],
+    ) -> Result<Operation> {
+        self.network.unbind_vip_from_ports(vip_id, port_ids).await
     }
 
-    pub async fn list_ssh_keys(
+    pub async fn attach_floating_ip_to_vip(
         &self,
-        limit: Option<u32>,
-        page: Option<u32>,
-    ) -> Result<Vec<SshKeys>> {
-        self.portal.list_ssh_keys(limit, page).await
+        vip_id: VipId,
+        fip_id: FloatingIpId,
+    ) -> Result<Operation> {
+        self.network.attach_floating_ip_to_vip(vip_id, fip_id).await
+    }
+
+    pub async fn detach_floating_ip_from_vip(&self, vip_id: VipId) -> Result<Operation> {
+        self.network.detach_floating_ip_from_vip(vip_id).await
     }
 
-    pub async fn create_ssh_key(&self, request: crate::client::portal::CreateSshKeyRequest) -> Result<SshKeys> {
+    pub async fn list_floating_ips(&self, options: ListOptions) -> Result<Vec<FloatingIps>> {
+        self.network.list_floating_ips(options).await
+    }
+
+    pub fn list_floating_ips_stream(
+        &self,
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<FloatingIps>> {
+        self.network.list_floating_ips_stream(options)
+    }
+
+    pub async fn get_floating_ip(&self, fip_id: FloatingIpId) -> Result<FloatingIps> {
+        self.network.get_floating_ip(fip_id).await
+    }
+
+    pub async fn allocate_floating_ip(
+        &self,
+        region: RegionId,
+        bandwidth: u64,
+    ) -> Result<Operation> {
+        self.network.allocate_floating_ip(region, bandwidth).await
+    }
+
+    pub async fn associate_floating_ip(
+        &self,
+        fip_id: FloatingIpId,
+        target: FloatingIpTarget,
+    ) -> Result<Operation> {
+        self.network.associate_floating_ip(fip_id, target).await
+    }
+
+    pub async fn disassociate_floating_ip(&self, fip_id: FloatingIpId) -> Result<Operation> {
+        self.network.disassociate_floating_ip(fip_id).await
+    }
+
+    pub async fn update_floating_ip_bandwidth(
+        &self,
+        fip_id: FloatingIpId,
+        bandwidth: u64,
+    ) -> Result<Operation> {
+        self.network
+            .update_floating_ip_bandwidth(fip_id, bandwidth)
+            .await
+    }
+
+    pub async fn list_placement_policies(
+        &self,
+        options: ListOptions,
+    ) -> Result<Vec<PlacementPolicy>> {
+        self.compute.list_placement_policies(options).await
+    }
+
+    pub fn list_placement_policies_stream(
+        &self,
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<PlacementPolicy>> {
+        self.compute.list_placement_policies_stream(options)
+    }
+
+    pub async fn get_placement_policy(
+        &self,
+        placement_policy_id: PlacementPolicyId,
+    ) -> Result<PlacementPolicy> {
+        self.compute.get_placement_policy(placement_policy_id).await
+    }
+
+    pub async fn create_placement_policy(
+        &self,
+        request: CreatePlacementPolicyRequest,
+    ) -> Result<PlacementPolicy> {
+        self.compute.create_placement_policy(request).await
+    }
+
+    pub async fn delete_placement_policy(
+        &self,
+        placement_policy_id: PlacementPolicyId,
+    ) -> Result<MutationOutcome> {
+        self.compute
+            .delete_placement_policy(placement_policy_id)
+            .await
+    }
+
+    pub async fn list_placement_policy_instances(
+        &self,
+        placement_policy_id: PlacementPolicyId,
+        options: ListOptions,
+    ) -> Result<Vec<Instances>> {
+        self.compute
+            .list_placement_policy_instances(placement_policy_id, options)
+            .await
+    }
+
+    pub fn list_placement_policy_instances_stream(
+        &self,
+        placement_policy_id: PlacementPolicyId,
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<Instances>> {
+        self.compute
+            .list_placement_policy_instances_stream(placement_policy_id, options)
+    }
+
+    pub async fn list_backups(&self, options: ListOptions) -> Result<Vec<Backups>> {
+        self.compute.list_backups(options).await
+    }
+
+    pub fn list_backups_stream(&self, options: ListOptions) -> BoxStream<'_, Result<Backups>> {
+        self.compute.list_backups_stream(options)
+    }
+
+    pub async fn get_backup(&self, backup_id: BackupId) -> Result<Backups> {
+        self.compute.get_backup(backup_id).await
+    }
+
+    pub async fn create_backup(&self, source: BackupSource) -> Result<Operation> {
+        self.compute.create_backup(source).await
+    }
+
+    pub async fn restore_backup(
+        &self,
+        backup_id: BackupId,
+        target: BackupRestoreTarget,
+    ) -> Result<Operation> {
+        self.compute.restore_backup(backup_id, target).await
+    }
+
+    pub async fn delete_backup(&self, backup_id: BackupId) -> Result<MutationOutcome> {
+        self.compute.delete_backup(backup_id).await
+    }
+
+    pub async fn list_ssh_keys(&self, options: ListOptions) -> Result<Vec<SshKeys>> {
+        self.portal.list_ssh_keys(options).await
+    }
+
+    pub fn list_ssh_keys_stream(&self, options: ListOptions) -> BoxStream<'_, Result<SshKeys>> {
+        self.portal.list_ssh_keys_stream(options)
+    }
+
+    pub async fn create_ssh_key(
+        &self,
+        request: crate::client::portal::CreateSshKeyRequest,
+    ) -> Result<SshKeys> {
         self.portal.create_ssh_key(request).await
     }
 
-    pub async fn delete_ssh_key(&self, ssh_key_id: uuid::Uuid) -> Result<serde_json::Value> {
+    pub async fn get_ssh_key(&self, ssh_key_id: SshKeyId) -> Result<SshKeys> {
+        self.portal.get_ssh_key(ssh_key_id).await
+    }
+
+    pub async fn update_ssh_key(
+        &self,
+        ssh_key_id: SshKeyId,
+        update: SshKeyUpdate,
+    ) -> Result<SshKeys> {
+        self.portal.update_ssh_key(ssh_key_id, update).await
+    }
+
+    pub async fn import_ssh_key_from_file(
+        &self,
+        path: impl AsRef<std::path::Path>,
+        name: &str,
+        login: &str,
+    ) -> Result<SshKeys> {
+        self.portal
+            .import_ssh_key_from_file(path, name, login)
+            .await
+    }
+
+    pub async fn delete_ssh_key(&self, ssh_key_id: SshKeyId) -> Result<MutationOutcome> {
         self.portal.delete_ssh_key(ssh_key_id).await
     }
 
-    pub async fn list_subnets(&self, network_id: Option<&str>) -> Result<Vec<Subnets>> {
-        self.network.list_subnets(network_id).await
+    pub async fn list_subnets(
+        &self,
+        options: ListOptions,
+        network_id: Option<NetworkId>,
+    ) -> Result<Vec<Subnets>> {
+        self.network.list_subnets(options, network_id).await
+    }
+
+    pub fn list_subnets_stream(
+        &self,
+        options: ListOptions,
+        network_id: Option<NetworkId>,
+    ) -> BoxStream<'_, Result<Subnets>> {
+        self.network.list_subnets_stream(options, network_id)
+    }
+
+    pub async fn find_subnets(
+        &self,
+        query: SubnetQuery,
+        options: ListOptions,
+    ) -> Result<Vec<Subnets>> {
+        self.network.find_subnets(query, options).await
+    }
+
+    pub fn find_subnets_stream(
+        &self,
+        query: SubnetQuery,
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<Subnets>> {
+        self.network.find_subnets_stream(query, options)
     }
 
-    pub async fn delete_subnet(&self, subnet_id: Uuid) -> Result<serde_json::Value> {
+    pub async fn create_subnet(&self, request: CreateSubnetRequest) -> Result<Subnets> {
+        self.network.create_subnet(request).await
+    }
+
+    pub async fn update_subnet_dhcp(
+        &self,
+        subnet_id: SubnetId,
+        enable_dhcp: bool,
+        dhcp_settings: DhcpSettings,
+    ) -> Result<Subnets> {
+        self.network
+            .update_subnet_dhcp(subnet_id, enable_dhcp, dhcp_settings)
+            .await
+    }
+
+    pub async fn delete_subnet(&self, subnet_id: SubnetId) -> Result<MutationOutcome> {
         self.network.delete_subnet(subnet_id).await
     }
 
-    pub async fn delete_network(&self, network_id: Uuid) -> Result<serde_json::Value> {
+    pub async fn delete_network(&self, network_id: NetworkId) -> Result<MutationOutcome> {
         self.network.delete_network(network_id).await
     }
 
-    pub async fn delete_fip(&self, fip_id: Uuid) -> Result<serde_json::Value> {
+    pub async fn delete_fip(&self, fip_id: FloatingIpId) -> Result<MutationOutcome> {
         self.network.delete_fip(fip_id).await
     }
 
-    pub async fn delete_security_group(&self, security_group_id: Uuid) -> Result<serde_json::Value> {
+    pub async fn delete_security_group(
+        &self,
+        security_group_id: SecurityGroupId,
+    ) -> Result<MutationOutcome> {
         self.network.delete_security_group(security_group_id).await
     }
 
-    pub async fn delete_route_table(&self, route_table_id: Uuid) -> Result<serde_json::Value> {
+    pub async fn delete_route_table(
+        &self,
+        route_table_id: RouteTableId,
+    ) -> Result<MutationOutcome> {
         self.network.delete_route_table(route_table_id).await
     }
 
     pub async fn list_ports(
         &self,
-        limit: Option<u32>,
-        page: Option<u32>,
+        options: ListOptions,
         filter: PortFilter,
     ) -> Result<Vec<NetworkItem>> {
-        self.network.list_ports(limit, page, filter).await
+        self.network.list_ports(options, filter).await
     }
 
-    pub async fn list_routers(&self) -> Result<Vec<Routers>> {
-        self.network.list_routers().await
+    pub fn list_ports_stream(
+        &self,
+        options: ListOptions,
+        filter: PortFilter,
+    ) -> BoxStream<'_, Result<NetworkItem>> {
+        self.network.list_ports_stream(options, filter)
     }
 
-    pub async fn list_network_routers(
+    pub async fn list_routers(&self, options: ListOptions) -> Result<Vec<Routers>> {
+        self.network.list_routers(options).await
+    }
+
+    pub fn list_routers_stream(&self, options: ListOptions) -> BoxStream<'_, Result<Routers>> {
+        self.network.list_routers_stream(options)
+    }
+
+    pub async fn list_network_routers(&self, options: ListOptions) -> Result<Vec<NetworkRouter>> {
+        self.network.list_network_routers(options).await
+    }
+
+    pub fn list_network_routers_stream(
+        &self,
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<NetworkRouter>> {
+        self.network.list_network_routers_stream(options)
+    }
+
+    pub async fn get_router(&self, router_id: SnatRouterId) -> Result<Routers> {
+        self.network.get_router(router_id).await
+    }
+
+    pub async fn create_snat_router(&self, request: CreateSnatRouterRequest) -> Result<Operation> {
+        self.network.create_snat_router(request).await
+    }
+
+    pub async fn attach_subnet(
         &self,
-        limit: Option<u32>,
-        page: Option<u32>,
-    ) -> Result<Vec<NetworkRouter>> {
-        self.network.list_network_routers(limit, page).await
+        router_id: SnatRouterId,
+        subnet_id: SubnetId,
+    ) -> Result<Operation> {
+        self.network.attach_subnet(router_id, subnet_id).await
     }
 
-    pub async fn delete_router(&self, router_id: Uuid) -> Result<serde_json::Value> {
+    pub async fn detach_subnet(
+        &self,
+        router_id: SnatRouterId,
+        subnet_id: SubnetId,
+    ) -> Result<Operation> {
+        self.network.detach_subnet(router_id, subnet_id).await
+    }
+
+    pub async fn update_bandwidth(
+        &self,
+        router_id: SnatRouterId,
+        bandwidth: u64,
+    ) -> Result<Operation> {
+        self.network.update_bandwidth(router_id, bandwidth).await
+    }
+
+    pub async fn get_network_router(&self, router_id: NetworkRouterId) -> Result<NetworkRouter> {
+        self.network.get_network_router(router_id).await
+    }
+
+    pub async fn create_network_router(
+        &self,
+        name: &str,
+        description: Option<&str>,
+    ) -> Result<NetworkRouter> {
+        self.network.create_network_router(name, description).await
+    }
+
+    pub async fn list_router_interfaces(
+        &self,
+        router_id: NetworkRouterId,
+        options: ListOptions,
+    ) -> Result<Vec<NetworkRouterInterface>> {
+        self.network
+            .list_router_interfaces(router_id, options)
+            .await
+    }
+
+    pub async fn add_router_interface(
+        &self,
+        router_id: NetworkRouterId,
+        request: AddRouterInterfaceRequest,
+    ) -> Result<NetworkRouterInterface> {
+        self.network.add_router_interface(router_id, request).await
+    }
+
+    pub async fn remove_router_interface(
+        &self,
+        router_id: NetworkRouterId,
+        interface_id: RouterInterfaceId,
+    ) -> Result<MutationOutcome> {
+        self.network
+            .remove_router_interface(router_id, interface_id)
+            .await
+    }
+
+    pub async fn delete_router(&self, router_id: SnatRouterId) -> Result<MutationOutcome> {
         self.network.delete_router(router_id).await
     }
 
-    pub async fn delete_network_router(&self, router_id: Uuid) -> Result<serde_json::Value> {
+    pub async fn delete_network_router(
+        &self,
+        router_id: NetworkRouterId,
+    ) -> Result<MutationOutcome> {
         self.network.delete_network_router(router_id).await
     }
 
-    pub async fn delete_vip(&self, vip_id: Uuid) -> Result<serde_json::Value> {
+    pub async fn delete_vip(&self, vip_id: VipId) -> Result<MutationOutcome> {
         self.network.delete_vip(vip_id).await
     }
 
-    pub async fn delete_port(&self, port_id: Uuid) -> Result<serde_json::Value> {
+    pub async fn get_port(&self, port_id: PortId) -> Result<NicPort> {
+        self.network.get_port(port_id).await
+    }
+
+    pub async fn create_port(&self, request: CreatePortRequest) -> Result<Operation> {
+        self.network.create_port(request).await
+    }
+
+    pub async fn update_port_security_groups(
+        &self,
+        port_id: PortId,
+        security_group_ids: &[SecurityGroupId],
+    ) -> Result<Operation> {
+        self.network
+            .update_port_security_groups(port_id, security_group_ids)
+            .await
+    }
+
+    pub async fn attach_port_to_instance(
+        &self,
+        port_id: PortId,
+        instance_id: InstanceId,
+    ) -> Result<Operation> {
+        self.network
+            .attach_port_to_instance(port_id, instance_id)
+            .await
+    }
+
+    pub async fn detach_port_from_instance(&self, port_id: PortId) -> Result<Operation> {
+        self.network.detach_port_from_instance(port_id).await
+    }
+
+    pub async fn delete_port(&self, port_id: PortId) -> Result<MutationOutcome> {
         self.network.delete_port(port_id).await
     }
 
-    pub async fn list_route_tables(
+    pub async fn list_route_tables(&self, options: ListOptions) -> Result<Vec<RouteTables>> {
+        self.network.list_route_tables(options).await
+    }
+
+    pub fn list_route_tables_stream(
         &self,
-        limit: Option<u32>,
-        page: Option<u32>,
-    ) -> Result<Vec<RouteTables>> {
-        self.network.list_route_tables(limit, page).await
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<RouteTables>> {
+        self.network.list_route_tables_stream(options)
     }
 
-    pub async fn list_tasks(&self) -> Result<Vec<Tasks>> {
-        self.compute.list_tasks().await
+    pub async fn get_route_table(&self, route_table_id: RouteTableId) -> Result<RouteTables> {
+        self.network.get_route_table(route_table_id).await
     }
 
-    pub async fn get_task(&self, task_id: Uuid) -> Result<Tasks> {
+    pub async fn create_route_table(
+        &self,
+        request: CreateRouteTableRequest,
+    ) -> Result<RouteTables> {
+        self.network.create_route_table(request).await
+    }
+
+    pub async fn add_route(
+        &self,
+        route_table_id: RouteTableId,
+        route: RouteSpec,
+    ) -> Result<RouteTables> {
+        self.network.add_route(route_table_id, route).await
+    }
+
+    pub async fn remove_route(
+        &self,
+        route_table_id: RouteTableId,
+        prefix: &str,
+    ) -> Result<RouteTables> {
+        self.network.remove_route(route_table_id, prefix).await
+    }
+
+    pub async fn replace_routes(
+        &self,
+        route_table_id: RouteTableId,
+        routes: Vec<RouteSpec>,
+    ) -> Result<RouteTables> {
+        self.network.replace_routes(route_table_id, routes).await
+    }
+
+    pub async fn associate_network(
+        &self,
+        route_table_id: RouteTableId,
+        network_id: NetworkId,
+    ) -> Result<RouteTables> {
+        self.network
+            .associate_network(route_table_id, network_id)
+            .await
+    }
+
+    pub async fn disassociate_network(
+        &self,
+        route_table_id: RouteTableId,
+        network_id: NetworkId,
+    ) -> Result<MutationOutcome> {
+        self.network
+            .disassociate_network(route_table_id, network_id)
+            .await
+    }
+
+    pub async fn list_tasks(&self, filter: TaskFilter, options: ListOptions) -> Result<Vec<Tasks>> {
+        self.compute.list_tasks(filter, options).await
+    }
+
+    pub fn list_tasks_stream(
+        &self,
+        filter: TaskFilter,
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<Tasks>> {
+        self.compute.list_tasks_stream(filter, options)
+    }
+
+    pub async fn get_task(&self, task_id: TaskId) -> Result<Tasks> {
         self.compute.get_task(task_id).await
     }
 
+    #[deprecated(note = "use `get::<T>` with a typed id, or await the task's `Operation`")]
     pub async fn get_resource(&self, object_type: &str, object_id: Uuid) -> Result<Resource> {
-        match object_type {
-            "instance" | "Instance" => {
-                let instance = self.get_instance(object_id).await?;
-                Ok(Resource::Instance(instance))
-            }
-            "flavor" | "Flavor" => {
-                let flavor = self.get_flavor(object_id).await?;
-                Ok(Resource::Flavor(flavor))
-            }
-            "image" | "Image" | "shared_image" | "SharedImage" => {
-                let image = self.get_image(object_id).await?;
-                Ok(Resource::Image(image))
-            }
-            "volume_type" | "VolumeType" => {
-                let volume_type = self.get_volume_type(object_id).await?;
-                Ok(Resource::VolumeType(volume_type))
-            }
-            "volume" | "Volume" => {
-                let volume = self.get_volume(object_id).await?;
-                Ok(Resource::Volume(volume))
-            }
-            "network" | "Network" => {
-                let network = self.network.get_network(object_id).await?;
-                Ok(Resource::Network(network))
-            }
-            "security_group" | "SecurityGroup" => {
-                let sg = self.network.get_security_group(object_id).await?;
-                Ok(Resource::SecurityGroup(sg))
-            }
-            "placement_policy" | "PlacementPolicy" => {
-                let pp = self.compute.get_placement_policy(object_id).await?;
-                Ok(Resource::PlacementPolicy(pp))
-            }
-            "region" | "Region" => {
-                let region = self.compute.get_region(object_id).await?;
-                Ok(Resource::Region(region))
-            }
-            "availability_zone" | "AvailabilityZone" => {
-                let az = self.compute.get_availability_zone(object_id).await?;
-                Ok(Resource::AvailabilityZone(az))
-            }
-            "task" | "Task" => {
-                let task = self.get_task(object_id).await?;
-                Ok(Resource::Task(task))
-            }
-            _ => Err(ComputeError::validation(
-                Service::Global,
-                None,
-                format!("Unknown resource type: {}", object_type),
-            )),
+        self.fetch_task_object(object_type, object_id).await
+    }
+
+    /// Fetches the object a task refers to, resolving its type from the
+    /// task's `object_type`.
+    pub(crate) async fn fetch_task_object(
+        &self,
+        object_type: &str,
+        object_id: Uuid,
+    ) -> Result<Resource> {
+        macro_rules! dispatch {
+            ($($variant:ident($ty:ty)),+ $(,)?) => {
+                $(if <$ty>::matches_kind(object_type) {
+                    return self.get::<$ty>(object_id.into()).await.map(Resource::$variant);
+                })+
+            };
         }
+
+        dispatch!(
+            Instance(Instances),
+            Flavor(Flavors),
+            Image(Images),
+            VolumeType(VolumeType),
+            Volume(Volumes),
+            Snapshot(Snapshots),
+            Network(Networks),
+            FloatingIp(FloatingIps),
+            Vip(VirtualIps),
+            Port(NicPort),
+            Router(Routers),
+            NetworkRouter(NetworkRouter),
+            SecurityGroup(SecurityGroups),
+            PlacementPolicy(PlacementPolicy),
+            Region(Regions),
+            AvailabilityZone(AvailabilityZone),
+            Task(Tasks),
+            Backup(Backups),
+        );
+        Err(ComputeError::validation(
+            Service::Global,
+            None,
+            format!("Unknown resource type: {}", object_type),
+        ))
+    }
+
+    pub async fn wait_instance_status(
+        &self,
+        instance_id: InstanceId,
+        target: InstanceStatus,
+        config: WaitConfig,
+    ) -> std::result::Result<Instances, OperationError> {
+        wait_until(
+            || self.get_instance(instance_id),
+            |instance| match &instance.status {
+                status if *status == target => WaitStatus::Ready,
+                InstanceStatus::Error => {
+                    WaitStatus::Failed(format!("instance {} is in error state", instance_id))
+                }
+                _ => WaitStatus::Pending,
+            },
+            config,
+        )
+        .await
+    }
+
+    pub async fn wait_volume_status(
+        &self,
+        volume_id: VolumeId,
+        target: VolumeStatus,
+        config: WaitConfig,
+    ) -> std::result::Result<Volumes, OperationError> {
+        wait_until(
+            || self.get_volume(volume_id),
+            |volume| match &volume.status {
+                status if *status == target => WaitStatus::Ready,
+                VolumeStatus::Error | VolumeStatus::ErrorDeleting => WaitStatus::Failed(format!(
+                    "volume {} is in {:?} state",
+                    volume_id, volume.status
+                )),
+                _ => WaitStatus::Pending,
+            },
+            config,
+        )
+        .await
+    }
+
+    pub async fn wait_image_active(
+        &self,
+        image_id: ImageId,
+        config: WaitConfig,
+    ) -> std::result::Result<Images, OperationError> {
+        wait_until(
+            || self.get_image(image_id),
+            |image| match image.status {
+                ImageStatus::Active => WaitStatus::Ready,
+                ImageStatus::Error | ImageStatus::Killed => {
+                    WaitStatus::Failed(format!("image {} is in {:?} state", image_id, image.status))
+                }
+                _ => WaitStatus::Pending,
+            },
+            config,
+        )
+        .await
+    }
+
+    pub async fn wait_deleted<T: CloudResource>(
+        &self,
+        id: T::Id,
+        config: WaitConfig,
+    ) -> std::result::Result<(), OperationError> {
+        wait_deleted(|| T::get(&self.client, &id), config).await
     }
 }
@@ -0,0 +1,131 @@
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cidr {
+    addr: IpAddr,
+    prefix_len: u8,
+}
+
+impl Cidr {
+    pub fn new(addr: IpAddr, prefix_len: u8) -> Result<Self, String> {
+        let max = max_prefix_len(&addr);
+        if prefix_len > max {
+            return Err(format!(
+                "prefix length {} exceeds maximum of {} for {}",
+                prefix_len, max, addr
+            ));
+        }
+        let cidr = Self { addr, prefix_len };
+        if cidr.network() != addr {
+            return Err(format!(
+                "{}/{} has host bits set, expected {}",
+                addr,
+                prefix_len,
+                cidr.with_addr(cidr.network())
+            ));
+        }
+        Ok(cidr)
+    }
+
+    pub fn addr(&self) -> IpAddr {
+        self.addr
+    }
+
+    pub fn prefix_len(&self) -> u8 {
+        self.prefix_len
+    }
+
+    pub fn is_ipv4(&self) -> bool {
+        self.addr.is_ipv4()
+    }
+
+    pub fn network(&self) -> IpAddr {
+        match self.addr {
+            IpAddr::V4(a) => IpAddr::V4(Ipv4Addr::from(u32::from(a) & v4_mask(self.prefix_len))),
+            IpAddr::V6(a) => IpAddr::V6(Ipv6Addr::from(u128::from(a) & v6_mask(self.prefix_len))),
+        }
+    }
+
+    pub fn broadcast(&self) -> Option<IpAddr> {
+        match self.addr {
+            IpAddr::V4(a) => Some(IpAddr::V4(Ipv4Addr::from(
+                u32::from(a) | !v4_mask(self.prefix_len),
+            ))),
+            IpAddr::V6(_) => None,
+        }
+    }
+
+    pub fn contains(&self, ip: &IpAddr) -> bool {
+        match (self.addr, ip) {
+            (IpAddr::V4(net), IpAddr::V4(ip)) => {
+                let mask = v4_mask(self.prefix_len);
+                u32::from(net) & mask == u32::from(*ip) & mask
+            }
+            (IpAddr::V6(net), IpAddr::V6(ip)) => {
+                let mask = v6_mask(self.prefix_len);
+                u128::from(net) & mask == u128::from(*ip) & mask
+            }
+            _ => false,
+        }
+    }
+
+    pub fn overlaps(&self, other: &Cidr) -> bool {
+        self.contains(&other.addr) || other.contains(&self.addr)
+    }
+
+    pub fn is_usable_host(&self, ip: &IpAddr) -> bool {
+        if !self.contains(ip) {
+            return false;
+        }
+        match self.addr {
+            IpAddr::V4(_) if self.prefix_len >= 31 => true,
+            IpAddr::V4(_) => *ip != self.network() && Some(*ip) != self.broadcast(),
+            IpAddr::V6(_) => *ip != self.network(),
+        }
+    }
+
+    fn with_addr(&self, addr: IpAddr) -> String {
+        format!("{}/{}", addr, self.prefix_len)
+    }
+}
+
+impl FromStr for Cidr {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (addr, prefix) = s
+            .trim()
+            .split_once('/')
+            .ok_or_else(|| format!("invalid CIDR '{}': missing prefix length", s))?;
+        let addr: IpAddr = addr
+            .parse()
+            .map_err(|_| format!("invalid CIDR '{}': bad address", s))?;
+        let prefix_len: u8 = prefix
+            .parse()
+            .map_err(|_| format!("invalid CIDR '{}': bad prefix length", s))?;
+        Cidr::new(addr, prefix_len).map_err(|e| format!("invalid CIDR '{}': {}", s, e))
+    }
+}
+
+impl fmt::Display for Cidr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.addr, self.prefix_len)
+    }
+}
+
+fn max_prefix_len(addr: &IpAddr) -> u8 {
+    match addr {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    }
+}
+
+fn v4_mask(prefix_len: u8) -> u32 {
+    u32::MAX.checked_shl(32 - prefix_len as u32).unwrap_or(0)
+}
+
+fn v6_mask(prefix_len: u8) -> u128 {
+    u128::MAX.checked_shl(128 - prefix_len as u32).unwrap_or(0)
+}
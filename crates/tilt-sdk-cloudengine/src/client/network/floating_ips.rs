@@ -0,0 +1,134 @@
+use super::{NetworkClient, VPC_SERVICE};
+use crate::client::request::ServiceClient;
+use crate::client::resource::CloudResource;
+use crate::client::responses::TaskResponse;
+use crate::error::{ComputeError, Result};
+use crate::models::{FloatingIpId, FloatingIps, PortId, RegionId, SnatRouterId, VipId};
+use crate::operations::Operation;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AllocateFloatingIpRequest {
+    pub region: RegionId,
+    pub bandwidth: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FloatingIpTarget {
+    Port(PortId),
+    Vip(VipId),
+    Router(SnatRouterId),
+}
+
+impl FloatingIpTarget {
+    fn item_type(&self) -> &'static str {
+        match self {
+            FloatingIpTarget::Port(_) => "nic",
+            FloatingIpTarget::Vip(_) => "vip",
+            FloatingIpTarget::Router(_) => "snat_router",
+        }
+    }
+
+    fn item_id(&self) -> Uuid {
+        match *self {
+            FloatingIpTarget::Port(id) => id.into(),
+            FloatingIpTarget::Vip(id) => id.into(),
+            FloatingIpTarget::Router(id) => id.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct AssociateFloatingIpAttrs {
+    parent_item_id: Uuid,
+    parent_item_type: &'static str,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct UpdateBandwidthAttrs {
+    bandwidth: u64,
+}
+
+impl NetworkClient {
+    fn floating_ip_path(&self, fip_id: FloatingIpId) -> String {
+        format!(
+            "/vpc/api/v1/projects/{}/public-ip-addresses/{}",
+            self.client.project(),
+            fip_id
+        )
+    }
+
+    async fn floating_ip_action<A: serde::Serialize>(
+        &self,
+        fip_id: FloatingIpId,
+        action: &str,
+        attrs: A,
+    ) -> Result<Operation> {
+        self.item_action(&self.floating_ip_path(fip_id), action, attrs)
+            .await
+    }
+
+    pub async fn get_floating_ip(&self, fip_id: FloatingIpId) -> Result<FloatingIps> {
+        FloatingIps::get(&self.client, &fip_id).await
+    }
+
+    pub async fn allocate_floating_ip(
+        &self,
+        region: RegionId,
+        bandwidth: u64,
+    ) -> Result<Operation> {
+        let path = format!(
+            "/vpc/api/v1/projects/{}/public-ip-addresses",
+            self.client.project()
+        );
+        if bandwidth == 0 {
+            return Err(ComputeError::validation(
+                VPC_SERVICE,
+                Some(&path),
+                "bandwidth must be greater than zero".to_string(),
+            ));
+        }
+        let body = AllocateFloatingIpRequest { region, bandwidth };
+        let response: TaskResponse = self.post(&path, &body).await?;
+        Ok(Operation::for_task(response.data.into(), &self.client))
+    }
+
+    pub async fn associate_floating_ip(
+        &self,
+        fip_id: FloatingIpId,
+        target: FloatingIpTarget,
+    ) -> Result<Operation> {
+        let attrs = AssociateFloatingIpAttrs {
+            parent_item_id: target.item_id(),
+            parent_item_type: target.item_type(),
+        };
+        self.floating_ip_action(fip_id, "associate_public_ip", attrs)
+            .await
+    }
+
+    pub async fn disassociate_floating_ip(&self, fip_id: FloatingIpId) -> Result<Operation> {
+        self.floating_ip_action(fip_id, "disassociate_public_ip", serde_json::json!({}))
+            .await
+    }
+
+    pub async fn update_floating_ip_bandwidth(
+        &self,
+        fip_id: FloatingIpId,
+        bandwidth: u64,
+    ) -> Result<Operation> {
+        if bandwidth == 0 {
+            return Err(ComputeError::validation(
+                VPC_SERVICE,
+                Some(&self.floating_ip_path(fip_id)),
+                "bandwidth must be greater than zero".to_string(),
+            ));
+        }
+        self.floating_ip_action(
+            fip_id,
+            "change_bandwidth",
+            UpdateBandwidthAttrs { bandwidth },
+        )
+        .await
+    }
+}
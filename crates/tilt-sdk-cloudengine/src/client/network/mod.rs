@@ -1,22 +1,131 @@
-use crate::client::paginate::paginate;
-use crate::client::responses::{NetworkRoutersResponse, RouteTablesResponse};
+use crate::client::compute::order_action;
+use crate::client::paginate::{ListOptions, PrefetchOptions};
+use crate::client::query::ResourceQuery;
+use crate::client::request::ServiceClient;
+use crate::client::resource::{CloudResource, DeletableResource};
+use crate::client::responses::OrderItemRef;
 use crate::error::{ComputeError, Result, Service};
 use crate::models::{
-    FloatingIps, NetworkInterface, NetworkItem, NetworkRouter, Networks, RouteTables, Routers,
-    SecurityGroupRule, SecurityGroups, Subnets, VirtualIps,
+    DhcpSettings, FloatingIpId, FloatingIps, InstanceId, NetworkId, NetworkInterface, NetworkItem,
+    NetworkRouter, NetworkRouterId, Networks, NicPort, PortId, RouteTableId, RouteTables, Routers,
+    SecurityGroupId, SecurityGroupRule, SecurityGroups, SnatRouterId, SubnetId, Subnets, VipId,
+    VirtualIps,
 };
+use crate::operations::{MutationOutcome, Operation};
+use futures::stream::{BoxStream, StreamExt, TryStreamExt};
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
 use tilt_sdk::Client;
-use tracing::{Instrument, debug, info_span};
-use uuid::Uuid;
 
-use super::responses::{
-    FloatingIpsResponse, NetworkInterfacesResponse, NetworksResponse, PortsResponse,
-    RoutersResponse, SecurityGroupRulesResponse, SecurityGroupsResponse, SubnetsResponse,
-    VipsResponse,
+use super::responses::{NetworkInterfacesResponse, SecurityGroupRulesResponse};
+
+mod cidr;
+mod floating_ips;
+mod ports;
+mod route_tables;
+mod routers;
+mod security_groups;
+mod vips;
+
+pub use cidr::Cidr;
+pub use floating_ips::{AllocateFloatingIpRequest, FloatingIpTarget};
+pub use ports::CreatePortRequest;
+pub use route_tables::{CreateRouteTableRequest, NextHopType, RouteSpec, validate_routes};
+pub use routers::{AddRouterInterfaceRequest, CreateNetworkRouterRequest, CreateSnatRouterRequest};
+pub use security_groups::{
+    CreateSecurityGroupRequest, EtherType, RuleDiff, RuleDirection, RuleProtocol, RuleSyncResult,
+    SecurityGroupRuleBuilder, SecurityGroupRuleSpec, UpdateSecurityGroupRequest,
 };
+pub use vips::{CreateVipRequest, VipAddressMode};
 
 const VPC_SERVICE: Service = Service::VpcApi;
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateNetworkRequest {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dhcp_settings: Option<DhcpSettings>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateNetworkRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dhcp_settings: Option<DhcpSettings>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateSubnetRequest {
+    pub network_id: NetworkId,
+    pub name: String,
+    pub cidr: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gateway_ip: Option<String>,
+    pub enable_dhcp: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dhcp_settings: Option<DhcpSettings>,
+}
+
+impl CreateSubnetRequest {
+    pub fn validate(&self) -> std::result::Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("subnet name must not be empty".to_string());
+        }
+        let cidr: Cidr = self.cidr.parse()?;
+        if let Some(gateway) = &self.gateway_ip {
+            let ip: IpAddr = gateway
+                .parse()
+                .map_err(|_| format!("invalid gateway IP '{}'", gateway))?;
+            if !cidr.is_usable_host(&ip) {
+                return Err(format!(
+                    "gateway {} is not a usable address in {}",
+                    ip, cidr
+                ));
+            }
+        }
+        if let Some(settings) = &self.dhcp_settings {
+            validate_dhcp_settings(settings)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateSubnetDhcpRequest {
+    pub enable_dhcp: bool,
+    pub dhcp_settings: DhcpSettings,
+}
+
+pub fn validate_dhcp_settings(settings: &DhcpSettings) -> std::result::Result<(), String> {
+    if settings.dns.method.trim().is_empty() {
+        return Err("DHCP DNS method must not be empty".to_string());
+    }
+    if let Some(domain) = &settings.domain_name
+        && (domain.is_empty()
+            || domain.len() > 253
+            || domain.split('.').any(|label| {
+                label.is_empty()
+                    || label.len() > 63
+                    || label.starts_with('-')
+                    || label.ends_with('-')
+                    || !label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+            }))
+    {
+        return Err(format!("invalid DHCP domain name '{}'", domain));
+    }
+    if settings.ntp_servers.iter().any(|s| s.trim().is_empty()) {
+        return Err("NTP server entries must not be empty".to_string());
+    }
+    Ok(())
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum PortFilter {
     All,
@@ -34,71 +143,211 @@ impl PortFilter {
     }
 }
 
-pub struct NetworkClient<'a> {
-    client: &'a Client,
+#[derive(Clone)]
+pub struct NetworkClient {
+    client: Client,
+    prefetch: Option<PrefetchOptions>,
 }
 
-impl<'a> NetworkClient<'a> {
-    pub fn new(client: &'a Client) -> Self {
-        Self { client }
-    }
-
-    async fn get<T: serde::de::DeserializeOwned>(&self, path: &str) -> Result<T> {
-        let span = info_span!("network_get", path);
-        async move {
-            debug!(path, "Fetching resource");
-            match self.client.http().get(path).await {
-                Ok(response) => {
-                    debug!(path, "Successfully fetched resource");
-                    Ok(response)
-                }
-                Err(e) => Err(ComputeError::from_sdk_error(e, VPC_SERVICE, Some(path))),
-            }
+impl ServiceClient for NetworkClient {
+    const SERVICE: Service = VPC_SERVICE;
+
+    fn http_client(&self) -> &Client {
+        &self.client
+    }
+}
+
+impl NetworkClient {
+    pub fn new(client: &Client) -> Self {
+        Self {
+            client: client.clone(),
+            prefetch: None,
         }
-        .instrument(span)
-        .await
     }
 
-    async fn get_with_query<T: serde::de::DeserializeOwned>(
+    pub fn with_prefetch(mut self, prefetch: PrefetchOptions) -> Self {
+        self.prefetch = Some(prefetch);
+        self
+    }
+
+    async fn item_action<A: serde::Serialize>(
         &self,
-        path: &str,
-        query: &[(&str, &str)],
-    ) -> Result<T> {
-        let span = info_span!("network_get_with_query", path);
-        async move {
-            debug!(path, ?query, "Fetching resources with query");
-            match self.client.http().get_with_query(path, query).await {
-                Ok(response) => {
-                    debug!(path, ?query, "Successfully fetched resources");
-                    Ok(response)
-                }
-                Err(e) => Err(ComputeError::from_sdk_error(e, VPC_SERVICE, Some(path))),
-            }
+        item_path: &str,
+        action: &str,
+        attrs: A,
+    ) -> Result<Operation> {
+        let item: OrderItemRef = self.get(item_path).await?;
+        if item.order_id.is_nil() || item.item_id.is_nil() {
+            return Err(ComputeError::validation(
+                VPC_SERVICE,
+                Some(item_path),
+                "item has no order reference".to_string(),
+            ));
         }
-        .instrument(span)
-        .await
+        let task = order_action(&self.client, item.order_id, item.item_id, action, attrs).await?;
+        Ok(Operation::for_task(task, &self.client))
     }
 
-    async fn delete(&self, path: &str) -> Result<serde_json::Value> {
-        let span = info_span!("network_delete", path);
-        async move {
-            debug!(path, "Deleting resource");
-            self.client.http().delete::<serde_json::Value>(path).await
-                .map_err(|e| ComputeError::from_sdk_error(e, VPC_SERVICE, Some(path)))
+    /// Checks that `subnet_id` belongs to `network_id` and, when `ip` is given,
+    /// that it is a usable host address in that subnet.
+    async fn check_subnet_address(
+        &self,
+        path: &str,
+        network_id: NetworkId,
+        subnet_id: SubnetId,
+        ip: Option<IpAddr>,
+    ) -> Result<()> {
+        let subnets = self
+            .list_subnets(ListOptions::all(), Some(network_id))
+            .await?;
+        let subnet = subnets.iter().find(|s| s.id == subnet_id).ok_or_else(|| {
+            ComputeError::validation(
+                VPC_SERVICE,
+                Some(path),
+                format!(
+                    "subnet {} does not belong to network {}",
+                    subnet_id, network_id
+                ),
+            )
+        })?;
+        let Some(ip) = ip else {
+            return Ok(());
+        };
+        let cidr: Cidr = subnet.cidr.parse().map_err(|e| {
+            ComputeError::validation(
+                VPC_SERVICE,
+                Some(path),
+                format!("subnet {} has an invalid CIDR: {}", subnet_id, e),
+            )
+        })?;
+        if !cidr.is_usable_host(&ip) {
+            return Err(ComputeError::validation(
+                VPC_SERVICE,
+                Some(path),
+                format!("fixed IP {} is not a usable address in {}", ip, cidr),
+            ));
         }
-        .instrument(span)
-        .await
+        Ok(())
+    }
+
+    pub async fn list_networks(&self, options: ListOptions) -> Result<Vec<Networks>> {
+        self.list_networks_stream(options).try_collect().await
     }
 
-    pub async fn list_networks(&self) -> Result<Vec<Networks>> {
+    pub fn list_networks_stream(&self, options: ListOptions) -> BoxStream<'_, Result<Networks>> {
+        Networks::list_stream(&self.client, self.prefetch, options)
+    }
+
+    pub async fn find_networks(
+        &self,
+        query: ResourceQuery<Networks>,
+        options: ListOptions,
+    ) -> Result<Vec<Networks>> {
+        let path = Networks::collection_path(self.client.project());
+        query
+            .collect(&path, options, |query| {
+                self.find_networks_stream(query, options)
+            })
+            .await
+    }
+
+    pub fn find_networks_stream(
+        &self,
+        query: ResourceQuery<Networks>,
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<Networks>> {
+        let path = Networks::collection_path(self.client.project());
+        query.filter_stream(&path, options, |params| {
+            Networks::list_stream_with(&self.client, self.prefetch, options, params)
+        })
+    }
+
+    pub async fn create_network(
+        &self,
+        name: &str,
+        description: Option<&str>,
+        dhcp_settings: Option<DhcpSettings>,
+    ) -> Result<Networks> {
         let path = format!("/vpc/api/v1/projects/{}/networks", self.client.project());
-        let response: NetworksResponse = self.get_with_query(&path, &[]).await?;
-        Ok(response.into_iter().map(Into::into).collect())
+        if name.trim().is_empty() {
+            return Err(ComputeError::validation(
+                VPC_SERVICE,
+                Some(&path),
+                "network name must not be empty".to_string(),
+            ));
+        }
+        if let Some(settings) = &dhcp_settings {
+            validate_dhcp_settings(settings)
+                .map_err(|e| ComputeError::validation(VPC_SERVICE, Some(&path), e))?;
+        }
+        let body = CreateNetworkRequest {
+            name: name.to_string(),
+            description: description.map(str::to_string),
+            dhcp_settings,
+        };
+        let response: super::responses::NetworkWrapper = self.post(&path, &body).await?;
+        Ok(response.into())
+    }
+
+    pub async fn update_network(
+        &self,
+        network_id: NetworkId,
+        request: UpdateNetworkRequest,
+    ) -> Result<Networks> {
+        let path = format!(
+            "/vpc/api/v1/projects/{}/networks/{}",
+            self.client.project(),
+            network_id
+        );
+        if request.name.as_deref().is_some_and(|n| n.trim().is_empty()) {
+            return Err(ComputeError::validation(
+                VPC_SERVICE,
+                Some(&path),
+                "network name must not be empty".to_string(),
+            ));
+        }
+        if let Some(settings) = &request.dhcp_settings {
+            validate_dhcp_settings(settings)
+                .map_err(|e| ComputeError::validation(VPC_SERVICE, Some(&path), e))?;
+        }
+        let response: super::responses::NetworkWrapper = self.patch(&path, &request).await?;
+        Ok(response.into())
+    }
+
+    pub async fn create_subnet(&self, request: CreateSubnetRequest) -> Result<Subnets> {
+        let path = format!("/vpc/api/v1/projects/{}/subnets", self.client.project());
+        request
+            .validate()
+            .map_err(|e| ComputeError::validation(VPC_SERVICE, Some(&path), e))?;
+        let response: super::responses::SubnetWrapper = self.post(&path, &request).await?;
+        Ok(response.into())
+    }
+
+    pub async fn update_subnet_dhcp(
+        &self,
+        subnet_id: SubnetId,
+        enable_dhcp: bool,
+        dhcp_settings: DhcpSettings,
+    ) -> Result<Subnets> {
+        let path = format!(
+            "/vpc/api/v1/projects/{}/subnets/{}/dhcp",
+            self.client.project(),
+            subnet_id
+        );
+        validate_dhcp_settings(&dhcp_settings)
+            .map_err(|e| ComputeError::validation(VPC_SERVICE, Some(&path), e))?;
+        let body = UpdateSubnetDhcpRequest {
+            enable_dhcp,
+            dhcp_settings,
+        };
+        let response: super::responses::SubnetWrapper = self.put(&path, &body).await?;
+        Ok(response.into())
     }
 
     pub async fn list_network_interfaces(
         &self,
-        instance_id: Uuid,
+        instance_id: InstanceId,
+        options: ListOptions,
     ) -> Result<Vec<NetworkInterface>> {
         let path = format!(
             "/order-service/api/v1/projects/{}/compute/instances/{}/network-interfaces",
@@ -106,50 +355,50 @@ impl<'a> NetworkClient<'a> {
             instance_id
         );
         let response: NetworkInterfacesResponse = self.get(&path).await?;
-        Ok(response.interfaces.into_iter().map(Into::into).collect())
+        Ok(options.window(response.interfaces.into_iter().map(Into::into).collect()))
+    }
+
+    pub async fn list_security_groups(&self, options: ListOptions) -> Result<Vec<SecurityGroups>> {
+        self.list_security_groups_stream(options)
+            .try_collect()
+            .await
     }
 
-    pub async fn list_security_groups(
+    pub fn list_security_groups_stream(
         &self,
-        limit: Option<u32>,
-        page: Option<u32>,
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<SecurityGroups>> {
+        SecurityGroups::list_stream(&self.client, self.prefetch, options)
+    }
+
+    pub async fn find_security_groups(
+        &self,
+        query: ResourceQuery<SecurityGroups>,
+        options: ListOptions,
     ) -> Result<Vec<SecurityGroups>> {
-        let path = format!(
-            "/vpc/api/v1/projects/{}/security-groups",
-            self.client.project()
-        );
+        let path = SecurityGroups::collection_path(self.client.project());
+        query
+            .collect(&path, options, |query| {
+                self.find_security_groups_stream(query, options)
+            })
+            .await
+    }
 
-        let this = &self;
-        paginate(
-            limit,
-            page,
-            Service::VpcApi,
-            &path,
-            |page: u32, limit: u32| {
-                let path = path.clone();
-                async move {
-                    let response: SecurityGroupsResponse = this
-                        .get_with_query(
-                            &path,
-                            &[
-                                ("include", "total_count"),
-                                ("page", &page.to_string()),
-                                ("per_page", &limit.to_string()),
-                            ],
-                        )
-                        .await?;
-                    let items: Vec<SecurityGroups> = response.into_iter().map(Into::into).collect();
-                    let total_count = items.len() as u32;
-                    Ok((items, total_count))
-                }
-            },
-        )
-        .await
+    pub fn find_security_groups_stream(
+        &self,
+        query: ResourceQuery<SecurityGroups>,
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<SecurityGroups>> {
+        let path = SecurityGroups::collection_path(self.client.project());
+        query.filter_stream(&path, options, |params| {
+            SecurityGroups::list_stream_with(&self.client, self.prefetch, options, params)
+        })
     }
 
     pub async fn list_security_group_rules(
         &self,
-        security_group_id: Uuid,
+        security_group_id: SecurityGroupId,
+        options: ListOptions,
     ) -> Result<Vec<SecurityGroupRule>> {
         let path = format!(
             "/vpc/api/v1/projects/{}/security-groups/{}/rules",
@@ -157,345 +406,200 @@ impl<'a> NetworkClient<'a> {
             security_group_id
         );
         let response: SecurityGroupRulesResponse = self.get_with_query(&path, &[]).await?;
-        Ok(response.into_iter().map(Into::into).collect())
+        Ok(options.window(response.into_iter().map(Into::into).collect()))
     }
 
-    pub async fn list_vips(&self) -> Result<Vec<VirtualIps>> {
-        let path = format!(
-            "/vpc/api/v1/projects/{}/virtual-ip-addresses",
-            self.client.project()
-        );
-        let response: VipsResponse = self.get_with_query(&path, &[("entire", "true")]).await?;
-        Ok(response.list.into_iter().map(Into::into).collect())
+    pub async fn list_vips(&self, options: ListOptions) -> Result<Vec<VirtualIps>> {
+        self.list_vips_stream(options).try_collect().await
     }
 
-    pub async fn list_floating_ips(&self) -> Result<Vec<FloatingIps>> {
-        let path = format!(
-            "/vpc/api/v1/projects/{}/public-ip-addresses",
-            self.client.project()
-        );
-        let response: FloatingIpsResponse =
-            self.get_with_query(&path, &[("entire", "true")]).await?;
-        Ok(response.list.into_iter().map(Into::into).collect())
+    pub fn list_vips_stream(&self, options: ListOptions) -> BoxStream<'_, Result<VirtualIps>> {
+        VirtualIps::list_stream(&self.client, self.prefetch, options)
     }
 
-    pub async fn list_subnets(&self, network_id: Option<&str>) -> Result<Vec<Subnets>> {
-        let path = format!("/vpc/api/v1/projects/{}/subnets", self.client.project());
+    pub async fn list_floating_ips(&self, options: ListOptions) -> Result<Vec<FloatingIps>> {
+        self.list_floating_ips_stream(options).try_collect().await
+    }
 
-        let query: Vec<(&str, &str)> = if let Some(id) = network_id {
-            vec![("network_id", id)]
-        } else {
-            vec![]
-        };
+    pub fn list_floating_ips_stream(
+        &self,
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<FloatingIps>> {
+        FloatingIps::list_stream(&self.client, self.prefetch, options)
+    }
 
-        let response: SubnetsResponse = self.get_with_query(&path, &query).await?;
-        Ok(response.into_iter().map(Into::into).collect())
+    pub async fn list_subnets(
+        &self,
+        options: ListOptions,
+        network_id: Option<NetworkId>,
+    ) -> Result<Vec<Subnets>> {
+        self.list_subnets_stream(options, network_id)
+            .try_collect()
+            .await
     }
 
-    pub async fn delete_subnet(&self, subnet_id: Uuid) -> Result<serde_json::Value> {
-        let path = format!(
-            "/vpc/api/v1/projects/{}/subnets/{}",
-            self.client.project(),
-            subnet_id
-        );
-        self.delete(&path).await
+    pub fn list_subnets_stream(
+        &self,
+        options: ListOptions,
+        network_id: Option<NetworkId>,
+    ) -> BoxStream<'_, Result<Subnets>> {
+        let params = network_id
+            .map(|id| vec![("network_id", id.to_string())])
+            .unwrap_or_default();
+        Subnets::list_stream_with(&self.client, self.prefetch, options, params)
     }
 
-    pub async fn delete_network(&self, network_id: Uuid) -> Result<serde_json::Value> {
-        let path = format!(
-            "/vpc/api/v1/projects/{}/networks/{}",
-            self.client.project(),
-            network_id
-        );
-        self.delete(&path).await
+    pub async fn find_subnets(
+        &self,
+        query: ResourceQuery<Subnets>,
+        options: ListOptions,
+    ) -> Result<Vec<Subnets>> {
+        let path = Subnets::collection_path(self.client.project());
+        query
+            .collect(&path, options, |query| {
+                self.find_subnets_stream(query, options)
+            })
+            .await
     }
 
-    pub async fn delete_fip(&self, fip_id: Uuid) -> Result<serde_json::Value> {
-        let path = format!(
-            "/vpc/api/v1/projects/{}/public-ip-addresses/{}",
-            self.client.project(),
-            fip_id
-        );
-        self.delete(&path).await
+    pub fn find_subnets_stream(
+        &self,
+        query: ResourceQuery<Subnets>,
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<Subnets>> {
+        let path = Subnets::collection_path(self.client.project());
+        query.filter_stream(&path, options, |params| {
+            Subnets::list_stream_with(&self.client, self.prefetch, options, params)
+        })
     }
 
-    pub async fn delete_security_group(&self, security_group_id: Uuid) -> Result<serde_json::Value> {
-        let path = format!(
-            "/vpc/api/v1/projects/{}/security-groups/{}",
-            self.client.project(),
-            security_group_id
-        );
-        self.delete(&path).await
+    pub async fn delete_subnet(&self, subnet_id: SubnetId) -> Result<MutationOutcome> {
+        Subnets::delete(&self.client, &subnet_id).await
     }
 
-    pub async fn delete_route_table(&self, route_table_id: Uuid) -> Result<serde_json::Value> {
-        let path = format!(
-            "/vpc/api/v1/projects/{}/network_route_tables/{}",
-            self.client.project(),
-            route_table_id
-        );
-        self.delete(&path).await
+    pub async fn delete_network(&self, network_id: NetworkId) -> Result<MutationOutcome> {
+        Networks::delete(&self.client, &network_id).await
+    }
+
+    pub async fn delete_fip(&self, fip_id: FloatingIpId) -> Result<MutationOutcome> {
+        FloatingIps::delete(&self.client, &fip_id).await
+    }
+
+    pub async fn delete_security_group(
+        &self,
+        security_group_id: SecurityGroupId,
+    ) -> Result<MutationOutcome> {
+        SecurityGroups::delete(&self.client, &security_group_id).await
+    }
+
+    pub async fn delete_route_table(
+        &self,
+        route_table_id: RouteTableId,
+    ) -> Result<MutationOutcome> {
+        RouteTables::delete(&self.client, &route_table_id).await
     }
 
     pub async fn list_ports(
         &self,
-        limit: Option<u32>,
-        page: Option<u32>,
+        options: ListOptions,
         filter: PortFilter,
     ) -> Result<Vec<NetworkItem>> {
-        let path = format!(
-            "/vpc/api/v1/projects/{}/network-interfaces",
-            self.client.project()
-        );
-
-        match limit {
-            Some(input_limit) => {
-                let limit = super::clamp_page_limit(input_limit);
-                let page = page.unwrap_or(1);
-                let response: PortsResponse = self
-                    .get_with_query(
-                        &path,
-                        &[
-                            ("projectName", self.client.project()),
-                            ("entire", "true"),
-                            ("page", &page.to_string()),
-                            ("per_page", &limit.to_string()),
-                        ],
-                    )
-                    .await?;
-
-                let mut items = Vec::new();
-
-                for port in response.list {
-                    if filter.matches(&port.type_field) {
-                        items.push(port.clone().into_network_item());
-                    }
-
-                    if let Some(ref parent_item) = port.data.parent_item
-                        && parent_item.type_field == "instance"
-                        && (filter == PortFilter::All || filter == PortFilter::Instance)
-                    {
-                        items.push(NetworkItem::Instance(
-                            parent_item.clone().into_instance(&port.created_at),
-                        ));
-                    }
-                }
-
-                Ok(items)
-            }
-            None => {
-                let mut all_items: Vec<NetworkItem> = Vec::new();
-                let mut page = 1;
-                let per_page = 100;
-                let mut first_page_total: Option<usize> = None;
-
-                loop {
-                    let response: PortsResponse = self
-                        .get_with_query(
-                            &path,
-                            &[
-                                ("projectName", self.client.project()),
-                                ("entire", "true"),
-                                ("page", &page.to_string()),
-                                ("per_page", &per_page.to_string()),
-                            ],
-                        )
-                        .await?;
-
-                    if first_page_total.is_none() {
-                        first_page_total = Some(response.meta.total_count);
-                    }
-
-                    if response.list.is_empty() {
-                        break;
-                    }
-
-                    for port in &response.list {
-                        let has_instance_parent = port.data.parent_item.is_some()
-                            && port.data.parent_item.as_ref().unwrap().type_field == "instance";
+        self.list_ports_stream(options, filter).try_collect().await
+    }
 
-                        if filter.matches(&port.type_field) || has_instance_parent {
-                            all_items.push(port.clone().into_network_item());
+    pub fn list_ports_stream(
+        &self,
+        options: ListOptions,
+        filter: PortFilter,
+    ) -> BoxStream<'_, Result<NetworkItem>> {
+        NicPort::list_items(&self.client, self.prefetch, options, Vec::new())
+            .flat_map(move |port| {
+                let items = match port {
+                    Ok(port) => {
+                        let mut items = Vec::new();
+                        let instance_parent = port
+                            .data
+                            .parent_item
+                            .as_ref()
+                            .filter(|parent| parent.type_field == "instance");
+                        if filter.matches(&port.type_field) || instance_parent.is_some() {
+                            items.push(Ok(port.clone().into_network_item()));
                         }
-
-                        if has_instance_parent
+                        if let Some(parent_item) = instance_parent
                             && (filter == PortFilter::All || filter == PortFilter::Instance)
                         {
-                            all_items.push(NetworkItem::Instance(
-                                port.data
-                                    .parent_item
-                                    .as_ref()
-                                    .unwrap()
-                                    .clone()
-                                    .into_instance(&port.created_at),
-                            ));
+                            items.push(Ok(NetworkItem::Instance(
+                                parent_item.clone().into_instance(&port.created_at),
+                            )));
                         }
+                        items
                     }
+                    Err(e) => vec![Err(e)],
+                };
+                futures::stream::iter(items)
+            })
+            .boxed()
+    }
 
-                    let total_count = first_page_total.unwrap_or(0);
-                    let expected_pages = (total_count as f64 / per_page as f64).ceil() as u32;
-                    if all_items.len() >= total_count || page >= expected_pages {
-                        break;
-                    }
-
-                    page += 1;
-
-                    if page > 100 {
-                        return Err(ComputeError::validation(
-                            Service::VpcApi,
-                            Some(&path),
-                            format!(
-                                "Pagination limit reached. Fetched {} of {} records",
-                                all_items.len(),
-                                total_count
-                            ),
-                        ));
-                    }
-                }
+    pub async fn list_routers(&self, options: ListOptions) -> Result<Vec<Routers>> {
+        self.list_routers_stream(options).try_collect().await
+    }
 
-                Ok(all_items)
-            }
-        }
+    pub fn list_routers_stream(&self, options: ListOptions) -> BoxStream<'_, Result<Routers>> {
+        Routers::list_stream(&self.client, self.prefetch, options)
     }
 
-    pub async fn list_routers(&self) -> Result<Vec<Routers>> {
-        let path = format!(
-            "/vpc/api/v1/projects/{}/snat-routers",
-            self.client.project()
-        );
-        let response: RoutersResponse = self.get_with_query(&path, &[("entire", "true")]).await?;
-        Ok(response.list.into_iter().map(Into::into).collect())
+    pub async fn delete_port(&self, port_id: PortId) -> Result<MutationOutcome> {
+        NicPort::delete(&self.client, &port_id).await
     }
 
-    pub async fn delete_port(&self, port_id: Uuid) -> Result<serde_json::Value> {
-        let path = format!(
-            "/vpc/api/v1/projects/{}/network-interfaces/{}",
-            self.client.project(),
-            port_id
-        );
-        self.delete(&path).await
+    pub async fn list_route_tables(&self, options: ListOptions) -> Result<Vec<RouteTables>> {
+        self.list_route_tables_stream(options).try_collect().await
     }
 
-    pub async fn list_route_tables(
+    pub fn list_route_tables_stream(
         &self,
-        limit: Option<u32>,
-        page: Option<u32>,
-    ) -> Result<Vec<RouteTables>> {
-        let path = format!(
-            "/vpc/api/v1/projects/{}/network_route_tables",
-            self.client.project()
-        );
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<RouteTables>> {
+        RouteTables::list_stream(&self.client, self.prefetch, options)
+    }
 
-        let this = &self;
-        paginate(
-            limit,
-            page,
-            Service::VpcApi,
-            &path,
-            |page: u32, limit: u32| {
-                let path = path.clone();
-                async move {
-                    let response: RouteTablesResponse = this
-                        .get_with_query(
-                            &path,
-                            &[
-                                ("include", "total_count"),
-                                ("page", &page.to_string()),
-                                ("per_page", &limit.to_string()),
-                            ],
-                        )
-                        .await?;
-                    let items: Vec<RouteTables> =
-                        response.list.into_iter().map(Into::into).collect();
-                    Ok((items, response.meta.total_count))
-                }
-            },
-        )
-        .await
-    }
-
-    pub async fn get_network(&self, network_id: Uuid) -> Result<Networks> {
-        let path = format!(
-            "/vpc/api/v1/projects/{}/networks/{}",
-            self.client.project(),
-            network_id
-        );
-        let response: super::responses::NetworkWrapper = self.get(&path).await?;
-        Ok(response.into())
+    pub async fn get_network(&self, network_id: NetworkId) -> Result<Networks> {
+        Networks::get(&self.client, &network_id).await
     }
 
-    pub async fn get_security_group(&self, security_group_id: Uuid) -> Result<SecurityGroups> {
-        let path = format!(
-            "/api/v1/projects/{}/security-groups/{}",
-            self.client.project(),
-            security_group_id
-        );
-        let response: super::responses::SecurityGroupWrapper = self.get(&path).await?;
-        Ok(response.into())
+    pub async fn get_security_group(
+        &self,
+        security_group_id: SecurityGroupId,
+    ) -> Result<SecurityGroups> {
+        SecurityGroups::get(&self.client, &security_group_id).await
+    }
+
+    pub async fn list_network_routers(&self, options: ListOptions) -> Result<Vec<NetworkRouter>> {
+        self.list_network_routers_stream(options)
+            .try_collect()
+            .await
     }
 
-    pub async fn list_network_routers(
+    pub fn list_network_routers_stream(
         &self,
-        limit: Option<u32>,
-        page: Option<u32>,
-    ) -> Result<Vec<NetworkRouter>> {
-        let path = format!(
-            "/vpc/api/v1/projects/{}/routers",
-            self.client.project()
-        );
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<NetworkRouter>> {
+        NetworkRouter::list_stream(&self.client, self.prefetch, options)
+    }
 
-        let this = &self;
-        paginate(
-            limit,
-            page,
-            Service::VpcApi,
-            &path,
-            |page: u32, limit: u32| {
-                let path = path.clone();
-                async move {
-                    let response: NetworkRoutersResponse = this
-                        .get_with_query(
-                            &path,
-                            &[
-                                ("include", "total_count"),
-                                ("page", &page.to_string()),
-                                ("per_page", &limit.to_string()),
-                            ],
-                        )
-                        .await?;
-                    let items: Vec<NetworkRouter> =
-                        response.list.into_iter().map(Into::into).collect();
-                    Ok((items, response.meta.total_count))
-                }
-            },
-        )
-        .await
-    }
-
-    pub async fn delete_network_router(&self, router_id: Uuid) -> Result<serde_json::Value> {
-        let path = format!(
-            "/vpc/api/v1/projects/{}/routers/{}",
-            self.client.project(),
-            router_id
-        );
-        self.delete(&path).await
+    pub async fn delete_network_router(
+        &self,
+        router_id: NetworkRouterId,
+    ) -> Result<MutationOutcome> {
+        NetworkRouter::delete(&self.client, &router_id).await
     }
 
-    pub async fn delete_router(&self, router_id: Uuid) -> Result<serde_json::Value> {
-        let path = format!(
-            "/vpc/api/v1/projects/{}/snat-routers/{}",
-            self.client.project(),
-            router_id
-        );
-        self.delete(&path).await
+    pub async fn delete_router(&self, router_id: SnatRouterId) -> Result<MutationOutcome> {
+        Routers::delete(&self.client, &router_id).await
     }
 
-    pub async fn delete_vip(&self, vip_id: Uuid) -> Result<serde_json::Value> {
-        let path = format!(
-            "/vpc/api/v1/projects/{}/virtual-ip-addresses/{}",
-            self.client.project(),
-            vip_id
-        );
-        self.delete(&path).await
+    pub async fn delete_vip(&self, vip_id: VipId) -> Result<MutationOutcome> {
+        VirtualIps::delete(&self.client, &vip_id).await
     }
 }
@@ -0,0 +1,107 @@
+use super::{NetworkClient, VPC_SERVICE};
+use crate::client::request::ServiceClient;
+use crate::client::resource::CloudResource;
+use crate::client::responses::TaskResponse;
+use crate::error::{ComputeError, Result};
+use crate::models::{InstanceId, NetworkId, NicPort, PortId, SecurityGroupId, SubnetId};
+use crate::operations::Operation;
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatePortRequest {
+    pub name: String,
+    pub network_id: NetworkId,
+    pub subnet_id: SubnetId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed_ip: Option<String>,
+    #[serde(default)]
+    pub security_group_ids: Vec<SecurityGroupId>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct PortSecurityGroupsAttrs<'s> {
+    security_group_ids: &'s [SecurityGroupId],
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct PortInstanceAttrs {
+    instance_id: InstanceId,
+}
+
+impl NetworkClient {
+    fn port_path(&self, port_id: PortId) -> String {
+        format!(
+            "/vpc/api/v1/projects/{}/network-interfaces/{}",
+            self.client.project(),
+            port_id
+        )
+    }
+
+    pub async fn get_port(&self, port_id: PortId) -> Result<NicPort> {
+        NicPort::get(&self.client, &port_id).await
+    }
+
+    pub async fn create_port(&self, request: CreatePortRequest) -> Result<Operation> {
+        let path = format!(
+            "/vpc/api/v1/projects/{}/network-interfaces",
+            self.client.project()
+        );
+        if request.name.trim().is_empty() {
+            return Err(ComputeError::validation(
+                VPC_SERVICE,
+                Some(&path),
+                "port name must not be empty".to_string(),
+            ));
+        }
+        let ip = match &request.fixed_ip {
+            Some(fixed_ip) => Some(fixed_ip.parse::<IpAddr>().map_err(|_| {
+                ComputeError::validation(
+                    VPC_SERVICE,
+                    Some(&path),
+                    format!("invalid fixed IP '{}'", fixed_ip),
+                )
+            })?),
+            None => None,
+        };
+        self.check_subnet_address(&path, request.network_id, request.subnet_id, ip)
+            .await?;
+        let response: TaskResponse = self.post(&path, &request).await?;
+        Ok(Operation::for_task(response.data.into(), &self.client))
+    }
+
+    pub async fn update_port_security_groups(
+        &self,
+        port_id: PortId,
+        security_group_ids: &[SecurityGroupId],
+    ) -> Result<Operation> {
+        self.item_action(
+            &self.port_path(port_id),
+            "update_security_groups",
+            PortSecurityGroupsAttrs { security_group_ids },
+        )
+        .await
+    }
+
+    pub async fn attach_port_to_instance(
+        &self,
+        port_id: PortId,
+        instance_id: InstanceId,
+    ) -> Result<Operation> {
+        self.item_action(
+            &self.port_path(port_id),
+            "attach_to_instance",
+            PortInstanceAttrs { instance_id },
+        )
+        .await
+    }
+
+    pub async fn detach_port_from_instance(&self, port_id: PortId) -> Result<Operation> {
+        self.item_action(
+            &self.port_path(port_id),
+            "detach_from_instance",
+            serde_json::json!({}),
+        )
+        .await
+    }
+}
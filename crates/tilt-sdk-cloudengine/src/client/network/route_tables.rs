@@ -0,0 +1,292 @@
+use super::{Cidr, NetworkClient, VPC_SERVICE};
+use crate::client::paginate::ListOptions;
+use crate::client::request::ServiceClient;
+use crate::client::resource::CloudResource;
+use crate::client::responses::RouteTableWrapper;
+use crate::error::{ComputeError, Result};
+use crate::models::{NetworkId, RouteTableId, RouteTableRoute, RouteTables};
+use crate::operations::{DeleteResponse, MutationOutcome};
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NextHopType {
+    #[serde(rename = "ip_address")]
+    IpAddress,
+    #[serde(rename = "interface")]
+    Interface,
+}
+
+impl NextHopType {
+    fn from_api(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "ip_address" => Some(NextHopType::IpAddress),
+            "interface" => Some(NextHopType::Interface),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RouteSpec {
+    pub prefix: String,
+    pub next_hop: String,
+    pub next_hop_type: NextHopType,
+}
+
+impl RouteSpec {
+    pub fn new(
+        prefix: impl Into<String>,
+        next_hop: impl Into<String>,
+        next_hop_type: NextHopType,
+    ) -> Self {
+        Self {
+            prefix: prefix.into(),
+            next_hop: next_hop.into(),
+            next_hop_type,
+        }
+    }
+}
+
+impl TryFrom<&RouteTableRoute> for RouteSpec {
+    type Error = String;
+
+    fn try_from(route: &RouteTableRoute) -> std::result::Result<Self, Self::Error> {
+        let next_hop_type = NextHopType::from_api(&route.next_hop_type)
+            .ok_or_else(|| format!("unknown next hop type '{}'", route.next_hop_type))?;
+        Ok(RouteSpec {
+            prefix: route.prefix.clone(),
+            next_hop: route.next_hop.clone(),
+            next_hop_type,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateRouteTableRequest {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub routes: Vec<RouteSpec>,
+    #[serde(default)]
+    pub network_ids: Vec<NetworkId>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct ReplaceRoutesRequest<'r> {
+    routes: &'r [RouteSpec],
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct AssociateNetworkRequest {
+    network_id: NetworkId,
+}
+
+/// Rejects duplicate or overlapping prefixes, malformed IP next hops and IP next
+/// hops outside `subnets`.
+/// IP next hops are also rejected when `subnets` is empty, since the route table
+/// has no associated network to reach them through.
+pub fn validate_routes(routes: &[RouteSpec], subnets: &[Cidr]) -> std::result::Result<(), String> {
+    let mut prefixes: Vec<Cidr> = Vec::with_capacity(routes.len());
+
+    for route in routes {
+        let prefix: Cidr = route.prefix.parse()?;
+        if let Some(existing) = prefixes.iter().find(|p| p.overlaps(&prefix)) {
+            return Err(if *existing == prefix {
+                format!("duplicate route prefix {}", prefix)
+            } else {
+                format!("route prefix {} overlaps {}", prefix, existing)
+            });
+        }
+        prefixes.push(prefix);
+
+        if route.next_hop.trim().is_empty() {
+            return Err(format!("route {} has an empty next hop", prefix));
+        }
+        if route.next_hop_type == NextHopType::IpAddress {
+            let hop: IpAddr = route.next_hop.parse().map_err(|_| {
+                format!(
+                    "next hop {} for {} is not an IP address",
+                    route.next_hop, prefix
+                )
+            })?;
+            if subnets.is_empty() {
+                return Err(format!(
+                    "next hop {} for {} cannot be checked without associated networks",
+                    hop, prefix
+                ));
+            }
+            if !subnets.iter().any(|s| s.contains(&hop)) {
+                return Err(format!(
+                    "next hop {} for {} is outside the associated networks",
+                    hop, prefix
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn current_routes(table: &RouteTables) -> std::result::Result<Vec<RouteSpec>, String> {
+    table.routes.iter().map(RouteSpec::try_from).collect()
+}
+
+impl NetworkClient {
+    fn route_table_path(&self, route_table_id: RouteTableId) -> String {
+        format!(
+            "/vpc/api/v1/projects/{}/network_route_tables/{}",
+            self.client.project(),
+            route_table_id
+        )
+    }
+
+    async fn network_cidrs(&self, path: &str, network_ids: &[NetworkId]) -> Result<Vec<Cidr>> {
+        let mut cidrs = Vec::new();
+        for network_id in network_ids {
+            let subnets = self
+                .list_subnets(ListOptions::all(), Some(*network_id))
+                .await?;
+            for subnet in subnets {
+                let cidr = subnet.cidr.parse::<Cidr>().map_err(|e| {
+                    ComputeError::validation(
+                        VPC_SERVICE,
+                        Some(path),
+                        format!("subnet {} has an invalid CIDR: {}", subnet.id, e),
+                    )
+                })?;
+                cidrs.push(cidr);
+            }
+        }
+        Ok(cidrs)
+    }
+
+    pub async fn get_route_table(&self, route_table_id: RouteTableId) -> Result<RouteTables> {
+        RouteTables::get(&self.client, &route_table_id).await
+    }
+
+    pub async fn create_route_table(
+        &self,
+        request: CreateRouteTableRequest,
+    ) -> Result<RouteTables> {
+        let path = format!(
+            "/vpc/api/v1/projects/{}/network_route_tables",
+            self.client.project()
+        );
+        if request.name.trim().is_empty() {
+            return Err(ComputeError::validation(
+                VPC_SERVICE,
+                Some(&path),
+                "route table name must not be empty".to_string(),
+            ));
+        }
+        let subnets = self.network_cidrs(&path, &request.network_ids).await?;
+        validate_routes(&request.routes, &subnets)
+            .map_err(|e| ComputeError::validation(VPC_SERVICE, Some(&path), e))?;
+        let response: RouteTableWrapper = self.post(&path, &request).await?;
+        Ok(response.into())
+    }
+
+    pub async fn replace_routes(
+        &self,
+        route_table_id: RouteTableId,
+        routes: Vec<RouteSpec>,
+    ) -> Result<RouteTables> {
+        let table = self.get_route_table(route_table_id).await?;
+        self.put_routes(&table, &routes).await
+    }
+
+    /// Appends `route` to the table's current routes and replaces the whole set.
+    ///
+    /// The API only accepts the complete route list, so this is a read-modify-write:
+    /// a concurrent change to the same table between the read and the write is lost.
+    pub async fn add_route(
+        &self,
+        route_table_id: RouteTableId,
+        route: RouteSpec,
+    ) -> Result<RouteTables> {
+        let table = self.get_route_table(route_table_id).await?;
+        let mut routes = current_routes(&table).map_err(|e| {
+            ComputeError::validation(VPC_SERVICE, Some(&self.route_table_path(route_table_id)), e)
+        })?;
+        routes.push(route);
+        self.put_routes(&table, &routes).await
+    }
+
+    /// Removes the route for `prefix` and replaces the whole set.
+    ///
+    /// Like [`add_route`](Self::add_route), this is an unguarded read-modify-write.
+    pub async fn remove_route(
+        &self,
+        route_table_id: RouteTableId,
+        prefix: &str,
+    ) -> Result<RouteTables> {
+        let table = self.get_route_table(route_table_id).await?;
+        let target: Cidr = prefix.parse().map_err(|e| {
+            ComputeError::validation(VPC_SERVICE, Some(&self.route_table_path(route_table_id)), e)
+        })?;
+        let mut routes = current_routes(&table).map_err(|e| {
+            ComputeError::validation(VPC_SERVICE, Some(&self.route_table_path(route_table_id)), e)
+        })?;
+        let before = routes.len();
+        routes.retain(|r| r.prefix.parse::<Cidr>().ok() != Some(target));
+        if routes.len() == before {
+            return Err(ComputeError::validation(
+                VPC_SERVICE,
+                Some(&self.route_table_path(route_table_id)),
+                format!("route table has no route for {}", target),
+            ));
+        }
+        self.put_routes(&table, &routes).await
+    }
+
+    async fn put_routes(&self, table: &RouteTables, routes: &[RouteSpec]) -> Result<RouteTables> {
+        let path = format!("{}/routes", self.route_table_path(table.id));
+        let network_ids: Vec<NetworkId> = table.networks.iter().map(|n| n.id).collect();
+        let subnets = self.network_cidrs(&path, &network_ids).await?;
+        validate_routes(routes, &subnets)
+            .map_err(|e| ComputeError::validation(VPC_SERVICE, Some(&path), e))?;
+        let response: RouteTableWrapper = self.put(&path, &ReplaceRoutesRequest { routes }).await?;
+        Ok(response.into())
+    }
+
+    pub async fn associate_network(
+        &self,
+        route_table_id: RouteTableId,
+        network_id: NetworkId,
+    ) -> Result<RouteTables> {
+        let path = format!("{}/networks", self.route_table_path(route_table_id));
+        let response: RouteTableWrapper = self
+            .post(&path, &AssociateNetworkRequest { network_id })
+            .await?;
+        Ok(response.into())
+    }
+
+    /// Detaches `network_id` after checking the remaining routes still reach their
+    /// IP next hops through the networks left on the table.
+    pub async fn disassociate_network(
+        &self,
+        route_table_id: RouteTableId,
+        network_id: NetworkId,
+    ) -> Result<MutationOutcome> {
+        let path = format!(
+            "{}/networks/{}",
+            self.route_table_path(route_table_id),
+            network_id
+        );
+        let table = self.get_route_table(route_table_id).await?;
+        let routes = current_routes(&table)
+            .map_err(|e| ComputeError::validation(VPC_SERVICE, Some(&path), e))?;
+        let network_ids: Vec<NetworkId> = table
+            .networks
+            .iter()
+            .map(|n| n.id)
+            .filter(|id| *id != network_id)
+            .collect();
+        let subnets = self.network_cidrs(&path, &network_ids).await?;
+        validate_routes(&routes, &subnets)
+            .map_err(|e| ComputeError::validation(VPC_SERVICE, Some(&path), e))?;
+        self.delete(&path, DeleteResponse::Empty).await
+    }
+}
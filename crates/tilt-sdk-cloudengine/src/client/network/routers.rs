@@ -0,0 +1,204 @@
+use super::{NetworkClient, VPC_SERVICE};
+use crate::client::paginate::ListOptions;
+use crate::client::request::ServiceClient;
+use crate::client::resource::CloudResource;
+use crate::client::responses::{
+    NetworkRouterInterfaceWrapper, NetworkRouterInterfacesResponse, NetworkRouterWrapper,
+    TaskResponse,
+};
+use crate::error::{ComputeError, Result};
+use crate::models::{
+    NetworkRouter, NetworkRouterId, NetworkRouterInterface, RouterInterfaceId, Routers,
+    SnatRouterId, SubnetId,
+};
+use crate::operations::Operation;
+use crate::operations::{DeleteResponse, MutationOutcome};
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateSnatRouterRequest {
+    pub name: String,
+    pub availability_zone: String,
+    pub bandwidth: u64,
+    #[serde(default)]
+    pub subnet_ids: Vec<SubnetId>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateNetworkRouterRequest {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddRouterInterfaceRequest {
+    pub subnet_id: SubnetId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<IpAddr>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct RouterSubnetAttrs {
+    subnet_id: SubnetId,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct RouterBandwidthAttrs {
+    bandwidth: u64,
+}
+
+impl NetworkClient {
+    fn snat_router_path(&self, router_id: SnatRouterId) -> String {
+        format!(
+            "/vpc/api/v1/projects/{}/snat-routers/{}",
+            self.client.project(),
+            router_id
+        )
+    }
+
+    fn network_router_path(&self, router_id: NetworkRouterId) -> String {
+        format!(
+            "/vpc/api/v1/projects/{}/routers/{}",
+            self.client.project(),
+            router_id
+        )
+    }
+
+    pub async fn get_router(&self, router_id: SnatRouterId) -> Result<Routers> {
+        Routers::get(&self.client, &router_id).await
+    }
+
+    pub async fn create_snat_router(&self, request: CreateSnatRouterRequest) -> Result<Operation> {
+        let path = format!(
+            "/vpc/api/v1/projects/{}/snat-routers",
+            self.client.project()
+        );
+        let invalid = if request.name.trim().is_empty() {
+            Some("router name must not be empty")
+        } else if request.availability_zone.trim().is_empty() {
+            Some("availability zone must not be empty")
+        } else if request.bandwidth == 0 {
+            Some("bandwidth must be greater than zero")
+        } else {
+            None
+        };
+        if let Some(message) = invalid {
+            return Err(ComputeError::validation(
+                VPC_SERVICE,
+                Some(&path),
+                message.to_string(),
+            ));
+        }
+        let response: TaskResponse = self.post(&path, &request).await?;
+        Ok(Operation::for_task(response.data.into(), &self.client))
+    }
+
+    pub async fn attach_subnet(
+        &self,
+        router_id: SnatRouterId,
+        subnet_id: SubnetId,
+    ) -> Result<Operation> {
+        self.item_action(
+            &self.snat_router_path(router_id),
+            "attach_subnet",
+            RouterSubnetAttrs { subnet_id },
+        )
+        .await
+    }
+
+    pub async fn detach_subnet(
+        &self,
+        router_id: SnatRouterId,
+        subnet_id: SubnetId,
+    ) -> Result<Operation> {
+        self.item_action(
+            &self.snat_router_path(router_id),
+            "detach_subnet",
+            RouterSubnetAttrs { subnet_id },
+        )
+        .await
+    }
+
+    pub async fn update_bandwidth(
+        &self,
+        router_id: SnatRouterId,
+        bandwidth: u64,
+    ) -> Result<Operation> {
+        let path = self.snat_router_path(router_id);
+        if bandwidth == 0 {
+            return Err(ComputeError::validation(
+                VPC_SERVICE,
+                Some(&path),
+                "bandwidth must be greater than zero".to_string(),
+            ));
+        }
+        self.item_action(
+            &path,
+            "change_bandwidth",
+            RouterBandwidthAttrs { bandwidth },
+        )
+        .await
+    }
+
+    pub async fn get_network_router(&self, router_id: NetworkRouterId) -> Result<NetworkRouter> {
+        NetworkRouter::get(&self.client, &router_id).await
+    }
+
+    /// Network routers are created synchronously by the VPC API, so unlike
+    /// [`create_snat_router`](Self::create_snat_router) there is no task to track.
+    pub async fn create_network_router(
+        &self,
+        name: &str,
+        description: Option<&str>,
+    ) -> Result<NetworkRouter> {
+        let path = format!("/vpc/api/v1/projects/{}/routers", self.client.project());
+        if name.trim().is_empty() {
+            return Err(ComputeError::validation(
+                VPC_SERVICE,
+                Some(&path),
+                "router name must not be empty".to_string(),
+            ));
+        }
+        let body = CreateNetworkRouterRequest {
+            name: name.to_string(),
+            description: description.map(str::to_string),
+        };
+        let response: NetworkRouterWrapper = self.post(&path, &body).await?;
+        Ok(response.into())
+    }
+
+    pub async fn list_router_interfaces(
+        &self,
+        router_id: NetworkRouterId,
+        options: ListOptions,
+    ) -> Result<Vec<NetworkRouterInterface>> {
+        let path = format!("{}/interfaces", self.network_router_path(router_id));
+        let response: NetworkRouterInterfacesResponse = self.get_with_query(&path, &[]).await?;
+        Ok(options.window(response.into_iter().map(Into::into).collect()))
+    }
+
+    pub async fn add_router_interface(
+        &self,
+        router_id: NetworkRouterId,
+        request: AddRouterInterfaceRequest,
+    ) -> Result<NetworkRouterInterface> {
+        let path = format!("{}/interfaces", self.network_router_path(router_id));
+        let response: NetworkRouterInterfaceWrapper = self.post(&path, &request).await?;
+        Ok(response.into())
+    }
+
+    pub async fn remove_router_interface(
+        &self,
+        router_id: NetworkRouterId,
+        interface_id: RouterInterfaceId,
+    ) -> Result<MutationOutcome> {
+        let path = format!(
+            "{}/interfaces/{}",
+            self.network_router_path(router_id),
+            interface_id
+        );
+        self.delete(&path, DeleteResponse::Empty).await
+    }
+}
@@ -0,0 +1,387 @@
+use super::{Cidr, NetworkClient, VPC_SERVICE};
+use crate::client::paginate::ListOptions;
+use crate::client::request::ServiceClient;
+use crate::client::responses::{SecurityGroupRuleWrapper, SecurityGroupWrapper};
+use crate::error::{ComputeError, Result};
+use crate::models::{SecurityGroupId, SecurityGroupRule, SecurityGroupRuleId, SecurityGroups};
+use crate::operations::{DeleteResponse, MutationOutcome};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RuleDirection {
+    #[serde(rename = "ingress")]
+    Ingress,
+    #[serde(rename = "egress")]
+    Egress,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum EtherType {
+    IPv4,
+    IPv6,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RuleProtocol {
+    #[serde(rename = "any")]
+    Any,
+    #[serde(rename = "tcp")]
+    Tcp,
+    #[serde(rename = "udp")]
+    Udp,
+    #[serde(rename = "icmp")]
+    Icmp,
+}
+
+impl RuleProtocol {
+    fn from_api(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "" | "any" | "null" => Some(RuleProtocol::Any),
+            "tcp" => Some(RuleProtocol::Tcp),
+            "udp" => Some(RuleProtocol::Udp),
+            "icmp" | "ipv6-icmp" | "icmpv6" => Some(RuleProtocol::Icmp),
+            _ => None,
+        }
+    }
+
+    fn supports_ports(&self) -> bool {
+        matches!(self, RuleProtocol::Tcp | RuleProtocol::Udp)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SecurityGroupRuleSpec {
+    pub direction: RuleDirection,
+    pub ethertype: EtherType,
+    pub protocol: RuleProtocol,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port_range_min: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port_range_max: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remote_ip_prefix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remote_group_id: Option<SecurityGroupId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl SecurityGroupRuleSpec {
+    pub fn ingress() -> SecurityGroupRuleBuilder {
+        SecurityGroupRuleBuilder::new(RuleDirection::Ingress)
+    }
+
+    pub fn egress() -> SecurityGroupRuleBuilder {
+        SecurityGroupRuleBuilder::new(RuleDirection::Egress)
+    }
+
+    pub fn validate(&self) -> std::result::Result<(), String> {
+        match (self.port_range_min, self.port_range_max) {
+            (None, None) => {}
+            (Some(min), Some(max)) => {
+                if !self.protocol.supports_ports() {
+                    return Err(format!(
+                        "port range requires tcp or udp protocol, got {:?}",
+                        self.protocol
+                    ));
+                }
+                if min == 0 || max > 65535 || min > max {
+                    return Err(format!("invalid port range {}-{}", min, max));
+                }
+            }
+            _ => {
+                return Err("port range requires both min and max".to_string());
+            }
+        }
+
+        if self.remote_ip_prefix.is_some() && self.remote_group_id.is_some() {
+            return Err("remote prefix and remote group are mutually exclusive".to_string());
+        }
+
+        if let Some(prefix) = &self.remote_ip_prefix {
+            let cidr: Cidr = prefix.parse()?;
+            let expected = match self.ethertype {
+                EtherType::IPv4 => cidr.is_ipv4(),
+                EtherType::IPv6 => !cidr.is_ipv4(),
+            };
+            if !expected {
+                return Err(format!(
+                    "remote prefix {} does not match ethertype {:?}",
+                    cidr, self.ethertype
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn matches(&self, rule: &SecurityGroupRule) -> bool {
+        SecurityGroupRuleSpec::try_from(rule).is_ok_and(|current| {
+            current.direction == self.direction
+                && current.ethertype == self.ethertype
+                && current.protocol == self.protocol
+                && current.port_range_min == self.port_range_min
+                && current.port_range_max == self.port_range_max
+                && current.remote_ip_prefix == normalize_prefix(self.remote_ip_prefix.as_deref())
+                && current.remote_group_id == self.remote_group_id
+        })
+    }
+}
+
+impl TryFrom<&SecurityGroupRule> for SecurityGroupRuleSpec {
+    type Error = String;
+
+    fn try_from(rule: &SecurityGroupRule) -> std::result::Result<Self, Self::Error> {
+        let direction = match rule.direction.to_ascii_lowercase().as_str() {
+            "ingress" => RuleDirection::Ingress,
+            "egress" => RuleDirection::Egress,
+            other => return Err(format!("unknown rule direction '{}'", other)),
+        };
+        let ethertype = match rule.ethertype.to_ascii_lowercase().as_str() {
+            "ipv4" => EtherType::IPv4,
+            "ipv6" => EtherType::IPv6,
+            other => return Err(format!("unknown ethertype '{}'", other)),
+        };
+        let protocol = RuleProtocol::from_api(&rule.protocol)
+            .ok_or_else(|| format!("unknown rule protocol '{}'", rule.protocol))?;
+        let remote_ip_prefix = normalize_prefix(rule.remote_ip_prefix.as_deref());
+
+        Ok(SecurityGroupRuleSpec {
+            direction,
+            ethertype,
+            protocol,
+            port_range_min: rule.port_range_min,
+            port_range_max: rule.port_range_max,
+            remote_ip_prefix,
+            remote_group_id: rule.remote_group_id,
+            description: rule.description.clone(),
+        })
+    }
+}
+
+fn normalize_prefix(prefix: Option<&str>) -> Option<String> {
+    prefix.map(|p| {
+        p.parse::<Cidr>()
+            .map(|c| c.to_string())
+            .unwrap_or_else(|_| p.to_string())
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct SecurityGroupRuleBuilder {
+    spec: SecurityGroupRuleSpec,
+}
+
+impl SecurityGroupRuleBuilder {
+    pub fn new(direction: RuleDirection) -> Self {
+        Self {
+            spec: SecurityGroupRuleSpec {
+                direction,
+                ethertype: EtherType::IPv4,
+                protocol: RuleProtocol::Any,
+                port_range_min: None,
+                port_range_max: None,
+                remote_ip_prefix: None,
+                remote_group_id: None,
+                description: None,
+            },
+        }
+    }
+
+    pub fn ethertype(mut self, ethertype: EtherType) -> Self {
+        self.spec.ethertype = ethertype;
+        self
+    }
+
+    pub fn protocol(mut self, protocol: RuleProtocol) -> Self {
+        self.spec.protocol = protocol;
+        self
+    }
+
+    pub fn port(self, port: u32) -> Self {
+        self.port_range(port, port)
+    }
+
+    pub fn port_range(mut self, min: u32, max: u32) -> Self {
+        self.spec.port_range_min = Some(min);
+        self.spec.port_range_max = Some(max);
+        self
+    }
+
+    pub fn remote_ip_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.spec.remote_ip_prefix = Some(prefix.into());
+        self
+    }
+
+    pub fn remote_group(mut self, group_id: SecurityGroupId) -> Self {
+        self.spec.remote_group_id = Some(group_id);
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.spec.description = Some(description.into());
+        self
+    }
+
+    pub fn build(self) -> std::result::Result<SecurityGroupRuleSpec, String> {
+        self.spec.validate()?;
+        Ok(self.spec)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RuleDiff {
+    pub to_add: Vec<SecurityGroupRuleSpec>,
+    pub to_remove: Vec<SecurityGroupRuleId>,
+}
+
+impl RuleDiff {
+    pub fn compute(current: &[SecurityGroupRule], desired: &[SecurityGroupRuleSpec]) -> Self {
+        let mut unmatched: Vec<&SecurityGroupRule> = current.iter().collect();
+        let mut to_add = Vec::new();
+
+        for spec in desired {
+            match unmatched.iter().position(|rule| spec.matches(rule)) {
+                Some(idx) => {
+                    unmatched.swap_remove(idx);
+                }
+                None => to_add.push(spec.clone()),
+            }
+        }
+
+        Self {
+            to_add,
+            to_remove: unmatched.into_iter().map(|rule| rule.id).collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.to_add.is_empty() && self.to_remove.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RuleSyncResult {
+    pub added: Vec<SecurityGroupRule>,
+    pub removed: Vec<SecurityGroupRuleId>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateSecurityGroupRequest {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateSecurityGroupRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl NetworkClient {
+    fn security_group_path(&self, security_group_id: SecurityGroupId) -> String {
+        format!(
+            "/vpc/api/v1/projects/{}/security-groups/{}",
+            self.client.project(),
+            security_group_id
+        )
+    }
+
+    pub async fn create_security_group(
+        &self,
+        name: &str,
+        description: Option<&str>,
+    ) -> Result<SecurityGroups> {
+        let path = format!(
+            "/vpc/api/v1/projects/{}/security-groups",
+            self.client.project()
+        );
+        if name.trim().is_empty() {
+            return Err(ComputeError::validation(
+                VPC_SERVICE,
+                Some(&path),
+                "security group name must not be empty".to_string(),
+            ));
+        }
+        let body = CreateSecurityGroupRequest {
+            name: name.to_string(),
+            description: description.map(str::to_string),
+        };
+        let response: SecurityGroupWrapper = self.post(&path, &body).await?;
+        Ok(response.into())
+    }
+
+    pub async fn update_security_group(
+        &self,
+        security_group_id: SecurityGroupId,
+        request: UpdateSecurityGroupRequest,
+    ) -> Result<SecurityGroups> {
+        let path = self.security_group_path(security_group_id);
+        if request.name.as_deref().is_some_and(|n| n.trim().is_empty()) {
+            return Err(ComputeError::validation(
+                VPC_SERVICE,
+                Some(&path),
+                "security group name must not be empty".to_string(),
+            ));
+        }
+        let response: SecurityGroupWrapper = self.patch(&path, &request).await?;
+        Ok(response.into())
+    }
+
+    pub async fn add_rule(
+        &self,
+        security_group_id: SecurityGroupId,
+        rule: SecurityGroupRuleSpec,
+    ) -> Result<SecurityGroupRule> {
+        let path = format!("{}/rules", self.security_group_path(security_group_id));
+        rule.validate()
+            .map_err(|e| ComputeError::validation(VPC_SERVICE, Some(&path), e))?;
+        let response: SecurityGroupRuleWrapper = self.post(&path, &rule).await?;
+        Ok(response.into())
+    }
+
+    pub async fn remove_rule(
+        &self,
+        security_group_id: SecurityGroupId,
+        rule_id: SecurityGroupRuleId,
+    ) -> Result<MutationOutcome> {
+        let path = format!(
+            "{}/rules/{}",
+            self.security_group_path(security_group_id),
+            rule_id
+        );
+        self.delete(&path, DeleteResponse::Empty).await
+    }
+
+    pub async fn sync_rules(
+        &self,
+        security_group_id: SecurityGroupId,
+        desired_rules: Vec<SecurityGroupRuleSpec>,
+    ) -> Result<RuleSyncResult> {
+        let path = format!("{}/rules", self.security_group_path(security_group_id));
+        for rule in &desired_rules {
+            rule.validate()
+                .map_err(|e| ComputeError::validation(VPC_SERVICE, Some(&path), e))?;
+        }
+
+        let current = self
+            .list_security_group_rules(security_group_id, ListOptions::all())
+            .await?;
+        let diff = RuleDiff::compute(&current, &desired_rules);
+
+        let mut result = RuleSyncResult::default();
+        for rule in diff.to_add {
+            result
+                .added
+                .push(self.add_rule(security_group_id, rule).await?);
+        }
+        for rule_id in diff.to_remove {
+            self.remove_rule(security_group_id, rule_id).await?;
+            result.removed.push(rule_id);
+        }
+        Ok(result)
+    }
+}
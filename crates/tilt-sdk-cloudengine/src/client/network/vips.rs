@@ -0,0 +1,142 @@
+use super::{NetworkClient, VPC_SERVICE};
+use crate::client::request::ServiceClient;
+use crate::client::resource::CloudResource;
+use crate::client::responses::TaskResponse;
+use crate::error::{ComputeError, Result};
+use crate::models::{FloatingIpId, NetworkId, PortId, SubnetId, VipId, VirtualIps};
+use crate::operations::Operation;
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VipAddressMode {
+    #[serde(rename = "static")]
+    Static,
+    #[serde(rename = "dhcp")]
+    Dhcp,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateVipRequest {
+    pub name: String,
+    pub network_id: NetworkId,
+    pub subnet_id: SubnetId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed_ip: Option<String>,
+    pub l2_enabled: bool,
+    pub address_mode: VipAddressMode,
+}
+
+impl CreateVipRequest {
+    pub fn validate(&self) -> std::result::Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("VIP name must not be empty".to_string());
+        }
+        match (&self.fixed_ip, self.address_mode) {
+            (Some(ip), _) => {
+                ip.parse::<IpAddr>()
+                    .map_err(|_| format!("invalid fixed IP '{}'", ip))?;
+            }
+            (None, VipAddressMode::Static) => {
+                return Err("static address mode requires a fixed IP".to_string());
+            }
+            (None, VipAddressMode::Dhcp) => {}
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct VipPortsAttrs<'p> {
+    port_ids: &'p [PortId],
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct VipFloatingIpAttrs {
+    public_ip_id: FloatingIpId,
+}
+
+impl NetworkClient {
+    fn vip_path(&self, vip_id: VipId) -> String {
+        format!(
+            "/vpc/api/v1/projects/{}/virtual-ip-addresses/{}",
+            self.client.project(),
+            vip_id
+        )
+    }
+
+    pub async fn get_vip(&self, vip_id: VipId) -> Result<VirtualIps> {
+        VirtualIps::get(&self.client, &vip_id).await
+    }
+
+    pub async fn create_vip(&self, request: CreateVipRequest) -> Result<Operation> {
+        let path = format!(
+            "/vpc/api/v1/projects/{}/virtual-ip-addresses",
+            self.client.project()
+        );
+        request
+            .validate()
+            .map_err(|e| ComputeError::validation(VPC_SERVICE, Some(&path), e))?;
+        // `validate` has already rejected a malformed fixed IP.
+        let ip = request.fixed_ip.as_deref().and_then(|ip| ip.parse().ok());
+        self.check_subnet_address(&path, request.network_id, request.subnet_id, ip)
+            .await?;
+        let response: TaskResponse = self.post(&path, &request).await?;
+        Ok(Operation::for_task(response.data.into(), &self.client))
+    }
+
+    pub async fn bind_vip_to_ports(&self, vip_id: VipId, port_ids: &[PortId]) -> Result<Operation> {
+        self.vip_ports_action(vip_id, "bind_ports", port_ids).await
+    }
+
+    pub async fn unbind_vip_from_ports(
+        &self,
+        vip_id: VipId,
+        port_ids: &[PortId],
+    ) -> Result<Operation> {
+        self.vip_ports_action(vip_id, "unbind_ports", port_ids)
+            .await
+    }
+
+    pub async fn attach_floating_ip_to_vip(
+        &self,
+        vip_id: VipId,
+        fip_id: FloatingIpId,
+    ) -> Result<Operation> {
+        self.item_action(
+            &self.vip_path(vip_id),
+            "attach_public_ip",
+            VipFloatingIpAttrs {
+                public_ip_id: fip_id,
+            },
+        )
+        .await
+    }
+
+    pub async fn detach_floating_ip_from_vip(&self, vip_id: VipId) -> Result<Operation> {
+        self.item_action(
+            &self.vip_path(vip_id),
+            "detach_public_ip",
+            serde_json::json!({}),
+        )
+        .await
+    }
+
+    async fn vip_ports_action(
+        &self,
+        vip_id: VipId,
+        action: &str,
+        port_ids: &[PortId],
+    ) -> Result<Operation> {
+        let path = self.vip_path(vip_id);
+        if port_ids.is_empty() {
+            return Err(ComputeError::validation(
+                VPC_SERVICE,
+                Some(&path),
+                "at least one port is required".to_string(),
+            ));
+        }
+        self.item_action(&path, action, VipPortsAttrs { port_ids })
+            .await
+    }
+}
@@ -1,56 +1,288 @@
-use crate::error::{ComputeError, Result};
+use crate::error::{Result, Service};
+use futures::future;
+use futures::stream::{self, BoxStream, Stream, StreamExt, TryStreamExt};
 use std::future::Future;
+use tracing::debug;
 
 const DEFAULT_PER_PAGE: u32 = 100;
-const MAX_PAGES: u32 = 100;
+const DEFAULT_PREFETCH_CONCURRENCY: usize = 4;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ListOptions {
+    limit: Option<u32>,
+    page: Option<u32>,
+}
+
+impl ListOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn all() -> Self {
+        Self::default()
+    }
+
+    pub fn paged(limit: Option<u32>, page: Option<u32>) -> Self {
+        Self { limit, page }
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Selects a single page. Without a `limit` the page holds the
+    /// default page size of 100 items.
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn is_all(&self) -> bool {
+        self.limit.is_none() && self.page.is_none()
+    }
+
+    fn page_window(&self) -> Option<(u32, u32)> {
+        match (self.limit, self.page) {
+            (None, None) => None,
+            (limit, page) => Some((
+                page.unwrap_or(1),
+                limit.map_or(DEFAULT_PER_PAGE, super::clamp_page_limit),
+            )),
+        }
+    }
+
+    /// Applies the options to a collection the server returned in full.
+    pub(crate) fn window<T>(&self, items: Vec<T>) -> Vec<T> {
+        match self.page_window() {
+            None => items,
+            Some((page, per_page)) => items
+                .into_iter()
+                .skip(page.saturating_sub(1) as usize * per_page as usize)
+                .take(per_page as usize)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrefetchOptions {
+    concurrency: usize,
+}
+
+impl PrefetchOptions {
+    pub fn new() -> Self {
+        Self {
+            concurrency: DEFAULT_PREFETCH_CONCURRENCY,
+        }
+    }
+
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+}
+
+impl Default for PrefetchOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 pub async fn paginate<T, F, Fut>(
     limit: Option<u32>,
     page: Option<u32>,
-    service: crate::error::Service,
+    service: Service,
     endpoint: &str,
-    mut fetch_page: F,
+    fetch_page: F,
 ) -> Result<Vec<T>>
 where
     F: FnMut(u32, u32) -> Fut,
     Fut: Future<Output = Result<(Vec<T>, u32)>>,
 {
-    match limit {
-        Some(input_limit) => {
-            let limit = input_limit.min(MAX_PAGES);
-            let page = page.unwrap_or(1);
-            let (items, _) = fetch_page(page, limit).await?;
-            Ok(items)
-        }
-        None => {
-            let mut all_items: Vec<T> = Vec::new();
-            let mut current_page = 1;
-            let per_page = DEFAULT_PER_PAGE;
-
-            loop {
-                let (items, total_count) = fetch_page(current_page, per_page).await?;
-                all_items.extend(items);
-
-                if all_items.len() >= total_count as usize {
-                    break;
-                }
-
-                current_page += 1;
-
-                if current_page > MAX_PAGES {
-                    return Err(ComputeError::validation(
-                        service,
-                        Some(endpoint),
-                        format!(
-                            "Pagination limit reached. Fetched {} of {} records",
-                            all_items.len(),
-                            total_count
-                        ),
-                    ));
-                }
+    paginate_stream(limit, page, service, endpoint, fetch_page)
+        .try_collect()
+        .await
+}
+
+struct PageState<F> {
+    fetch_page: F,
+    service: Service,
+    endpoint: String,
+    next_page: u32,
+    per_page: u32,
+    fetched: usize,
+    single_page: bool,
+    done: bool,
+}
+
+pub fn paginate_stream<'s, T, F, Fut>(
+    limit: Option<u32>,
+    page: Option<u32>,
+    service: Service,
+    endpoint: impl Into<String>,
+    fetch_page: F,
+) -> impl Stream<Item = Result<T>> + 's
+where
+    T: 's,
+    F: FnMut(u32, u32) -> Fut + 's,
+    Fut: Future<Output = Result<(Vec<T>, u32)>> + 's,
+{
+    let (next_page, per_page, single_page) = match ListOptions::paged(limit, page).page_window() {
+        Some((page, per_page)) => (page, per_page, true),
+        None => (1, DEFAULT_PER_PAGE, false),
+    };
+    let state = PageState {
+        fetch_page,
+        service,
+        endpoint: endpoint.into(),
+        next_page,
+        per_page,
+        fetched: 0,
+        single_page,
+        done: false,
+    };
+    flatten_pages(sequential_pages(state))
+}
+
+fn sequential_pages<'s, T, F, Fut>(state: PageState<F>) -> impl Stream<Item = Result<Vec<T>>> + 's
+where
+    T: 's,
+    F: FnMut(u32, u32) -> Fut + 's,
+    Fut: Future<Output = Result<(Vec<T>, u32)>> + 's,
+{
+    stream::unfold(state, |mut state| async move {
+        if state.done {
+            return None;
+        }
+        debug!(
+            service = %state.service,
+            endpoint = state.endpoint,
+            page = state.next_page,
+            "Fetching page"
+        );
+        match (state.fetch_page)(state.next_page, state.per_page).await {
+            Ok((items, total_count)) => {
+                state.fetched += items.len();
+                // A non-empty page with a zero total means the endpoint did not
+                // report one, so keep going until a short page instead.
+                let last = if total_count > 0 {
+                    state.fetched >= total_count as usize
+                } else {
+                    items.len() < state.per_page as usize
+                };
+                state.done = state.single_page || items.is_empty() || last;
+                state.next_page += 1;
+                Some((Ok(items), state))
             }
+            Err(e) => {
+                state.done = true;
+                Some((Err(e), state))
+            }
+        }
+    })
+}
+
+fn flatten_pages<'s, T: 's>(
+    pages: impl Stream<Item = Result<Vec<T>>> + 's,
+) -> impl Stream<Item = Result<T>> + 's {
+    pages.flat_map(|page| {
+        stream::iter(match page {
+            Ok(items) => items.into_iter().map(Ok).collect::<Vec<_>>(),
+            Err(e) => vec![Err(e)],
+        })
+    })
+}
 
-            Ok(all_items)
+pub fn paginate_stream_with<'s, T, F, Fut>(
+    prefetch: Option<PrefetchOptions>,
+    options: ListOptions,
+    service: Service,
+    endpoint: impl Into<String>,
+    fetch_page: F,
+) -> BoxStream<'s, Result<T>>
+where
+    T: Send + 's,
+    F: FnMut(u32, u32) -> Fut + Send + 's,
+    Fut: Future<Output = Result<(Vec<T>, u32)>> + Send + 's,
+{
+    match prefetch {
+        Some(prefetch) if options.is_all() => {
+            paginate_stream_prefetch(prefetch, service, endpoint, fetch_page).boxed()
         }
+        _ => paginate_stream(options.limit, options.page, service, endpoint, fetch_page).boxed(),
     }
 }
+
+pub fn paginate_stream_prefetch<'s, T, F, Fut>(
+    prefetch: PrefetchOptions,
+    service: Service,
+    endpoint: impl Into<String>,
+    mut fetch_page: F,
+) -> impl Stream<Item = Result<T>> + 's
+where
+    T: 's,
+    F: FnMut(u32, u32) -> Fut + 's,
+    Fut: Future<Output = Result<(Vec<T>, u32)>> + 's,
+{
+    let endpoint = endpoint.into();
+    let per_page = DEFAULT_PER_PAGE;
+
+    let pages = stream::once(async move {
+        debug!(service = %service, endpoint, page = 1, "Fetching page");
+        let (items, total_count) = match fetch_page(1, per_page).await {
+            Ok(first) => first,
+            Err(e) => return stream::iter(vec![Err(e)]).left_stream(),
+        };
+        // Without a total there is nothing to plan from, so a full first
+        // page falls back to fetching one page at a time.
+        if total_count == 0 && items.len() >= per_page as usize {
+            let state = PageState {
+                fetch_page,
+                service,
+                endpoint,
+                next_page: 2,
+                per_page,
+                fetched: items.len(),
+                single_page: false,
+                done: false,
+            };
+            return stream::iter(vec![Ok(items)])
+                .chain(sequential_pages(state))
+                .left_stream()
+                .right_stream();
+        }
+        // The server may cap the page size below what was asked for, so the
+        // page count follows the size of the page it actually returned.
+        let page_size = items.len() as u32;
+        let last_page = if items.is_empty() || total_count <= page_size {
+            1
+        } else {
+            total_count.div_ceil(page_size)
+        };
+        debug!(
+            service = %service,
+            endpoint,
+            pages = last_page,
+            concurrency = prefetch.concurrency,
+            "Prefetching remaining pages"
+        );
+        let rest = stream::iter(2..=last_page)
+            .map(move |page| fetch_page(page, per_page))
+            .buffered(prefetch.concurrency)
+            .map_ok(|(items, _)| items);
+        stream::iter(vec![Ok(items)])
+            .chain(rest)
+            .right_stream()
+            .right_stream()
+    })
+    .flatten()
+    .scan(false, |failed, page: Result<Vec<T>>| {
+        if *failed {
+            return future::ready(None);
+        }
+        *failed = page.is_err();
+        future::ready(Some(page))
+    });
+    flatten_pages(pages)
+}
@@ -1,9 +1,13 @@
-use crate::client::paginate::paginate;
+use crate::client::paginate::{ListOptions, PrefetchOptions};
+use crate::client::request::ServiceClient;
+use crate::client::resource::{CloudResource, DeletableResource};
 use crate::error::{ComputeError, Result, Service};
-use crate::models::SshKeys;
+use crate::models::{SshKeyId, SshKeys, SshPublicKey};
+use crate::operations::MutationOutcome;
+use futures::stream::{BoxStream, TryStreamExt};
 use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 use tilt_sdk::Client;
-use tracing::{Instrument, debug, info_span};
 
 const PORTAL_SERVICE: Service = Service::PortalApi;
 
@@ -19,109 +23,181 @@ pub struct SshKeyDetails {
     pub name: String,
 }
 
-pub struct PortalClient<'a> {
-    client: &'a Client,
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateSshKeyRequest {
+    pub ssh_key: UpdateSshKeyDetails,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateSshKeyDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_keys: Option<Vec<String>>,
 }
 
-impl<'a> PortalClient<'a> {
-    pub fn new(client: &'a Client) -> Self {
-        Self { client }
+#[derive(Debug, Clone, Default)]
+pub struct SshKeyUpdate {
+    pub name: Option<String>,
+    pub add_public_keys: Vec<String>,
+    pub remove_public_keys: Vec<String>,
+}
+
+pub fn validate_public_keys(public_keys: &[String]) -> std::result::Result<(), String> {
+    if public_keys.is_empty() {
+        return Err("at least one public key is required".to_string());
+    }
+    for key in public_keys {
+        key.parse::<SshPublicKey>()?;
     }
+    Ok(())
+}
 
-    async fn get_with_query<T: serde::de::DeserializeOwned>(
-        &self,
-        path: &str,
-        query: &[(&str, &str)],
-    ) -> Result<T> {
-        let span = info_span!("portal_get_with_query", path);
-        async move {
-            debug!(path, ?query, "Fetching resources with query");
-            match self.client.http().get_with_query(path, query).await {
-                Ok(response) => {
-                    debug!(path, ?query, "Successfully fetched resources");
-                    Ok(response)
-                }
-                Err(e) => Err(ComputeError::from_sdk_error(e, PORTAL_SERVICE, Some(path))),
-            }
-        }
-        .instrument(span)
-        .await
+fn expand_home(path: &Path) -> PathBuf {
+    match (path.strip_prefix("~"), std::env::var_os("HOME")) {
+        (Ok(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => path.to_path_buf(),
     }
+}
 
-    async fn post<B: serde::Serialize, T: serde::de::DeserializeOwned>(
-        &self,
-        path: &str,
-        body: &B,
-    ) -> Result<T> {
-        let span = info_span!("portal_post", path);
-        async move {
-            debug!(path, "Creating resource");
-            match self.client.http().post(path, body).await {
-                Ok(response) => {
-                    debug!(path, "Successfully created resource");
-                    Ok(response)
-                }
-                Err(e) => Err(ComputeError::from_sdk_error(e, PORTAL_SERVICE, Some(path))),
-            }
-        }
-        .instrument(span)
-        .await
+#[derive(Clone)]
+pub struct PortalClient {
+    client: Client,
+    prefetch: Option<PrefetchOptions>,
+}
+
+impl ServiceClient for PortalClient {
+    const SERVICE: Service = PORTAL_SERVICE;
+
+    fn http_client(&self) -> &Client {
+        &self.client
     }
+}
 
-    async fn delete(&self, path: &str) -> Result<serde_json::Value> {
-        let span = info_span!("portal_delete", path);
-        async move {
-            debug!(path, "Deleting resource");
-            self.client.http().delete::<serde_json::Value>(path).await
-                .map_err(|e| ComputeError::from_sdk_error(e, PORTAL_SERVICE, Some(path)))
+impl PortalClient {
+    pub fn new(client: &Client) -> Self {
+        Self {
+            client: client.clone(),
+            prefetch: None,
         }
-        .instrument(span)
-        .await
     }
 
-    pub async fn list_ssh_keys(
-        &self,
-        limit: Option<u32>,
-        page: Option<u32>,
-    ) -> Result<Vec<SshKeys>> {
-        let path = format!("/portal/api/v2/projects/{}/ssh_keys", self.client.project());
+    pub fn with_prefetch(mut self, prefetch: PrefetchOptions) -> Self {
+        self.prefetch = Some(prefetch);
+        self
+    }
 
-        let this = &self;
-        paginate(
-            limit,
-            page,
-            PORTAL_SERVICE,
-            &path,
-            |page: u32, limit: u32| {
-                let path = path.clone();
-                async move {
-                    use super::responses::SshKeysResponse;
-                    let response: SshKeysResponse = this
-                        .get_with_query(
-                            &path,
-                            &[
-                                ("include", "total_count"),
-                                ("page", &page.to_string()),
-                                ("per_page", &limit.to_string()),
-                            ],
-                        )
-                        .await?;
-                    let items: Vec<SshKeys> = response.list.into_iter().map(Into::into).collect();
-                    Ok((items, response.meta.total_count))
-                }
-            },
-        )
-        .await
+    pub async fn list_ssh_keys(&self, options: ListOptions) -> Result<Vec<SshKeys>> {
+        self.list_ssh_keys_stream(options).try_collect().await
+    }
+
+    pub fn list_ssh_keys_stream(&self, options: ListOptions) -> BoxStream<'_, Result<SshKeys>> {
+        SshKeys::list_stream(&self.client, self.prefetch, options)
+    }
+
+    pub async fn get_ssh_key(&self, ssh_key_id: SshKeyId) -> Result<SshKeys> {
+        SshKeys::get(&self.client, &ssh_key_id).await
     }
 
     pub async fn create_ssh_key(&self, request: CreateSshKeyRequest) -> Result<SshKeys> {
         let path = format!("/portal/api/v2/projects/{}/ssh_keys", self.client.project());
+        validate_public_keys(&request.ssh_key.public_keys)
+            .map_err(|e| ComputeError::validation(PORTAL_SERVICE, Some(&path), e))?;
         let response: super::responses::SshKeyWrapper = self.post(&path, &request).await?;
         Ok(response.into())
     }
 
-    pub async fn delete_ssh_key(&self, ssh_key_id: uuid::Uuid) -> Result<serde_json::Value> {
-        let path = format!("/portal/api/v2/projects/{}/ssh_keys/{}", self.client.project(), ssh_key_id);
-        self.delete(&path).await
+    pub async fn delete_ssh_key(&self, ssh_key_id: SshKeyId) -> Result<MutationOutcome> {
+        SshKeys::delete(&self.client, &ssh_key_id).await
+    }
+
+    pub async fn update_ssh_key(
+        &self,
+        ssh_key_id: SshKeyId,
+        update: SshKeyUpdate,
+    ) -> Result<SshKeys> {
+        let path = format!(
+            "/portal/api/v2/projects/{}/ssh_keys/{}",
+            self.client.project(),
+            ssh_key_id
+        );
+        if update.name.as_deref().is_some_and(|n| n.trim().is_empty()) {
+            return Err(ComputeError::validation(
+                PORTAL_SERVICE,
+                Some(&path),
+                "SSH key name must not be empty".to_string(),
+            ));
+        }
+
+        let keys_changed =
+            !update.add_public_keys.is_empty() || !update.remove_public_keys.is_empty();
+        let public_keys = if !keys_changed {
+            None
+        } else {
+            let current = self.get_ssh_key(ssh_key_id).await?;
+            let matches = |key: &String, r: &String| {
+                let fingerprint = key.parse::<SshPublicKey>().ok().map(|k| k.fingerprint());
+                r.trim() == key.trim() || Some(r) == fingerprint.as_ref()
+            };
+            if let Some(unmatched) = update
+                .remove_public_keys
+                .iter()
+                .find(|r| !current.public_keys.iter().any(|k| matches(k, r)))
+            {
+                return Err(ComputeError::validation(
+                    PORTAL_SERVICE,
+                    Some(&path),
+                    format!("SSH key has no public key matching '{}'", unmatched),
+                ));
+            }
+            let mut keys: Vec<String> = current
+                .public_keys
+                .into_iter()
+                .filter(|k| !update.remove_public_keys.iter().any(|r| matches(k, r)))
+                .collect();
+            for key in update.add_public_keys {
+                if !keys.iter().any(|k| k.trim() == key.trim()) {
+                    keys.push(key);
+                }
+            }
+            validate_public_keys(&keys)
+                .map_err(|e| ComputeError::validation(PORTAL_SERVICE, Some(&path), e))?;
+            Some(keys)
+        };
+
+        let request = UpdateSshKeyRequest {
+            ssh_key: UpdateSshKeyDetails {
+                name: update.name,
+                public_keys,
+            },
+        };
+        let response: super::responses::SshKeyWrapper = self.patch(&path, &request).await?;
+        Ok(response.into())
+    }
+
+    pub async fn import_ssh_key_from_file(
+        &self,
+        path: impl AsRef<Path>,
+        name: &str,
+        login: &str,
+    ) -> Result<SshKeys> {
+        let file = expand_home(path.as_ref());
+        let contents = tokio::fs::read_to_string(&file)
+            .await
+            .map_err(|e| ComputeError::io(&file, e))?;
+        let public_keys: Vec<String> = contents
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty() && !l.starts_with('#'))
+            .map(str::to_string)
+            .collect();
+        let request = CreateSshKeyRequest {
+            ssh_key: SshKeyDetails {
+                public_keys,
+                login: login.to_string(),
+                name: name.to_string(),
+            },
+        };
+        self.create_ssh_key(request).await
     }
 }
@@ -0,0 +1,542 @@
+use crate::client::paginate::ListOptions;
+use crate::error::{ComputeError, Result, Service};
+use crate::models::{
+    Images, Instances, NetworkId, Networks, SecurityGroups, Snapshots, Subnets, Volumes,
+};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QueryParam {
+    NameContains,
+    StatusIn,
+    AvailabilityZone,
+    Network,
+    CreatedAfter,
+    CreatedBefore,
+    Sort,
+}
+
+impl QueryParam {
+    fn label(&self) -> &'static str {
+        match self {
+            QueryParam::NameContains => "name",
+            QueryParam::StatusIn => "status",
+            QueryParam::AvailabilityZone => "availability zone",
+            QueryParam::Network => "network",
+            QueryParam::CreatedAfter => "created after",
+            QueryParam::CreatedBefore => "created before",
+            QueryParam::Sort => "sort",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortField {
+    Name,
+    Status,
+    CreatedAt,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SortDirection {
+    #[default]
+    Asc,
+    Desc,
+}
+
+/// A resource that can be searched with a [`ResourceQuery`].
+///
+/// Every criterion in `CLIENT_PARAMS` is checked against each returned item,
+/// and sorting is applied to the collected results, so only the collecting
+/// `find_*` methods accept [`ResourceQuery::sort_by`]; the `find_*_stream`
+/// variants reject it. Criteria also listed in
+/// `SERVER_PARAMS` are additionally sent as query parameters so the API
+/// narrows the pages before they are downloaded; only parameters the T1 API
+/// is known to accept are listed there. A criterion missing from
+/// `CLIENT_PARAMS` is rejected with a validation error instead of being
+/// ignored.
+///
+/// | Resource | Sent to the API | Checked client-side |
+/// |----------|-----------------|---------------------|
+/// | Instances, volumes, snapshots, images | — | name, status, availability zone, created after/before, sort |
+/// | Networks, security groups | — | name, status, created after/before, sort |
+/// | Subnets | network (`network_id`) | name, status, network, sort |
+///
+/// Because filtering happens after download, a query that needs client-side
+/// filtering or sorting cannot be combined with a page limit from
+/// [`ListOptions`]: a single filtered page would silently hold fewer matches
+/// than requested. Such combinations are rejected; use [`ListOptions::all`].
+pub trait Queryable: Sized {
+    type Status: Serialize + PartialEq + Clone + std::fmt::Debug;
+
+    const SERVICE: Service;
+    const SERVER_PARAMS: &'static [QueryParam];
+    const CLIENT_PARAMS: &'static [QueryParam];
+
+    fn name(&self) -> &str;
+    fn status(&self) -> &Self::Status;
+
+    fn availability_zone(&self) -> Option<&str> {
+        None
+    }
+
+    fn network_id(&self) -> Option<NetworkId> {
+        None
+    }
+
+    fn created_at(&self) -> Option<DateTime<Utc>> {
+        None
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ResourceQuery<T: Queryable> {
+    name_contains: Option<String>,
+    status_in: Vec<T::Status>,
+    availability_zone: Option<String>,
+    network_id: Option<NetworkId>,
+    created_after: Option<DateTime<Utc>>,
+    created_before: Option<DateTime<Utc>>,
+    sort: Option<(SortField, SortDirection)>,
+}
+
+pub type InstanceQuery = ResourceQuery<Instances>;
+pub type ImageQuery = ResourceQuery<Images>;
+pub type VolumeQuery = ResourceQuery<Volumes>;
+pub type SnapshotQuery = ResourceQuery<Snapshots>;
+pub type NetworkQuery = ResourceQuery<Networks>;
+pub type SubnetQuery = ResourceQuery<Subnets>;
+pub type SecurityGroupQuery = ResourceQuery<SecurityGroups>;
+
+impl<T: Queryable> ResourceQuery<T> {
+    pub fn new() -> Self {
+        Self {
+            name_contains: None,
+            status_in: Vec::new(),
+            availability_zone: None,
+            network_id: None,
+            created_after: None,
+            created_before: None,
+            sort: None,
+        }
+    }
+
+    pub fn name_contains(mut self, name: impl Into<String>) -> Self {
+        self.name_contains = Some(name.into());
+        self
+    }
+
+    pub fn status_in(mut self, statuses: impl IntoIterator<Item = T::Status>) -> Self {
+        self.status_in.extend(statuses);
+        self
+    }
+
+    pub fn availability_zone(mut self, availability_zone: impl Into<String>) -> Self {
+        self.availability_zone = Some(availability_zone.into());
+        self
+    }
+
+    pub fn network(mut self, network_id: NetworkId) -> Self {
+        self.network_id = Some(network_id);
+        self
+    }
+
+    pub fn created_after(mut self, after: DateTime<Utc>) -> Self {
+        self.created_after = Some(after);
+        self
+    }
+
+    pub fn created_before(mut self, before: DateTime<Utc>) -> Self {
+        self.created_before = Some(before);
+        self
+    }
+
+    pub fn sort_by(mut self, field: SortField, direction: SortDirection) -> Self {
+        self.sort = Some((field, direction));
+        self
+    }
+
+    fn requested(&self) -> Vec<QueryParam> {
+        let mut params = Vec::new();
+        if self.name_contains.is_some() {
+            params.push(QueryParam::NameContains);
+        }
+        if !self.status_in.is_empty() {
+            params.push(QueryParam::StatusIn);
+        }
+        if self.availability_zone.is_some() {
+            params.push(QueryParam::AvailabilityZone);
+        }
+        if self.network_id.is_some() {
+            params.push(QueryParam::Network);
+        }
+        if self.created_after.is_some() {
+            params.push(QueryParam::CreatedAfter);
+        }
+        if self.created_before.is_some() {
+            params.push(QueryParam::CreatedBefore);
+        }
+        if self.sort.is_some() {
+            params.push(QueryParam::Sort);
+        }
+        params
+    }
+
+    pub fn validate(&self) -> std::result::Result<(), String> {
+        let unsupported: Vec<&str> = self
+            .requested()
+            .into_iter()
+            .filter(|p| !T::CLIENT_PARAMS.contains(p))
+            .map(|p| p.label())
+            .collect();
+        if unsupported.is_empty() {
+            return Ok(());
+        }
+        Err(format!(
+            "unsupported filter(s) for this resource: {}",
+            unsupported.join(", ")
+        ))
+    }
+
+    /// Checks that the query can be answered exactly with `options`; see
+    /// [`Queryable`] for why client-side criteria require listing all pages.
+    pub fn validate_options(&self, options: &ListOptions) -> std::result::Result<(), String> {
+        self.validate()?;
+        if options.is_all() {
+            return Ok(());
+        }
+        let local: Vec<&str> = self
+            .requested()
+            .into_iter()
+            .filter(|p| !T::SERVER_PARAMS.contains(p))
+            .map(|p| p.label())
+            .collect();
+        if local.is_empty() {
+            return Ok(());
+        }
+        Err(format!(
+            "filter(s) applied client-side cannot be combined with a page limit: {}",
+            local.join(", ")
+        ))
+    }
+
+    pub fn server_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(network_id) = self
+            .network_id
+            .filter(|_| T::SERVER_PARAMS.contains(&QueryParam::Network))
+        {
+            params.push(("network_id", network_id.to_string()));
+        }
+        params
+    }
+
+    pub fn matches(&self, item: &T) -> bool {
+        if let Some(name) = &self.name_contains
+            && !item.name().to_lowercase().contains(&name.to_lowercase())
+        {
+            return false;
+        }
+        if !self.status_in.is_empty() && !self.status_in.contains(item.status()) {
+            return false;
+        }
+        if let Some(az) = &self.availability_zone
+            && item.availability_zone() != Some(az.as_str())
+        {
+            return false;
+        }
+        if let Some(network_id) = self.network_id
+            && item.network_id() != Some(network_id)
+        {
+            return false;
+        }
+        if let Some(after) = self.created_after
+            && item.created_at().is_none_or(|created| created < after)
+        {
+            return false;
+        }
+        if let Some(before) = self.created_before
+            && item.created_at().is_none_or(|created| created > before)
+        {
+            return false;
+        }
+        true
+    }
+
+    pub fn sort(&self, items: &mut [T]) {
+        let Some((field, direction)) = self.sort else {
+            return;
+        };
+        items.sort_by(|a, b| {
+            let ordering = match field {
+                SortField::Name => a.name().cmp(b.name()),
+                SortField::Status => status_str(a.status()).cmp(&status_str(b.status())),
+                SortField::CreatedAt => a.created_at().cmp(&b.created_at()),
+            };
+            match direction {
+                SortDirection::Asc => ordering,
+                SortDirection::Desc => ordering.reverse(),
+            }
+        });
+    }
+
+    pub(crate) fn filter_stream<'s>(
+        self,
+        endpoint: &str,
+        options: ListOptions,
+        items: impl FnOnce(Vec<(&'static str, String)>) -> BoxStream<'s, Result<T>>,
+    ) -> BoxStream<'s, Result<T>>
+    where
+        T: Send + 's,
+        T::Status: Send + 's,
+    {
+        let checked = self.validate_options(&options).and_then(|()| match self.sort {
+            Some(_) => Err(
+                "sort is only applied when results are collected; use find_* instead of find_*_stream"
+                    .to_string(),
+            ),
+            None => Ok(()),
+        });
+        if let Err(message) = checked {
+            let error = ComputeError::validation(T::SERVICE, Some(endpoint), message);
+            return stream::iter([Err(error)]).boxed();
+        }
+        items(self.server_params())
+            .filter(move |item| {
+                std::future::ready(item.as_ref().map_or(true, |item| self.matches(item)))
+            })
+            .boxed()
+    }
+
+    /// Runs the unsorted query through `stream` and sorts the collected
+    /// results, which is what the `find_*` methods return.
+    pub(crate) async fn collect<'s>(
+        self,
+        endpoint: &str,
+        options: ListOptions,
+        stream: impl FnOnce(Self) -> BoxStream<'s, Result<T>>,
+    ) -> Result<Vec<T>>
+    where
+        T: 's,
+    {
+        if let Err(message) = self.validate_options(&options) {
+            return Err(ComputeError::validation(
+                T::SERVICE,
+                Some(endpoint),
+                message,
+            ));
+        }
+        let order = Self {
+            sort: self.sort,
+            ..Self::new()
+        };
+        let unsorted = Self { sort: None, ..self };
+        let mut items: Vec<T> = stream(unsorted).try_collect().await?;
+        order.sort(&mut items);
+        Ok(items)
+    }
+}
+
+impl<T: Queryable> Default for ResourceQuery<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn status_str<S: Serialize>(status: &S) -> Option<String> {
+    serde_json::to_value(status)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+}
+
+fn parse_timestamp(s: &str) -> Option<DateTime<Utc>> {
+    crate::models::parse_datetime(s).or_else(|| {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.f")
+            .ok()
+            .map(|dt| dt.and_utc())
+    })
+}
+
+const LOCAL_PARAMS: &[QueryParam] = &[
+    QueryParam::NameContains,
+    QueryParam::StatusIn,
+    QueryParam::AvailabilityZone,
+    QueryParam::CreatedAfter,
+    QueryParam::CreatedBefore,
+    QueryParam::Sort,
+];
+
+impl Queryable for Instances {
+    type Status = crate::models::InstanceStatus;
+
+    const SERVICE: Service = Service::OrderService;
+    const SERVER_PARAMS: &'static [QueryParam] = &[];
+    const CLIENT_PARAMS: &'static [QueryParam] = LOCAL_PARAMS;
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn status(&self) -> &Self::Status {
+        &self.status
+    }
+
+    fn availability_zone(&self) -> Option<&str> {
+        self.availability_zone.as_deref()
+    }
+
+    fn created_at(&self) -> Option<DateTime<Utc>> {
+        parse_timestamp(&self.created_row_dt)
+    }
+}
+
+impl Queryable for Volumes {
+    type Status = crate::models::VolumeStatus;
+
+    const SERVICE: Service = Service::OrderService;
+    const SERVER_PARAMS: &'static [QueryParam] = &[];
+    const CLIENT_PARAMS: &'static [QueryParam] = LOCAL_PARAMS;
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn status(&self) -> &Self::Status {
+        &self.status
+    }
+
+    fn availability_zone(&self) -> Option<&str> {
+        self.availability_zone.as_deref()
+    }
+
+    fn created_at(&self) -> Option<DateTime<Utc>> {
+        parse_timestamp(&self.created_at)
+    }
+}
+
+impl Queryable for Snapshots {
+    type Status = crate::models::SnapshotStatus;
+
+    const SERVICE: Service = Service::OrderService;
+    const SERVER_PARAMS: &'static [QueryParam] = &[];
+    const CLIENT_PARAMS: &'static [QueryParam] = LOCAL_PARAMS;
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn status(&self) -> &Self::Status {
+        &self.status
+    }
+
+    fn availability_zone(&self) -> Option<&str> {
+        self.availability_zone.as_deref()
+    }
+
+    fn created_at(&self) -> Option<DateTime<Utc>> {
+        parse_timestamp(&self.created_at)
+    }
+}
+
+impl Queryable for Images {
+    type Status = crate::models::ImageStatus;
+
+    const SERVICE: Service = Service::ComputeApi;
+    const SERVER_PARAMS: &'static [QueryParam] = &[];
+    const CLIENT_PARAMS: &'static [QueryParam] = LOCAL_PARAMS;
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn status(&self) -> &Self::Status {
+        &self.status
+    }
+
+    fn availability_zone(&self) -> Option<&str> {
+        self.availability_zone.as_deref()
+    }
+
+    fn created_at(&self) -> Option<DateTime<Utc>> {
+        Some(self.created_at)
+    }
+}
+
+impl Queryable for Networks {
+    type Status = String;
+
+    const SERVICE: Service = Service::VpcApi;
+    const SERVER_PARAMS: &'static [QueryParam] = &[];
+    const CLIENT_PARAMS: &'static [QueryParam] = &[
+        QueryParam::NameContains,
+        QueryParam::StatusIn,
+        QueryParam::CreatedAfter,
+        QueryParam::CreatedBefore,
+        QueryParam::Sort,
+    ];
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn status(&self) -> &Self::Status {
+        &self.status
+    }
+
+    fn created_at(&self) -> Option<DateTime<Utc>> {
+        self.create_time.as_deref().and_then(parse_timestamp)
+    }
+}
+
+impl Queryable for Subnets {
+    type Status = String;
+
+    const SERVICE: Service = Service::VpcApi;
+    const SERVER_PARAMS: &'static [QueryParam] = &[QueryParam::Network];
+    const CLIENT_PARAMS: &'static [QueryParam] = &[
+        QueryParam::NameContains,
+        QueryParam::StatusIn,
+        QueryParam::Network,
+        QueryParam::Sort,
+    ];
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn status(&self) -> &Self::Status {
+        &self.status
+    }
+
+    fn network_id(&self) -> Option<NetworkId> {
+        Some(self.network_id)
+    }
+}
+
+impl Queryable for SecurityGroups {
+    type Status = String;
+
+    const SERVICE: Service = Service::VpcApi;
+    const SERVER_PARAMS: &'static [QueryParam] = &[];
+    const CLIENT_PARAMS: &'static [QueryParam] = &[
+        QueryParam::NameContains,
+        QueryParam::StatusIn,
+        QueryParam::CreatedAfter,
+        QueryParam::CreatedBefore,
+        QueryParam::Sort,
+    ];
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn status(&self) -> &Self::Status {
+        &self.status
+    }
+
+    fn created_at(&self) -> Option<DateTime<Utc>> {
+        parse_timestamp(&self.create_time)
+    }
+}
@@ -0,0 +1,168 @@
+use crate::error::{ComputeError, Result, Service};
+use crate::operations::{DeleteResponse, MutationOutcome};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use tilt_sdk::Client;
+use tracing::{Instrument, debug, info_span};
+
+pub(crate) async fn get<T: DeserializeOwned>(
+    client: &Client,
+    service: Service,
+    path: &str,
+) -> Result<T> {
+    let span = info_span!("api_get", %service, path);
+    async move {
+        debug!(path, "Fetching resource");
+        match client.http().get(path).await {
+            Ok(response) => {
+                debug!(path, "Successfully fetched resource");
+                Ok(response)
+            }
+            Err(e) => Err(ComputeError::from_sdk_error(e, service, Some(path))),
+        }
+    }
+    .instrument(span)
+    .await
+}
+
+pub(crate) async fn get_with_query<T: DeserializeOwned>(
+    client: &Client,
+    service: Service,
+    path: &str,
+    query: &[(&str, &str)],
+) -> Result<T> {
+    let span = info_span!("api_get_with_query", %service, path);
+    async move {
+        debug!(path, ?query, "Fetching resources with query");
+        match client.http().get_with_query(path, query).await {
+            Ok(response) => {
+                debug!(path, ?query, "Successfully fetched resources");
+                Ok(response)
+            }
+            Err(e) => Err(ComputeError::from_sdk_error(e, service, Some(path))),
+        }
+    }
+    .instrument(span)
+    .await
+}
+
+pub(crate) async fn post<B: Serialize, T: DeserializeOwned>(
+    client: &Client,
+    service: Service,
+    path: &str,
+    body: &B,
+) -> Result<T> {
+    let span = info_span!("api_post", %service, path);
+    async move {
+        debug!(path, "Creating resource");
+        match client.http().post(path, body).await {
+            Ok(response) => {
+                debug!(path, "Successfully created resource");
+                Ok(response)
+            }
+            Err(e) => Err(ComputeError::from_sdk_error(e, service, Some(path))),
+        }
+    }
+    .instrument(span)
+    .await
+}
+
+pub(crate) async fn put<B: Serialize, T: DeserializeOwned>(
+    client: &Client,
+    service: Service,
+    path: &str,
+    body: &B,
+) -> Result<T> {
+    let span = info_span!("api_put", %service, path);
+    async move {
+        debug!(path, "Replacing resource");
+        match client.http().put(path, body).await {
+            Ok(response) => {
+                debug!(path, "Successfully replaced resource");
+                Ok(response)
+            }
+            Err(e) => Err(ComputeError::from_sdk_error(e, service, Some(path))),
+        }
+    }
+    .instrument(span)
+    .await
+}
+
+pub(crate) async fn patch<B: Serialize, T: DeserializeOwned>(
+    client: &Client,
+    service: Service,
+    path: &str,
+    body: &B,
+) -> Result<T> {
+    let span = info_span!("api_patch", %service, path);
+    async move {
+        debug!(path, "Updating resource");
+        match client.http().patch(path, body).await {
+            Ok(response) => {
+                debug!(path, "Successfully updated resource");
+                Ok(response)
+            }
+            Err(e) => Err(ComputeError::from_sdk_error(e, service, Some(path))),
+        }
+    }
+    .instrument(span)
+    .await
+}
+
+pub(crate) async fn delete(
+    client: &Client,
+    service: Service,
+    path: &str,
+    expected: DeleteResponse,
+) -> Result<MutationOutcome> {
+    let span = info_span!("api_delete", %service, path);
+    async move {
+        debug!(path, "Deleting resource");
+        let body = client
+            .http()
+            .delete::<serde_json::Value>(path)
+            .await
+            .map_err(|e| ComputeError::from_sdk_error(e, service, Some(path)))?;
+        MutationOutcome::parse(expected, body).map_err(|e| {
+            ComputeError::from_sdk_error(tilt_sdk::SdkError::Json(e), service, Some(path))
+        })
+    }
+    .instrument(span)
+    .await
+}
+
+// Shared request helpers for the per-service sub-clients; each one only names
+// its `Service` so errors and spans are attributed consistently.
+pub(crate) trait ServiceClient {
+    const SERVICE: Service;
+
+    fn http_client(&self) -> &Client;
+
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        get(self.http_client(), Self::SERVICE, path).await
+    }
+
+    async fn get_with_query<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+    ) -> Result<T> {
+        get_with_query(self.http_client(), Self::SERVICE, path, query).await
+    }
+
+    async fn post<B: Serialize, T: DeserializeOwned>(&self, path: &str, body: &B) -> Result<T> {
+        post(self.http_client(), Self::SERVICE, path, body).await
+    }
+
+    async fn put<B: Serialize, T: DeserializeOwned>(&self, path: &str, body: &B) -> Result<T> {
+        put(self.http_client(), Self::SERVICE, path, body).await
+    }
+
+    async fn patch<B: Serialize, T: DeserializeOwned>(&self, path: &str, body: &B) -> Result<T> {
+        patch(self.http_client(), Self::SERVICE, path, body).await
+    }
+
+    async fn delete(&self, path: &str, expected: DeleteResponse) -> Result<MutationOutcome> {
+        delete(self.http_client(), Self::SERVICE, path, expected).await
+    }
+}
@@ -0,0 +1,605 @@
+use crate::client::paginate::{ListOptions, PrefetchOptions, paginate_stream_with};
+use crate::client::request;
+use crate::client::responses::{
+    AvailabilityZoneWrapper, BackupWrapper, FipWrapper, FlavorWrapper, ImageUploadWrapper,
+    ImageWrapper, InstanceWrapper, NetworkRouterWrapper, NetworkWrapper, PlacementPolicyWrapper,
+    PortWrapper, RegionWrapper, RouteTableWrapper, RouterWrapper, SecurityGroupWrapper,
+    SnapshotWrapper, SshKeyWrapper, SubnetWrapper, TaskResponse, TaskWrapper, VipWrapper,
+    VolumeTypeWrapper, VolumeWrapper,
+};
+use crate::error::{Result, Service};
+use crate::models::{
+    AvailabilityZone, AvailabilityZoneId, BackupId, Backups, FlavorId, Flavors, FloatingIpId,
+    FloatingIps, ImageId, ImageUpload, ImageUploadId, Images, InstanceId, Instances, NetworkId,
+    NetworkRouter, NetworkRouterId, Networks, NicPort, PageResponse, PlacementPolicy,
+    PlacementPolicyId, PortId, RegionId, Regions, RouteTableId, RouteTables, Routers,
+    SecurityGroupId, SecurityGroups, SnapshotId, Snapshots, SnatRouterId, SshKeyId, SshKeys,
+    SubnetId, Subnets, TaskId, Tasks, VipId, VirtualIps, VolumeId, VolumeType, VolumeTypeId,
+    Volumes,
+};
+use crate::operations::{DeleteResponse, MutationOutcome};
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
+use serde::de::DeserializeOwned;
+use std::fmt;
+use std::future::Future;
+use tilt_sdk::Client;
+use uuid::Uuid;
+
+/// A resource that can be fetched and listed through its REST collection.
+/// Implementing this trait is all that is needed for a model to work with
+/// `ComputeClient::get` and `list`.
+pub trait CloudResource: Sized + Send + 'static {
+    type Id: fmt::Display + From<Uuid> + Send + Sync;
+    /// Body returned when fetching a single resource.
+    type Wrapper: DeserializeOwned + Into<Self> + Send;
+    /// Element of the list returned by the collection endpoint.
+    type ListItem: DeserializeOwned + Into<Self> + Send + 'static;
+
+    const SERVICE: Service;
+    /// Object type as reported by tasks, e.g. `"floating_ip"`.
+    const KIND: &'static str;
+    const ALIASES: &'static [&'static str] = &[];
+    /// Whether the collection honours `page`/`per_page` and reports
+    /// `total_count`. Collections that always answer with every item are
+    /// fetched once and paged locally.
+    const PAGINATED: bool = true;
+
+    fn collection_path(project: &str) -> String;
+
+    fn item_path(project: &str, id: &Self::Id) -> String {
+        format!("{}/{}", Self::collection_path(project), id)
+    }
+
+    fn list_query(_project: &str) -> Vec<(&'static str, String)> {
+        vec![("include", "total_count".to_string())]
+    }
+
+    fn matches_kind(object_type: &str) -> bool {
+        let normalized = object_type.replace('_', "").to_ascii_lowercase();
+        std::iter::once(Self::KIND)
+            .chain(Self::ALIASES.iter().copied())
+            .any(|kind| kind.replace('_', "") == normalized)
+    }
+
+    fn get(client: &Client, id: &Self::Id) -> impl Future<Output = Result<Self>> + Send {
+        let path = Self::item_path(client.project(), id);
+        async move {
+            let response: Self::Wrapper = request::get(client, Self::SERVICE, &path).await?;
+            Ok(response.into())
+        }
+    }
+
+    /// Fetches one page of raw list items. `params` are sent alongside
+    /// `list_query`, e.g. server-side filters.
+    fn list_page(
+        client: &Client,
+        params: Vec<(&'static str, String)>,
+        page: u32,
+        per_page: u32,
+    ) -> impl Future<Output = Result<(Vec<Self::ListItem>, u32)>> + Send {
+        let path = Self::collection_path(client.project());
+        Self::list_page_at(client, Self::SERVICE, path, params, page, per_page)
+    }
+
+    /// Like `list_page`, for endpoints that list this resource outside its
+    /// own collection, e.g. under a parent resource.
+    fn list_page_at(
+        client: &Client,
+        service: Service,
+        path: String,
+        params: Vec<(&'static str, String)>,
+        page: u32,
+        per_page: u32,
+    ) -> impl Future<Output = Result<(Vec<Self::ListItem>, u32)>> + Send {
+        let mut query_params = Self::list_query(client.project());
+        query_params.extend(params);
+        if Self::PAGINATED {
+            query_params.push(("page", page.to_string()));
+            query_params.push(("per_page", per_page.to_string()));
+        }
+        async move {
+            let query: Vec<(&str, &str)> =
+                query_params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+            let response: PageResponse<Self::ListItem> =
+                request::get_with_query(client, service, &path, &query).await?;
+            if Self::PAGINATED {
+                Ok(response.into_page(page, per_page))
+            } else {
+                Ok(PageResponse::Bare(response.into_list()).into_page(page, per_page))
+            }
+        }
+    }
+
+    /// Streams raw list items, for callers that need fields the model drops.
+    fn list_items<'a>(
+        client: &'a Client,
+        prefetch: Option<PrefetchOptions>,
+        options: ListOptions,
+        params: Vec<(&'static str, String)>,
+    ) -> BoxStream<'a, Result<Self::ListItem>> {
+        let path = Self::collection_path(client.project());
+        Self::list_items_at(client, Self::SERVICE, path, prefetch, options, params)
+    }
+
+    /// Streams raw list items from an endpoint other than the collection,
+    /// see `list_page_at`.
+    fn list_items_at<'a>(
+        client: &'a Client,
+        service: Service,
+        path: String,
+        prefetch: Option<PrefetchOptions>,
+        options: ListOptions,
+        params: Vec<(&'static str, String)>,
+    ) -> BoxStream<'a, Result<Self::ListItem>> {
+        if !Self::PAGINATED {
+            // The whole collection arrives at once; page it here rather than
+            // refetching everything for every page.
+            return stream::once(Self::list_page_at(
+                client,
+                service,
+                path,
+                params,
+                1,
+                u32::MAX,
+            ))
+            .map_ok(move |(items, _)| stream::iter(options.window(items)).map(Ok))
+            .try_flatten()
+            .boxed();
+        }
+        paginate_stream_with(
+            prefetch,
+            options,
+            service,
+            path.clone(),
+            move |page: u32, limit: u32| {
+                Self::list_page_at(client, service, path.clone(), params.clone(), page, limit)
+            },
+        )
+    }
+
+    fn list_stream_with<'a>(
+        client: &'a Client,
+        prefetch: Option<PrefetchOptions>,
+        options: ListOptions,
+        params: Vec<(&'static str, String)>,
+    ) -> BoxStream<'a, Result<Self>> {
+        Self::list_items(client, prefetch, options, params)
+            .map_ok(Into::into)
+            .boxed()
+    }
+
+    fn list_stream(
+        client: &Client,
+        prefetch: Option<PrefetchOptions>,
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<Self>> {
+        Self::list_stream_with(client, prefetch, options, Vec::new())
+    }
+}
+
+/// A resource that accepts a plain `DELETE`. Resources that can only be
+/// removed through an order action do not implement it, so
+/// `ComputeClient::delete` rejects them at compile time.
+pub trait DeletableResource: CloudResource {
+    /// Shape of the body returned by `delete_path`.
+    const DELETE_RESPONSE: DeleteResponse = DeleteResponse::Empty;
+
+    fn delete_path(project: &str, id: &Self::Id) -> String {
+        Self::item_path(project, id)
+    }
+
+    fn delete(
+        client: &Client,
+        id: &Self::Id,
+    ) -> impl Future<Output = Result<MutationOutcome>> + Send {
+        let path = Self::delete_path(client.project(), id);
+        async move { request::delete(client, Self::SERVICE, &path, Self::DELETE_RESPONSE).await }
+    }
+}
+
+impl CloudResource for Instances {
+    type Id = InstanceId;
+    type Wrapper = InstanceWrapper;
+    type ListItem = InstanceWrapper;
+
+    const SERVICE: Service = Service::OrderService;
+    const KIND: &'static str = "instance";
+
+    fn collection_path(project: &str) -> String {
+        format!(
+            "/order-service/api/v1/projects/{}/compute/instances",
+            project
+        )
+    }
+}
+
+impl CloudResource for Flavors {
+    type Id = FlavorId;
+    type Wrapper = FlavorWrapper;
+    type ListItem = FlavorWrapper;
+
+    const SERVICE: Service = Service::ComputeApi;
+    const KIND: &'static str = "flavor";
+
+    fn collection_path(_project: &str) -> String {
+        "/api/v1/flavors".to_string()
+    }
+}
+
+impl CloudResource for Images {
+    type Id = ImageId;
+    type Wrapper = ImageWrapper;
+    type ListItem = ImageWrapper;
+
+    const SERVICE: Service = Service::ComputeApi;
+    const KIND: &'static str = "image";
+    const ALIASES: &'static [&'static str] = &["shared_image"];
+
+    fn collection_path(project: &str) -> String {
+        format!("/compute/api/v1/projects/{}/images", project)
+    }
+}
+
+impl DeletableResource for Images {}
+
+impl CloudResource for ImageUpload {
+    type Id = ImageUploadId;
+    type Wrapper = ImageUploadWrapper;
+    type ListItem = ImageUploadWrapper;
+
+    const SERVICE: Service = Service::ComputeApi;
+    const KIND: &'static str = "image_upload";
+
+    fn collection_path(project: &str) -> String {
+        format!("/image-uploader/api/v1/projects/{}/image-uploads", project)
+    }
+}
+
+impl CloudResource for VolumeType {
+    type Id = VolumeTypeId;
+    type Wrapper = VolumeTypeWrapper;
+    type ListItem = VolumeTypeWrapper;
+
+    const SERVICE: Service = Service::OrderService;
+    const KIND: &'static str = "volume_type";
+    const PAGINATED: bool = false;
+
+    fn collection_path(_project: &str) -> String {
+        "/api/v1/volume-types".to_string()
+    }
+
+    fn list_query(_project: &str) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+}
+
+impl CloudResource for Volumes {
+    type Id = VolumeId;
+    type Wrapper = VolumeWrapper;
+    type ListItem = VolumeWrapper;
+
+    const SERVICE: Service = Service::OrderService;
+    const KIND: &'static str = "volume";
+
+    fn collection_path(project: &str) -> String {
+        format!("/order-service/api/v1/projects/{}/compute/volumes", project)
+    }
+}
+
+impl CloudResource for Snapshots {
+    type Id = SnapshotId;
+    type Wrapper = SnapshotWrapper;
+    type ListItem = SnapshotWrapper;
+
+    const SERVICE: Service = Service::OrderService;
+    const KIND: &'static str = "snapshot";
+
+    fn collection_path(project: &str) -> String {
+        format!(
+            "/order-service/api/v1/projects/{}/compute/snapshots",
+            project
+        )
+    }
+}
+
+impl CloudResource for Backups {
+    type Id = BackupId;
+    type Wrapper = BackupWrapper;
+    type ListItem = BackupWrapper;
+
+    const SERVICE: Service = Service::OrderService;
+    const KIND: &'static str = "backup";
+    const ALIASES: &'static [&'static str] = &["backup_container"];
+
+    fn collection_path(project: &str) -> String {
+        format!(
+            "/order-service/api/v1/projects/{}/compute/backup_containers",
+            project
+        )
+    }
+
+    fn list_query(_project: &str) -> Vec<(&'static str, String)> {
+        vec![
+            ("with_children", "false".to_string()),
+            ("with_all_children", "false".to_string()),
+            ("include", "total_count".to_string()),
+        ]
+    }
+}
+
+impl CloudResource for Tasks {
+    type Id = TaskId;
+    type Wrapper = TaskResponse;
+    type ListItem = TaskWrapper;
+
+    const SERVICE: Service = Service::OrderService;
+    const KIND: &'static str = "task";
+
+    fn collection_path(project: &str) -> String {
+        format!("/order-service/api/v1/projects/{}/tasks", project)
+    }
+}
+
+impl CloudResource for Regions {
+    type Id = RegionId;
+    type Wrapper = RegionWrapper;
+    type ListItem = RegionWrapper;
+
+    const SERVICE: Service = Service::ComputeApi;
+    const KIND: &'static str = "region";
+    const PAGINATED: bool = false;
+
+    fn collection_path(project: &str) -> String {
+        format!("/compute/api/v1/projects/{}/regions", project)
+    }
+
+    fn item_path(_project: &str, id: &RegionId) -> String {
+        format!("/api/v1/regions/{}", id)
+    }
+
+    fn list_query(_project: &str) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+}
+
+impl CloudResource for AvailabilityZone {
+    type Id = AvailabilityZoneId;
+    type Wrapper = AvailabilityZoneWrapper;
+    type ListItem = AvailabilityZoneWrapper;
+
+    const SERVICE: Service = Service::ComputeApi;
+    const KIND: &'static str = "availability_zone";
+    const PAGINATED: bool = false;
+
+    fn collection_path(project: &str) -> String {
+        format!("/compute/api/v1/projects/{}/availability-zones", project)
+    }
+
+    fn list_query(_project: &str) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+}
+
+impl CloudResource for PlacementPolicy {
+    type Id = PlacementPolicyId;
+    type Wrapper = PlacementPolicyWrapper;
+    type ListItem = PlacementPolicyWrapper;
+
+    const SERVICE: Service = Service::ComputeApi;
+    const KIND: &'static str = "placement_policy";
+    const PAGINATED: bool = false;
+
+    fn collection_path(project: &str) -> String {
+        format!("/compute/api/v1/projects/{}/placement-policies", project)
+    }
+
+    fn item_path(project: &str, id: &PlacementPolicyId) -> String {
+        format!("/api/v1/projects/{}/placement-policies/{}", project, id)
+    }
+
+    fn list_query(_project: &str) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+}
+
+impl DeletableResource for PlacementPolicy {
+    fn delete_path(project: &str, id: &PlacementPolicyId) -> String {
+        format!("{}/{}", Self::collection_path(project), id)
+    }
+}
+
+impl CloudResource for SshKeys {
+    type Id = SshKeyId;
+    type Wrapper = SshKeyWrapper;
+    type ListItem = SshKeyWrapper;
+
+    const SERVICE: Service = Service::PortalApi;
+    const KIND: &'static str = "ssh_key";
+
+    fn collection_path(project: &str) -> String {
+        format!("/portal/api/v2/projects/{}/ssh_keys", project)
+    }
+}
+
+impl DeletableResource for SshKeys {}
+
+impl CloudResource for Networks {
+    type Id = NetworkId;
+    type Wrapper = NetworkWrapper;
+    type ListItem = NetworkWrapper;
+
+    const SERVICE: Service = Service::VpcApi;
+    const KIND: &'static str = "network";
+    const PAGINATED: bool = false;
+
+    fn collection_path(project: &str) -> String {
+        format!("/vpc/api/v1/projects/{}/networks", project)
+    }
+
+    fn list_query(_project: &str) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+}
+
+impl DeletableResource for Networks {}
+
+impl CloudResource for Subnets {
+    type Id = SubnetId;
+    type Wrapper = SubnetWrapper;
+    type ListItem = SubnetWrapper;
+
+    const SERVICE: Service = Service::VpcApi;
+    const KIND: &'static str = "subnet";
+    const PAGINATED: bool = false;
+
+    fn collection_path(project: &str) -> String {
+        format!("/vpc/api/v1/projects/{}/subnets", project)
+    }
+
+    fn list_query(_project: &str) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+}
+
+impl DeletableResource for Subnets {}
+
+impl CloudResource for SecurityGroups {
+    type Id = SecurityGroupId;
+    type Wrapper = SecurityGroupWrapper;
+    type ListItem = SecurityGroupWrapper;
+
+    const SERVICE: Service = Service::VpcApi;
+    const KIND: &'static str = "security_group";
+
+    fn collection_path(project: &str) -> String {
+        format!("/vpc/api/v1/projects/{}/security-groups", project)
+    }
+
+    fn item_path(project: &str, id: &SecurityGroupId) -> String {
+        format!("/api/v1/projects/{}/security-groups/{}", project, id)
+    }
+}
+
+impl DeletableResource for SecurityGroups {
+    fn delete_path(project: &str, id: &SecurityGroupId) -> String {
+        format!("{}/{}", Self::collection_path(project), id)
+    }
+}
+
+impl CloudResource for FloatingIps {
+    type Id = FloatingIpId;
+    type Wrapper = FipWrapper;
+    type ListItem = FipWrapper;
+
+    const SERVICE: Service = Service::VpcApi;
+    const KIND: &'static str = "floating_ip";
+    const ALIASES: &'static [&'static str] = &["public_ip"];
+    const PAGINATED: bool = false;
+
+    fn collection_path(project: &str) -> String {
+        format!("/vpc/api/v1/projects/{}/public-ip-addresses", project)
+    }
+
+    fn list_query(_project: &str) -> Vec<(&'static str, String)> {
+        vec![("entire", "true".to_string())]
+    }
+}
+
+impl DeletableResource for FloatingIps {
+    const DELETE_RESPONSE: DeleteResponse = DeleteResponse::Task;
+}
+
+impl CloudResource for VirtualIps {
+    type Id = VipId;
+    type Wrapper = VipWrapper;
+    type ListItem = VipWrapper;
+
+    const SERVICE: Service = Service::VpcApi;
+    const KIND: &'static str = "vip";
+    const ALIASES: &'static [&'static str] = &["virtual_ip"];
+    const PAGINATED: bool = false;
+
+    fn collection_path(project: &str) -> String {
+        format!("/vpc/api/v1/projects/{}/virtual-ip-addresses", project)
+    }
+
+    fn list_query(_project: &str) -> Vec<(&'static str, String)> {
+        vec![("entire", "true".to_string())]
+    }
+}
+
+impl DeletableResource for VirtualIps {}
+
+impl CloudResource for NicPort {
+    type Id = PortId;
+    type Wrapper = PortWrapper;
+    type ListItem = PortWrapper;
+
+    const SERVICE: Service = Service::VpcApi;
+    const KIND: &'static str = "port";
+    const ALIASES: &'static [&'static str] = &["nic", "network_interface"];
+
+    fn collection_path(project: &str) -> String {
+        format!("/vpc/api/v1/projects/{}/network-interfaces", project)
+    }
+
+    fn list_query(project: &str) -> Vec<(&'static str, String)> {
+        vec![
+            ("projectName", project.to_string()),
+            ("entire", "true".to_string()),
+        ]
+    }
+}
+
+impl DeletableResource for NicPort {}
+
+impl CloudResource for Routers {
+    type Id = SnatRouterId;
+    type Wrapper = RouterWrapper;
+    type ListItem = RouterWrapper;
+
+    const SERVICE: Service = Service::VpcApi;
+    const KIND: &'static str = "snat_router";
+    const PAGINATED: bool = false;
+
+    fn collection_path(project: &str) -> String {
+        format!("/vpc/api/v1/projects/{}/snat-routers", project)
+    }
+
+    fn list_query(_project: &str) -> Vec<(&'static str, String)> {
+        vec![("entire", "true".to_string())]
+    }
+}
+
+impl DeletableResource for Routers {}
+
+impl CloudResource for NetworkRouter {
+    type Id = NetworkRouterId;
+    type Wrapper = NetworkRouterWrapper;
+    type ListItem = NetworkRouterWrapper;
+
+    const SERVICE: Service = Service::VpcApi;
+    const KIND: &'static str = "network_router";
+    const ALIASES: &'static [&'static str] = &["router"];
+
+    fn collection_path(project: &str) -> String {
+        format!("/vpc/api/v1/projects/{}/routers", project)
+    }
+}
+
+impl DeletableResource for NetworkRouter {}
+
+impl CloudResource for RouteTables {
+    type Id = RouteTableId;
+    type Wrapper = RouteTableWrapper;
+    type ListItem = RouteTableWrapper;
+
+    const SERVICE: Service = Service::VpcApi;
+    const KIND: &'static str = "route_table";
+
+    fn collection_path(project: &str) -> String {
+        format!("/vpc/api/v1/projects/{}/network_route_tables", project)
+    }
+}
+
+impl DeletableResource for RouteTables {}
@@ -25,7 +25,7 @@ impl From<AvailabilityZoneWrapper> for crate::models::AvailabilityZone {
         );
 
         crate::models::AvailabilityZone {
-            id: wrapper.id,
+            id: wrapper.id.into(),
             name: wrapper.name,
             description: wrapper.description,
         }
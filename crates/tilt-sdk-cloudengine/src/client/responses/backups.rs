@@ -1,6 +1,6 @@
+use crate::models::ListResponse;
 use crate::models::common::extensible::LogSchemaWarnings;
 use crate::models::compute::Backup;
-use crate::models::ListResponse;
 use serde::Deserialize;
 
 pub type BackupsResponse = ListResponse<BackupWrapper>;
@@ -39,7 +39,7 @@ impl From<BackupWrapper> for Backup {
             .log_unknown_fields("/compute/api/v1/projects/{project}/backups");
 
         Backup {
-            id: wrapper.id,
+            id: wrapper.id.into(),
             order_id: wrapper.order_id,
             action_id: wrapper.action_id,
             graph_id: wrapper.graph_id,
@@ -49,7 +49,7 @@ impl From<BackupWrapper> for Backup {
             source_object_name: wrapper.data.config.source_object.name,
             source_object_type: wrapper.data.config.source_object.object_type,
             last_backup_time: wrapper.data.config.last_backup_time,
-            availability_zone_id: wrapper.data.config.availability_zone.id,
+            availability_zone_id: wrapper.data.config.availability_zone.id.into(),
             availability_zone_name: wrapper.data.config.availability_zone.name,
             size_gb: wrapper.data.config.size_gb,
             provider: wrapper.data.provider,
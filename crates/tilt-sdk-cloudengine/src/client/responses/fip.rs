@@ -83,7 +83,7 @@ impl From<FipWrapper> for FloatingIp {
         );
 
         FloatingIp {
-            id: wrapper.item_id,
+            id: wrapper.item_id.into(),
             floating_ip_address: wrapper.data.config.floating_ip_address.unwrap_or_default(),
             bandwidth: wrapper.data.config.bandwidth.unwrap_or(0),
             status: wrapper.data.state.unwrap_or_else(|| "unknown".to_string()),
@@ -103,7 +103,7 @@ impl From<FipWrapper> for FloatingIp {
                 .config
                 .region
                 .as_ref()
-                .and_then(|r| r.id.clone()),
+                .and_then(|r| r.id.clone().map(Into::into)),
         }
     }
 }
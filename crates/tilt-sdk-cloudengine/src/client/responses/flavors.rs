@@ -1,5 +1,5 @@
-use crate::models::common::extensible::LogSchemaWarnings;
 use crate::models::ListResponse;
+use crate::models::common::extensible::LogSchemaWarnings;
 use serde::Deserialize;
 
 pub type FlavorsResponse = ListResponse<FlavorWrapper>;
@@ -32,7 +32,7 @@ impl From<FlavorWrapper> for crate::models::Flavors {
             .log_unknown_fields("/compute/api/v1/projects/{project}/flavors");
 
         crate::models::Flavors {
-            id: wrapper.id,
+            id: wrapper.id.into(),
             name: wrapper.name,
             description: wrapper.description,
             ram_mb: wrapper.ram,
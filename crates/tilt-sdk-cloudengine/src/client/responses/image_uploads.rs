@@ -1,5 +1,5 @@
 use crate::models::common::extensible::LogSchemaWarnings;
-use crate::models::StatusEnum;
+use crate::models::{ImageUploadId, StatusEnum};
 use serde::Deserialize;
 
 #[derive(Debug, Deserialize, Default)]
@@ -27,6 +27,10 @@ pub struct ImageUploadWrapper {
     pub create_dt: String,
     #[serde(default)]
     pub update_dt: String,
+    #[serde(default)]
+    pub size: Option<u64>,
+    #[serde(default)]
+    pub checksum: Option<String>,
     #[serde(default, flatten)]
     pub _extra: std::collections::HashMap<String, serde_json::Value>,
 }
@@ -38,13 +42,14 @@ impl From<ImageUploadWrapper> for crate::models::ImageUpload {
             .log_unknown_fields("/compute/api/v1/projects/{project}/image-uploads");
 
         crate::models::ImageUpload {
-            id: wrapper.id.parse().unwrap_or_default(),
+            id: ImageUploadId::new(wrapper.id.parse().unwrap_or_default()),
             name: wrapper.name,
             status: crate::models::ImageUploadStatus::from_string(&wrapper.status),
             os_distro: wrapper.os_distro,
             os_version: wrapper.os_version,
             create_dt: wrapper.create_dt,
             update_dt: wrapper.update_dt,
+            checksum: wrapper.checksum,
         }
     }
 }
@@ -58,3 +63,9 @@ pub struct ImageUploadsMeta {
     #[serde(default)]
     pub next: Option<String>,
 }
+
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct ImageUploadOffsetResponse {
+    #[serde(default)]
+    pub offset: Option<u64>,
+}
@@ -1,13 +1,13 @@
 use crate::models::common::extensible::LogSchemaWarnings;
-use crate::models::{parse_datetime, ImageStatus, Images, NestedEntity, StatusEnum};
+use crate::models::{ImageStatus, Images, NestedEntity, StatusEnum, parse_datetime};
 use serde::Deserialize;
 
-fn parse_uuid_or_warn(s: &str, field: &str) -> uuid::Uuid {
-    match s.parse() {
-        Ok(uuid) => uuid,
+fn parse_uuid_or_warn<T: From<uuid::Uuid>>(s: &str, field: &str) -> T {
+    match s.parse::<uuid::Uuid>() {
+        Ok(uuid) => uuid.into(),
         Err(e) => {
             tracing::warn!(target: "tilt-sdk-cloudengine", "Invalid UUID for {}: {} ({})", field, s, e);
-            uuid::Uuid::nil()
+            uuid::Uuid::nil().into()
         }
     }
 }
@@ -3,12 +3,12 @@ use crate::models::{InstanceStatus, ListResponse, NestedEntity, StatusEnum};
 use serde::Deserialize;
 use std::collections::HashMap;
 
-fn parse_uuid_or_warn(s: &str, field: &str) -> uuid::Uuid {
-    match s.parse() {
-        Ok(uuid) => uuid,
+fn parse_uuid_or_warn<T: From<uuid::Uuid>>(s: &str, field: &str) -> T {
+    match s.parse::<uuid::Uuid>() {
+        Ok(uuid) => uuid.into(),
         Err(e) => {
             tracing::warn!(target: "tilt-sdk-cloudengine", "Invalid UUID for {}: {} ({})", field, s, e);
-            uuid::Uuid::nil()
+            uuid::Uuid::nil().into()
         }
     }
 }
@@ -7,6 +7,7 @@ pub mod images;
 pub mod instances;
 pub mod network_interfaces;
 pub mod networks;
+pub mod orders;
 pub mod placement_policies;
 pub mod ports;
 pub mod regions;
@@ -24,7 +25,7 @@ pub use availability_zones::{AvailabilityZoneWrapper, AvailabilityZonesResponse}
 pub use backups::{BackupWrapper, BackupsResponse};
 pub use fip::{FipWrapper, FloatingIpsResponse};
 pub use flavors::{FlavorWrapper, FlavorsResponse};
-pub use image_uploads::{ImageUploadWrapper, ImageUploadsResponse};
+pub use image_uploads::{ImageUploadOffsetResponse, ImageUploadWrapper, ImageUploadsResponse};
 pub use images::{
     ImageResponse, ImageWrapper, ImagesMeta, ProjectImageConfig, ProjectImageData,
     ProjectImageWrapper, ProjectImagesResponse, SharedImageConfig, SharedImageData,
@@ -33,11 +34,15 @@ pub use images::{
 pub use instances::{InstanceWrapper, InstancesResponse};
 pub use network_interfaces::{NetworkInterfaceWrapper, NetworkInterfacesResponse};
 pub use networks::{NetworkWrapper, NetworksResponse};
+pub use orders::OrderItemRef;
 pub use placement_policies::{PlacementPoliciesResponse, PlacementPolicyWrapper};
 pub use ports::{PortWrapper, PortsResponse};
 pub use regions::{RegionWrapper, RegionsResponse};
 pub use route_tables::{RouteTableWrapper, RouteTablesResponse};
-pub use routers::{NetworkRouterWrapper, NetworkRoutersResponse, RouterWrapper, RoutersResponse};
+pub use routers::{
+    NetworkRouterInterfaceWrapper, NetworkRouterInterfacesResponse, NetworkRouterWrapper,
+    NetworkRoutersResponse, RouterWrapper, RoutersResponse,
+};
 pub use security_groups::{
     SecurityGroupRuleWrapper, SecurityGroupRulesResponse, SecurityGroupWrapper,
     SecurityGroupsResponse,
@@ -45,6 +50,6 @@ pub use security_groups::{
 pub use snapshots::{SnapshotWrapper, SnapshotsResponse};
 pub use ssh_keys::{SshKeyWrapper, SshKeysResponse};
 pub use subnets::{SubnetWrapper, SubnetsResponse};
-pub use tasks::{TaskResponse, TasksResponse};
+pub use tasks::{TaskResponse, TaskWrapper, TasksResponse};
 pub use vip::{VipWrapper, VipsResponse};
 pub use volumes::{VolumeTypeWrapper, VolumeTypesResponse, VolumeWrapper, VolumesResponse};
@@ -30,8 +30,8 @@ impl From<NetworkInterfaceWrapper> for crate::models::NetworkInterface {
         wrapper._extra.log_unknown_fields("/order-service/api/v1/projects/{project}/compute/instances/{instance_id}/network-interfaces");
 
         crate::models::NetworkInterface {
-            id: wrapper.id,
-            network_id: wrapper.network_id,
+            id: wrapper.id.into(),
+            network_id: wrapper.network_id.into(),
             network_name: wrapper.network_name,
             mac_address: wrapper.mac_address,
             ip_addresses: wrapper
@@ -43,7 +43,7 @@ impl From<NetworkInterfaceWrapper> for crate::models::NetworkInterface {
                     version: ip.version,
                 })
                 .collect(),
-            instance_id: wrapper.instance_id,
+            instance_id: wrapper.instance_id.map(Into::into),
         }
     }
 }
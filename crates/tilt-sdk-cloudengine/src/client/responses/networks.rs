@@ -52,7 +52,7 @@ impl From<NetworkWrapper> for crate::models::Networks {
             .log_unknown_fields("/vpc/api/v1/projects/{project}/networks");
 
         crate::models::Networks {
-            id: wrapper.id,
+            id: wrapper.id.into(),
             name: wrapper.name,
             status: wrapper.status.unwrap_or_default(),
             description: wrapper.description,
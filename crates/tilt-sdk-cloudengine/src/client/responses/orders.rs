@@ -0,0 +1,10 @@
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct OrderItemRef {
+    pub item_id: uuid::Uuid,
+    pub order_id: uuid::Uuid,
+    #[serde(default)]
+    #[serde(rename = "type")]
+    pub item_type: String,
+}
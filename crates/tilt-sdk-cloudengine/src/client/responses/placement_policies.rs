@@ -1,5 +1,5 @@
 use crate::log_schema_drift;
-use crate::models::ListResponse;
+use crate::models::{ListResponse, StatusEnum};
 use serde::Deserialize;
 
 pub type PlacementPoliciesResponse = ListResponse<PlacementPolicyWrapper>;
@@ -27,9 +27,15 @@ impl From<PlacementPolicyWrapper> for crate::models::PlacementPolicy {
         );
 
         crate::models::PlacementPolicy {
-            id: wrapper.id,
+            id: wrapper.id.into(),
             name: wrapper.data.config.name,
-            policy_type: wrapper.data.config.policy_type,
+            policy_type: wrapper
+                .data
+                .config
+                .policy_type
+                .as_deref()
+                .map(crate::models::PlacementPolicyType::from_string)
+                .unwrap_or(crate::models::PlacementPolicyType::Unknown),
             availability_zone: wrapper.data.config.availability_zone.map(|az| az.name),
         }
     }
@@ -1,14 +1,14 @@
-use crate::models::common::extensible::LogSchemaWarnings;
 use crate::models::NestedEntity;
+use crate::models::common::extensible::LogSchemaWarnings;
 use serde::Deserialize;
 use std::collections::HashMap;
 
-fn parse_uuid_or_warn(s: &str, field: &str) -> uuid::Uuid {
-    match s.parse() {
-        Ok(uuid) => uuid,
+fn parse_uuid_or_warn<T: From<uuid::Uuid>>(s: &str, field: &str) -> T {
+    match s.parse::<uuid::Uuid>() {
+        Ok(uuid) => uuid.into(),
         Err(e) => {
             tracing::warn!(target: "tilt-sdk-cloudengine", "Invalid UUID for {}: {} ({})", field, s, e);
-            uuid::Uuid::nil()
+            uuid::Uuid::nil().into()
         }
     }
 }
@@ -141,9 +141,9 @@ impl ParentItem {
             id: parse_uuid_or_warn(&self.item_id, "port.id"),
             name: config.name.clone(),
             status: data_state,
-            flavor_id: flavor.id,
+            flavor_id: flavor.id.into(),
             flavor_name: flavor.name.clone(),
-            image_id: image.id,
+            image_id: image.id.into(),
             image_name: image.name.clone(),
             ip_address,
             network_name,
@@ -238,15 +238,27 @@ impl PortWrapper {
             name: config.name.clone(),
             mac_address: config.mac_address.clone(),
             ip_address,
-            subnet_id: first_ip.as_ref().and_then(|ip| ip.subnet_id),
+            subnet_id: first_ip
+                .as_ref()
+                .and_then(|ip| ip.subnet_id)
+                .map(Into::into),
             subnet_name: config.subnet.as_ref().map(|s| s.name.clone()),
-            network_id: config.network.as_ref().map(|n| n.id).unwrap_or_default(),
+            network_id: config
+                .network
+                .as_ref()
+                .map(|n| n.id)
+                .unwrap_or_default()
+                .into(),
             network_name: config
                 .network
                 .as_ref()
                 .map(|n| n.name.clone())
                 .unwrap_or_default(),
-            security_group_ids: config.security_groups.iter().map(|sg| sg.id).collect(),
+            security_group_ids: config
+                .security_groups
+                .iter()
+                .map(|sg| sg.id.into())
+                .collect(),
             security_group_names: config
                 .security_groups
                 .iter()
@@ -295,9 +307,9 @@ impl PortWrapper {
             id: parse_uuid_or_warn(&self.item_id, "port.id"),
             name: config.name.clone(),
             status: data_state,
-            flavor_id: flavor.id,
+            flavor_id: flavor.id.into(),
             flavor_name: flavor.name.clone(),
-            image_id: image.id,
+            image_id: image.id.into(),
             image_name: image.name.clone(),
             ip_address,
             network_name,
@@ -311,6 +323,12 @@ impl PortWrapper {
     }
 }
 
+impl From<PortWrapper> for crate::models::NicPort {
+    fn from(wrapper: PortWrapper) -> Self {
+        wrapper.into_nic()
+    }
+}
+
 impl From<PortWrapper> for crate::models::Ports {
     fn from(wrapper: PortWrapper) -> Self {
         wrapper
@@ -336,15 +354,27 @@ impl From<PortWrapper> for crate::models::Ports {
             name: config.name.clone(),
             mac_address: config.mac_address.clone(),
             ip_address: first_ip.as_ref().and_then(|ip| ip.ip_address.clone()),
-            subnet_id: first_ip.as_ref().and_then(|ip| ip.subnet_id),
+            subnet_id: first_ip
+                .as_ref()
+                .and_then(|ip| ip.subnet_id)
+                .map(Into::into),
             subnet_name: config.subnet.as_ref().map(|s| s.name.clone()),
-            network_id: config.network.as_ref().map(|n| n.id).unwrap_or_default(),
+            network_id: config
+                .network
+                .as_ref()
+                .map(|n| n.id)
+                .unwrap_or_default()
+                .into(),
             network_name: config
                 .network
                 .as_ref()
                 .map(|n| n.name.clone())
                 .unwrap_or_default(),
-            security_group_ids: config.security_groups.iter().map(|sg| sg.id).collect(),
+            security_group_ids: config
+                .security_groups
+                .iter()
+                .map(|sg| sg.id.into())
+                .collect(),
             security_group_names: config
                 .security_groups
                 .iter()
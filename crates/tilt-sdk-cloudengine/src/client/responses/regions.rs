@@ -20,7 +20,7 @@ impl From<RegionWrapper> for crate::models::Regions {
             .log_unknown_fields("/compute/api/v1/projects/{project}/regions");
 
         crate::models::Regions {
-            id: wrapper.id,
+            id: wrapper.id.into(),
             name: wrapper.name,
             description: wrapper.description,
         }
@@ -37,7 +37,9 @@ pub struct RouteTableRegionWrapper {
 impl From<RouteTableRegionWrapper> for RouteTableRegion {
     fn from(wrapper: RouteTableRegionWrapper) -> Self {
         RouteTableRegion {
-            id: uuid::Uuid::parse_str(&wrapper.id).unwrap_or_else(|_| uuid::Uuid::nil()),
+            id: uuid::Uuid::parse_str(&wrapper.id)
+                .unwrap_or_else(|_| uuid::Uuid::nil())
+                .into(),
             name: wrapper.name,
             description: wrapper.description,
         }
@@ -61,7 +63,9 @@ impl From<RouteTableNetworkWrapper> for RouteTableNetwork {
         RouteTableNetwork {
             name: wrapper.name,
             status: wrapper.status,
-            id: uuid::Uuid::parse_str(&wrapper.id).unwrap_or_else(|_| uuid::Uuid::nil()),
+            id: uuid::Uuid::parse_str(&wrapper.id)
+                .unwrap_or_else(|_| uuid::Uuid::nil())
+                .into(),
             description: wrapper.description,
             create_time: wrapper.create_time,
             shared_from: wrapper.shared_from,
@@ -95,7 +99,9 @@ impl From<RouteTableWrapper> for RouteTables {
         );
 
         RouteTables {
-            id: uuid::Uuid::parse_str(&wrapper.id).unwrap_or_else(|_| uuid::Uuid::nil()),
+            id: uuid::Uuid::parse_str(&wrapper.id)
+                .unwrap_or_else(|_| uuid::Uuid::nil())
+                .into(),
             name: wrapper.name,
             description: wrapper.description,
             routes: wrapper.routes.into_iter().map(Into::into).collect(),
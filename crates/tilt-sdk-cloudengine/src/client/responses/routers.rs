@@ -1,6 +1,7 @@
 use crate::models::common::extensible::LogSchemaWarnings;
 use crate::models::{
-    ListResponse, NestedEntity, NetworkRouter, RouterNic, RouterStatus, Routers, StatusEnum,
+    ListResponse, NestedEntity, NetworkRouter, NetworkRouterInterface, RouterNic, RouterStatus,
+    Routers, StatusEnum,
 };
 use serde::Deserialize;
 
@@ -91,7 +92,7 @@ impl From<RouterWrapper> for Routers {
             .log_unknown_fields("/vpc/api/v1/projects/{project}/snat-routers");
 
         Routers {
-            id: wrapper.item_id,
+            id: wrapper.item_id.into(),
             name: wrapper.data.config.name,
             status: RouterStatus::from_string(&wrapper.data.state),
             bandwidth: wrapper.data.config.bandwidth,
@@ -109,7 +110,7 @@ impl From<RouterWrapper> for Routers {
                 .into_iter()
                 .filter(|nic| nic.data.config.id != uuid::Uuid::nil())
                 .map(|nic| RouterNic {
-                    id: nic.data.config.id,
+                    id: nic.data.config.id.into(),
                     name: nic.data.config.name,
                     ip_address: nic
                         .data
@@ -152,7 +153,7 @@ pub struct NetworkRouterWrapper {
 impl From<NetworkRouterWrapper> for NetworkRouter {
     fn from(wrapper: NetworkRouterWrapper) -> Self {
         NetworkRouter {
-            id: wrapper.id,
+            id: wrapper.id.into(),
             name: wrapper.name,
             description: wrapper.description.into(),
             status: wrapper.status,
@@ -161,3 +162,35 @@ impl From<NetworkRouterWrapper> for NetworkRouter {
         }
     }
 }
+
+pub type NetworkRouterInterfacesResponse = Vec<NetworkRouterInterfaceWrapper>;
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct NetworkRouterInterfaceWrapper {
+    pub id: uuid::Uuid,
+    pub router_id: uuid::Uuid,
+    pub subnet_id: uuid::Uuid,
+    pub port_id: Option<uuid::Uuid>,
+    pub ip_address: String,
+    pub status: String,
+    #[serde(flatten)]
+    pub _extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl From<NetworkRouterInterfaceWrapper> for NetworkRouterInterface {
+    fn from(wrapper: NetworkRouterInterfaceWrapper) -> Self {
+        wrapper
+            ._extra
+            .log_unknown_fields("/vpc/api/v1/projects/{project}/routers/{router}/interfaces");
+
+        NetworkRouterInterface {
+            id: wrapper.id.into(),
+            router_id: wrapper.router_id.into(),
+            subnet_id: wrapper.subnet_id.into(),
+            port_id: wrapper.port_id.map(Into::into),
+            ip_address: wrapper.ip_address,
+            status: wrapper.status,
+        }
+    }
+}
@@ -71,7 +71,7 @@ impl From<SecurityGroupWrapper> for crate::models::SecurityGroups {
             .log_unknown_fields("/vpc/api/v1/projects/{project}/security-groups");
 
         crate::models::SecurityGroups {
-            id: wrapper.id,
+            id: wrapper.id.into(),
             name: wrapper.name,
             description: wrapper.description,
             status: wrapper.status.unwrap_or_default(),
@@ -89,15 +89,15 @@ impl From<SecurityGroupRuleWrapper> for crate::models::SecurityGroupRule {
         );
 
         crate::models::SecurityGroupRule {
-            id: wrapper.id,
-            security_group_id: wrapper.security_group_id,
+            id: wrapper.id.into(),
+            security_group_id: wrapper.security_group_id.into(),
             direction: wrapper.direction,
             protocol: wrapper.protocol,
             ethertype: wrapper.ethertype,
             port_range_min: wrapper.port_range_min,
             port_range_max: wrapper.port_range_max,
             remote_ip_prefix: wrapper.remote_ip_prefix,
-            remote_group_id: wrapper.remote_group.map(|g| g.id),
+            remote_group_id: wrapper.remote_group.map(|g| g.id.into()),
             description: wrapper.description,
             status: wrapper.status,
         }
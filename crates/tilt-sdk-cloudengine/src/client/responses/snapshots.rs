@@ -1,5 +1,5 @@
 use crate::log_schema_drift;
-use crate::models::{ListResponse, NestedEntity};
+use crate::models::{ListResponse, NestedEntity, SnapshotStatus, StatusEnum};
 use serde::Deserialize;
 
 pub type SnapshotsResponse = ListResponse<SnapshotWrapper>;
@@ -27,13 +27,13 @@ impl From<SnapshotWrapper> for crate::models::Snapshots {
 
         let config = &wrapper.data.config;
         crate::models::Snapshots {
-            id: wrapper.item_id,
+            id: wrapper.item_id.into(),
             name: config.name.clone(),
             size: config.size,
-            status: config.status.clone(),
-            volume_id: config.volume_id,
+            status: SnapshotStatus::from_string(&config.status),
+            volume_id: config.volume_id.into(),
             volume_name: None,
-            volume_type_id: config.volume_type.as_ref().map(|vt| vt.id),
+            volume_type_id: config.volume_type.as_ref().map(|vt| vt.id.into()),
             volume_type_name: config.volume_type.as_ref().map(|vt| vt.name.clone()),
             availability_zone: config.availability_zone.as_ref().map(|az| az.name.clone()),
             created_at: config.created_at.clone(),
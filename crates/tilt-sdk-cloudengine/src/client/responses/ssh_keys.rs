@@ -1,5 +1,5 @@
-use crate::models::common::extensible::LogSchemaWarnings;
 use crate::models::ListResponse;
+use crate::models::common::extensible::LogSchemaWarnings;
 use chrono::Utc;
 use serde::Deserialize;
 
@@ -31,7 +31,7 @@ impl From<SshKeyWrapper> for crate::models::SshKeys {
             .log_unknown_fields("/portal/api/v1/projects/{project}/ssh-keys");
 
         crate::models::SshKeys {
-            id: wrapper.id,
+            id: wrapper.id.into(),
             name: wrapper.name,
             login: wrapper.login,
             public_keys: wrapper.public_keys,
@@ -97,7 +97,7 @@ impl From<SubnetWrapper> for Subnets {
             });
 
         Subnets {
-            id,
+            id: id.into(),
             name,
             status: status.unwrap_or_default(),
             cidr,
@@ -105,9 +105,13 @@ impl From<SubnetWrapper> for Subnets {
             gateway_ip,
             enable_dhcp,
             dhcp_settings,
-            network_id: network.as_ref().map(|n| n.id).unwrap_or_default(),
+            network_id: network.as_ref().map(|n| n.id).unwrap_or_default().into(),
             network_name: network.map(|n| n.name).unwrap_or_default(),
-            region_id: region.as_ref().map(|r| r.id.clone()).unwrap_or_default(),
+            region_id: region
+                .as_ref()
+                .map(|r| r.id.clone())
+                .unwrap_or_default()
+                .into(),
             region_name: region.map(|r| r.name).unwrap_or_default(),
             shared_from: shared_from.map(|s| s.id),
             shared_to: shared_to.into_iter().map(|s| s.id).collect(),
@@ -1,13 +1,8 @@
 use crate::models::common::extensible::LogSchemaWarnings;
-use crate::models::StatusEnum;
-use chrono::Utc;
+use crate::models::{ListResponse, StatusEnum, TaskError, parse_datetime};
 use serde::Deserialize;
 
-#[derive(Debug, Deserialize, Default)]
-pub struct TasksResponse {
-    #[serde(default)]
-    pub list: Vec<TaskWrapper>,
-}
+pub type TasksResponse = ListResponse<TaskWrapper>;
 
 #[derive(Debug, Deserialize, Default)]
 pub struct TaskResponse {
@@ -46,21 +41,37 @@ pub struct TaskWrapper {
     pub _extra: std::collections::HashMap<String, serde_json::Value>,
 }
 
+impl From<TaskResponse> for crate::models::Tasks {
+    fn from(response: TaskResponse) -> Self {
+        response.data.into()
+    }
+}
+
 impl From<TaskWrapper> for crate::models::Tasks {
     fn from(wrapper: TaskWrapper) -> Self {
         wrapper
             ._extra
             .log_unknown_fields("/compute/api/v1/projects/{project}/tasks");
 
+        let created_at = parse_datetime(&wrapper.created);
+        let updated_at = wrapper.updated.as_deref().and_then(parse_datetime);
+        let duration_seconds = match (created_at, updated_at) {
+            (Some(created), Some(updated)) => u64::try_from((updated - created).num_seconds()).ok(),
+            _ => None,
+        };
+
         crate::models::Tasks {
-            id: wrapper.id,
+            id: wrapper.id.into(),
             status: crate::models::TaskStatus::from_string(&wrapper.status),
             object_type: wrapper.object_type,
             object_id: wrapper.object_id,
             initiator: wrapper.initiator,
-            created_at: wrapper.created.parse().unwrap_or_else(|_| Utc::now()),
-            duration_seconds: None,
-            error: wrapper.error.and_then(|e| serde_json::from_value(e).ok()),
+            created_at,
+            updated_at,
+            duration_seconds,
+            error: wrapper.error.and_then(TaskError::from_value),
+            metadata: wrapper.metadata,
+            result: wrapper.result,
         }
     }
 }
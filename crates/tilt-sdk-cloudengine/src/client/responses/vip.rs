@@ -73,7 +73,7 @@ impl From<VipWrapper> for VirtualIp {
             .into_iter()
             .map(|ip| VirtualIpFixedIp {
                 id: ip.id,
-                subnet_id: ip.subnet_id,
+                subnet_id: ip.subnet_id.into(),
                 ip_address: ip.ip_address,
             })
             .collect();
@@ -83,21 +83,21 @@ impl From<VipWrapper> for VirtualIp {
         let region = wrapper.data.config.region.as_ref();
 
         VirtualIp {
-            id: wrapper.item_id,
+            id: wrapper.item_id.into(),
             name: wrapper.data.config.name,
             mac_address: wrapper.data.config.mac_address,
             status: wrapper.data.state,
             floating_ip: wrapper.data.config.floating_ip.map(|fip| FloatingIpInfo {
-                id: fip.id,
+                id: fip.id.into(),
                 bandwidth: fip.bandwidth,
                 ip_address: fip.ip_address,
             }),
             fixed_ips,
-            network_id: network.map(|n| n.id.clone()),
+            network_id: network.and_then(|n| n.id.parse().ok()),
             network_name: network.as_ref().map(|n| n.name.clone()),
-            subnet_id: subnet.map(|s| s.id.clone()),
+            subnet_id: subnet.and_then(|s| s.id.parse().ok()),
             subnet_name: subnet.as_ref().map(|s| s.name.clone()),
-            region_id: region.map(|r| r.id.clone()),
+            region_id: region.map(|r| r.id.clone().into()),
             region_name: region.as_ref().map(|r| r.name.clone()),
             l2_enabled: wrapper.data.config.l2_enabled,
             address_mode: wrapper.data.config.address_mode.unwrap_or_default(),
@@ -2,12 +2,12 @@ use crate::models::common::extensible::LogSchemaWarnings;
 use crate::models::{NestedEntity, StatusEnum};
 use serde::Deserialize;
 
-fn parse_uuid_or_warn(s: &str, field: &str) -> uuid::Uuid {
-    match s.parse() {
-        Ok(uuid) => uuid,
+fn parse_uuid_or_warn<T: From<uuid::Uuid>>(s: &str, field: &str) -> T {
+    match s.parse::<uuid::Uuid>() {
+        Ok(uuid) => uuid.into(),
         Err(e) => {
             tracing::warn!(target: "tilt-sdk-cloudengine", "Invalid UUID for {}: {} ({})", field, s, e);
-            uuid::Uuid::nil()
+            uuid::Uuid::nil().into()
         }
     }
 }
@@ -128,7 +128,7 @@ pub struct VolumeAttachment {
 impl From<VolumeTypeWrapper> for crate::models::VolumeType {
     fn from(wrapper: VolumeTypeWrapper) -> Self {
         crate::models::VolumeType {
-            id: wrapper.id,
+            id: wrapper.id.into(),
             name: wrapper.name,
             description: wrapper.description,
         }
@@ -156,10 +156,10 @@ impl From<VolumeWrapper> for crate::models::Volumes {
             name: config.name,
             size: config.size,
             status: crate::models::VolumeStatus::from_string(&config.status),
-            volume_type_id: config.volume_type.id,
+            volume_type_id: config.volume_type.id.into(),
             volume_type_name: Some(config.volume_type.name),
             availability_zone: config.availability_zone.map(|az| az.name),
-            attached_server_id,
+            attached_server_id: attached_server_id.map(Into::into),
             attached_server_name,
             device,
             bootable: config.bootable == "true",
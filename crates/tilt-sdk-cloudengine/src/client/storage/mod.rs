@@ -1,155 +1,205 @@
-use crate::client::paginate::paginate;
+use crate::client::compute::order_action;
+use crate::client::paginate::{ListOptions, PrefetchOptions};
+use crate::client::query::ResourceQuery;
+use crate::client::request::{self, ServiceClient};
+use crate::client::resource::CloudResource;
+use crate::client::responses::OrderItemRef;
 use crate::error::{ComputeError, Result, Service};
-use crate::models::{Snapshots, VolumeType, Volumes};
+use crate::models::{SnapshotId, Snapshots, VolumeId, VolumeType, VolumeTypeId, Volumes};
+use crate::operations::{MutationOutcome, Operation};
+use futures::stream::{BoxStream, TryStreamExt};
+use serde::{Deserialize, Serialize};
 use tilt_sdk::Client;
-use tracing::{Instrument, debug, info_span};
-use uuid::Uuid;
 
-use super::responses::{
-    SnapshotsResponse, VolumeTypeWrapper, VolumeTypesResponse, VolumeWrapper, VolumesResponse,
-};
+use super::responses::TaskResponse;
 
 const STORAGE_SERVICE: Service = Service::OrderService;
 
-pub struct StorageClient<'a> {
-    client: &'a Client,
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateSnapshotRequest {
+    pub volume_id: VolumeId,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
 }
 
-impl<'a> StorageClient<'a> {
-    pub fn new(client: &'a Client) -> Self {
-        Self { client }
-    }
-
-    async fn get<T: serde::de::DeserializeOwned>(&self, path: &str) -> Result<T> {
-        let span = info_span!("storage_get", path);
-        async move {
-            debug!(path, "Fetching resource");
-            match self.client.http().get(path).await {
-                Ok(response) => {
-                    debug!(path, "Successfully fetched resource");
-                    Ok(response)
-                }
-                Err(e) => Err(ComputeError::from_sdk_error(e, STORAGE_SERVICE, Some(path))),
-            }
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RestoreSnapshotRequest {
+    pub name: String,
+}
+
+#[derive(Clone)]
+pub struct StorageClient {
+    client: Client,
+    prefetch: Option<PrefetchOptions>,
+}
+
+impl ServiceClient for StorageClient {
+    const SERVICE: Service = STORAGE_SERVICE;
+
+    fn http_client(&self) -> &Client {
+        &self.client
+    }
+}
+
+impl StorageClient {
+    pub fn new(client: &Client) -> Self {
+        Self {
+            client: client.clone(),
+            prefetch: None,
         }
-        .instrument(span)
-        .await
     }
 
-    async fn get_with_query<T: serde::de::DeserializeOwned>(
+    pub fn with_prefetch(mut self, prefetch: PrefetchOptions) -> Self {
+        self.prefetch = Some(prefetch);
+        self
+    }
+
+    pub async fn list_volume_types(&self, options: ListOptions) -> Result<Vec<VolumeType>> {
+        self.list_volume_types_stream(options).try_collect().await
+    }
+
+    pub fn list_volume_types_stream(
         &self,
-        path: &str,
-        query: &[(&str, &str)],
-    ) -> Result<T> {
-        let span = info_span!("storage_get_with_query", path);
-        async move {
-            debug!(path, ?query, "Fetching resources with query");
-            match self.client.http().get_with_query(path, query).await {
-                Ok(response) => {
-                    debug!(path, ?query, "Successfully fetched resources");
-                    Ok(response)
-                }
-                Err(e) => Err(ComputeError::from_sdk_error(e, STORAGE_SERVICE, Some(path))),
-            }
-        }
-        .instrument(span)
-        .await
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<VolumeType>> {
+        VolumeType::list_stream(&self.client, self.prefetch, options)
+    }
+
+    pub async fn get_volume_type(&self, volume_type_id: VolumeTypeId) -> Result<VolumeType> {
+        VolumeType::get(&self.client, &volume_type_id).await
     }
 
-    pub async fn list_volume_types(&self) -> Result<Vec<VolumeType>> {
-        let path = "/api/v1/volume-types";
-        let response: VolumeTypesResponse = self.get(path).await?;
-        Ok(response.0.into_iter().map(Into::into).collect())
+    pub async fn list_volumes(&self, options: ListOptions) -> Result<Vec<Volumes>> {
+        self.list_volumes_stream(options).try_collect().await
     }
 
-    pub async fn get_volume_type(&self, volume_type_id: Uuid) -> Result<VolumeType> {
-        let path = format!("/api/v1/volume-types/{}", volume_type_id);
-        let response: VolumeTypeWrapper = self.get(&path).await?;
-        Ok(response.into())
+    pub fn list_volumes_stream(&self, options: ListOptions) -> BoxStream<'_, Result<Volumes>> {
+        Volumes::list_stream(&self.client, self.prefetch, options)
     }
 
-    pub async fn list_volumes(
+    pub async fn find_volumes(
         &self,
-        limit: Option<u32>,
-        page: Option<u32>,
+        query: ResourceQuery<Volumes>,
+        options: ListOptions,
     ) -> Result<Vec<Volumes>> {
-        let path = format!(
-            "/order-service/api/v1/projects/{}/compute/volumes",
-            self.client.project()
-        );
+        let path = Volumes::collection_path(self.client.project());
+        query
+            .collect(&path, options, |query| {
+                self.find_volumes_stream(query, options)
+            })
+            .await
+    }
 
-        let this = &self;
-        paginate(
-            limit,
-            page,
-            STORAGE_SERVICE,
-            &path,
-            |page: u32, limit: u32| {
-                let path = path.clone();
-                async move {
-                    let response: VolumesResponse = this
-                        .get_with_query(
-                            &path,
-                            &[
-                                ("include", "total_count"),
-                                ("page", &page.to_string()),
-                                ("per_page", &limit.to_string()),
-                            ],
-                        )
-                        .await?;
-                    let items: Vec<Volumes> = response.list.into_iter().map(|w| w.into()).collect();
-                    Ok((items, response.meta.total_count))
-                }
-            },
-        )
-        .await
+    pub fn find_volumes_stream(
+        &self,
+        query: ResourceQuery<Volumes>,
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<Volumes>> {
+        let path = Volumes::collection_path(self.client.project());
+        query.filter_stream(&path, options, |params| {
+            Volumes::list_stream_with(&self.client, self.prefetch, options, params)
+        })
     }
 
-    pub async fn get_volume(&self, volume_id: Uuid) -> Result<Volumes> {
-        let path = format!(
-            "/order-service/api/v1/projects/{}/compute/volumes/{}",
-            self.client.project(),
-            volume_id
-        );
-        let response: VolumeWrapper = self.get(&path).await?;
-        Ok(response.into())
+    pub async fn get_volume(&self, volume_id: VolumeId) -> Result<Volumes> {
+        Volumes::get(&self.client, &volume_id).await
+    }
+
+    pub async fn list_snapshots(&self, options: ListOptions) -> Result<Vec<Snapshots>> {
+        self.list_snapshots_stream(options).try_collect().await
+    }
+
+    pub fn list_snapshots_stream(&self, options: ListOptions) -> BoxStream<'_, Result<Snapshots>> {
+        Snapshots::list_stream(&self.client, self.prefetch, options)
     }
 
-    pub async fn list_snapshots(
+    pub async fn find_snapshots(
         &self,
-        limit: Option<u32>,
-        page: Option<u32>,
+        query: ResourceQuery<Snapshots>,
+        options: ListOptions,
     ) -> Result<Vec<Snapshots>> {
+        let path = Snapshots::collection_path(self.client.project());
+        query
+            .collect(&path, options, |query| {
+                self.find_snapshots_stream(query, options)
+            })
+            .await
+    }
+
+    pub fn find_snapshots_stream(
+        &self,
+        query: ResourceQuery<Snapshots>,
+        options: ListOptions,
+    ) -> BoxStream<'_, Result<Snapshots>> {
+        let path = Snapshots::collection_path(self.client.project());
+        query.filter_stream(&path, options, |params| {
+            Snapshots::list_stream_with(&self.client, self.prefetch, options, params)
+        })
+    }
+
+    pub async fn get_snapshot(&self, snapshot_id: SnapshotId) -> Result<Snapshots> {
+        Snapshots::get(&self.client, &snapshot_id).await
+    }
+
+    pub async fn create_snapshot(
+        &self,
+        volume_id: VolumeId,
+        name: &str,
+        description: Option<&str>,
+    ) -> Result<Operation> {
         let path = format!(
-            "/order-service/api/v1/projects/{}/compute/snapshots",
+            "/compute/api/v1/projects/{}/snapshots",
             self.client.project()
         );
+        let request = CreateSnapshotRequest {
+            volume_id,
+            name: name.to_string(),
+            description: description.map(|d| d.to_string()),
+        };
+        let response: TaskResponse =
+            request::post(&self.client, Service::ComputeApi, &path, &request).await?;
+        Ok(Operation::for_task(response.data.into(), &self.client))
+    }
 
-        let this = &self;
-        paginate(
-            limit,
-            page,
-            STORAGE_SERVICE,
-            &path,
-            |page: u32, limit: u32| {
-                let path = path.clone();
-                async move {
-                    let response: SnapshotsResponse = this
-                        .get_with_query(
-                            &path,
-                            &[
-                                ("include", "total_count"),
-                                ("page", &page.to_string()),
-                                ("per_page", &limit.to_string()),
-                            ],
-                        )
-                        .await?;
-                    let items: Vec<Snapshots> =
-                        response.list.into_iter().map(|w| w.into()).collect();
-                    Ok((items, response.meta.total_count))
-                }
-            },
+    /// Snapshots are order-service items, so they are removed through the
+    /// order's `delete_snapshot` action rather than a plain `DELETE`.
+    pub async fn delete_snapshot(&self, snapshot_id: SnapshotId) -> Result<MutationOutcome> {
+        let path = Snapshots::item_path(self.client.project(), &snapshot_id);
+        let item: OrderItemRef = self.get(&path).await?;
+        if item.order_id.is_nil() || item.item_id.is_nil() {
+            return Err(ComputeError::validation(
+                STORAGE_SERVICE,
+                Some(&path),
+                format!("snapshot {} has no order reference", snapshot_id),
+            ));
+        }
+        let task = order_action(
+            &self.client,
+            item.order_id,
+            item.item_id,
+            "delete_snapshot",
+            serde_json::json!({}),
         )
-        .await
+        .await?;
+        Ok(MutationOutcome::Accepted(task.into()))
+    }
+
+    pub async fn restore_volume_from_snapshot(
+        &self,
+        snapshot_id: SnapshotId,
+        volume_name: &str,
+    ) -> Result<Operation> {
+        let path = format!(
+            "/compute/api/v1/projects/{}/snapshots/{}/restore",
+            self.client.project(),
+            snapshot_id
+        );
+        let request = RestoreSnapshotRequest {
+            name: volume_name.to_string(),
+        };
+        let response: TaskResponse =
+            request::post(&self.client, Service::ComputeApi, &path, &request).await?;
+        Ok(Operation::for_task(response.data.into(), &self.client))
     }
 }
@@ -7,6 +7,8 @@ pub enum Service {
     VpcApi,
     PortalApi,
     Global,
+    /// Work done on the caller's machine, such as reading a local file.
+    Local,
 }
 
 impl Service {
@@ -17,6 +19,7 @@ impl Service {
             Service::VpcApi => "vpc-api",
             Service::PortalApi => "portal-api",
             Service::Global => "global",
+            Service::Local => "local",
         }
     }
 }
@@ -89,6 +92,18 @@ impl ComputeError {
         }
     }
 
+    /// A failed local file operation on `path`, attributed to [`Service::Local`].
+    pub fn io(path: &std::path::Path, error: std::io::Error) -> Self {
+        Self {
+            service: Service::Local,
+            endpoint: Some(path.display().to_string()),
+            message: format!("{}: {}", path.display(), error),
+            request_id: None,
+            hints: None,
+            source: Some(tilt_sdk::SdkError::Io(error)),
+        }
+    }
+
     pub fn validation(service: Service, endpoint: Option<&str>, message: String) -> Self {
         Self {
             service,
@@ -111,4 +126,22 @@ impl ComputeError {
     pub fn hints(&self) -> Option<&[String]> {
         self.hints.as_deref()
     }
+
+    pub fn is_retryable(&self) -> bool {
+        self.source.as_ref().is_some_and(|e| e.is_retryable())
+    }
+
+    /// How long the server asked callers to back off, when it rate-limited the request.
+    pub fn retry_after(&self) -> Option<std::time::Duration> {
+        match self.source {
+            Some(tilt_sdk::SdkError::RateLimited { retry_after }) => {
+                Some(std::time::Duration::from_secs(retry_after))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn is_not_found(&self) -> bool {
+        matches!(self.source, Some(tilt_sdk::SdkError::NotFound { .. }))
+    }
 }
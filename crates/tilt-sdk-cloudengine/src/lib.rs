@@ -8,9 +8,14 @@ pub mod operations;
 pub use client::{ComputeClient, Resource};
 pub use error::{ComputeError, Result, Service};
 pub use models::{
-    AvailabilityZone, ExtraSpec, Flavors, ImageStatus, ImageUpload, ImageUploadStatus, Images,
-    InstanceStatus, Instances, ListMeta, ListResponse, NestedEntity, Network, NetworkInterface,
-    Networks, PlacementPolicy, Ports, Regions, SecurityGroup, SecurityGroupRule, SecurityGroups,
-    Snapshot, Snapshots, SshKeys, StatusEnum, Subnets, TaskStatus, Tasks, VolumeStatus, VolumeType,
-    Volumes, parse_datetime,
+    AvailabilityZone, AvailabilityZoneId, BackupId, ExtraSpec, FlavorId, Flavors, FloatingIpId,
+    ImageId, ImageStatus, ImageUpload, ImageUploadId, ImageUploadStatus, Images, InstanceId,
+    InstanceStatus, Instances, ListMeta, ListResponse, NestedEntity, Network, NetworkId,
+    NetworkInterface, NetworkRouterId, Networks, PlacementPolicy, PlacementPolicyId,
+    PlacementPolicyType, PortId, Ports, RegionId, Regions, RouteTableId, RouterInterfaceId,
+    SecurityGroup, SecurityGroupId, SecurityGroupRule, SecurityGroupRuleId, SecurityGroups,
+    Snapshot, SnapshotId, SnapshotStatus, Snapshots, SnatRouterId, SshKeyId, SshKeys, StatusEnum,
+    SubnetId, Subnets, TaskError, TaskId, TaskStatus, Tasks, VipId, VolumeId, VolumeStatus,
+    VolumeType, VolumeTypeId, Volumes, parse_datetime,
 };
+pub use operations::{DeleteResponse, MutationOutcome, TaskHandle};
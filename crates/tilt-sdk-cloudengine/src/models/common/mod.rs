@@ -1,5 +1,6 @@
 pub mod datetime;
 pub mod extensible;
+pub mod ids;
 pub mod resource;
 pub mod response;
 pub mod ssh_key;
@@ -7,7 +8,15 @@ pub mod status;
 
 pub use datetime::parse_datetime;
 pub use extensible::{LogSchemaWarnings, LogSchemaWarnings as _};
-pub use resource::{AvailabilityZone, PlacementPolicy, Regions, TaskStatus, Tasks};
-pub use response::{ListMeta, ListResponse, NestedEntity};
-pub use ssh_key::SshKeys;
+pub use ids::{
+    AvailabilityZoneId, BackupId, FlavorId, FloatingIpId, ImageId, ImageUploadId, InstanceId,
+    NetworkId, NetworkRouterId, PlacementPolicyId, PortId, RegionId, RouteTableId,
+    RouterInterfaceId, SecurityGroupId, SecurityGroupRuleId, SnapshotId, SnatRouterId, SshKeyId,
+    SubnetId, TaskId, VipId, VolumeId, VolumeTypeId,
+};
+pub use resource::{
+    AvailabilityZone, PlacementPolicy, PlacementPolicyType, Regions, TaskError, TaskStatus, Tasks,
+};
+pub use response::{ListMeta, ListResponse, NestedEntity, PageResponse};
+pub use ssh_key::{SshKeyAlgorithm, SshKeys, SshPublicKey};
 pub use status::StatusEnum;
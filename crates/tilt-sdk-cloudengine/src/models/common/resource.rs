@@ -1,40 +1,160 @@
 use super::StatusEnum;
+use crate::models::{AvailabilityZoneId, PlacementPolicyId, RegionId, TaskId};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Regions {
-    pub id: String,
+    pub id: RegionId,
     pub name: String,
     pub description: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AvailabilityZone {
-    pub id: String,
+    pub id: AvailabilityZoneId,
     pub name: String,
     pub description: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PlacementPolicy {
-    pub id: Uuid,
+    pub id: PlacementPolicyId,
     pub name: String,
-    pub policy_type: Option<String>,
+    pub policy_type: PlacementPolicyType,
     pub availability_zone: Option<String>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PlacementPolicyType {
+    #[serde(rename = "affinity")]
+    Affinity,
+    #[serde(rename = "anti-affinity")]
+    AntiAffinity,
+    #[serde(rename = "soft-affinity")]
+    SoftAffinity,
+    #[serde(rename = "soft-anti-affinity")]
+    SoftAntiAffinity,
+    #[serde(rename = "unknown")]
+    Unknown,
+}
+
+impl PlacementPolicyType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PlacementPolicyType::Affinity => "affinity",
+            PlacementPolicyType::AntiAffinity => "anti-affinity",
+            PlacementPolicyType::SoftAffinity => "soft-affinity",
+            PlacementPolicyType::SoftAntiAffinity => "soft-anti-affinity",
+            PlacementPolicyType::Unknown => "unknown",
+        }
+    }
+
+    pub fn is_soft(&self) -> bool {
+        matches!(
+            self,
+            PlacementPolicyType::SoftAffinity | PlacementPolicyType::SoftAntiAffinity
+        )
+    }
+
+    pub fn is_anti_affinity(&self) -> bool {
+        matches!(
+            self,
+            PlacementPolicyType::AntiAffinity | PlacementPolicyType::SoftAntiAffinity
+        )
+    }
+}
+
+impl StatusEnum for PlacementPolicyType {
+    fn from_string(s: &str) -> Self {
+        match s.to_lowercase().replace('_', "-").as_str() {
+            "affinity" => PlacementPolicyType::Affinity,
+            "anti-affinity" => PlacementPolicyType::AntiAffinity,
+            "soft-affinity" => PlacementPolicyType::SoftAffinity,
+            "soft-anti-affinity" => PlacementPolicyType::SoftAntiAffinity,
+            _ => PlacementPolicyType::Unknown,
+        }
+    }
+}
+
+impl std::fmt::Display for PlacementPolicyType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tasks {
-    pub id: Uuid,
+    pub id: TaskId,
     pub status: TaskStatus,
     pub object_type: String,
     pub object_id: Uuid,
     pub initiator: String,
-    pub created_at: DateTime<Utc>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
     pub duration_seconds: Option<u64>,
-    pub error: Option<String>,
+    pub error: Option<TaskError>,
+    pub metadata: Option<serde_json::Value>,
+    pub result: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskError {
+    pub code: Option<String>,
+    pub message: String,
+    pub details: Option<serde_json::Value>,
+}
+
+impl TaskError {
+    pub fn from_value(value: serde_json::Value) -> Option<Self> {
+        match value {
+            serde_json::Value::Null => None,
+            serde_json::Value::String(message) if message.is_empty() => None,
+            serde_json::Value::String(message) => Some(TaskError {
+                code: None,
+                message,
+                details: None,
+            }),
+            serde_json::Value::Object(mut map) => {
+                let code = map.remove("code").and_then(|c| match c {
+                    serde_json::Value::String(s) => Some(s),
+                    serde_json::Value::Null => None,
+                    other => Some(other.to_string()),
+                });
+                let message = ["message", "msg", "detail", "error"]
+                    .iter()
+                    .find_map(|key| match map.remove(*key) {
+                        Some(serde_json::Value::String(s)) => Some(s),
+                        _ => None,
+                    })
+                    .or_else(|| code.clone())
+                    .unwrap_or_else(|| "unknown task error".to_string());
+                let details = map
+                    .remove("details")
+                    .or_else(|| (!map.is_empty()).then_some(serde_json::Value::Object(map)));
+                Some(TaskError {
+                    code,
+                    message,
+                    details,
+                })
+            }
+            other => Some(TaskError {
+                code: None,
+                message: other.to_string(),
+                details: None,
+            }),
+        }
+    }
+}
+
+impl std::fmt::Display for TaskError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.code {
+            Some(code) => write!(f, "{}: {}", code, self.message),
+            None => f.write_str(&self.message),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -51,6 +171,18 @@ pub enum TaskStatus {
     Unknown,
 }
 
+impl TaskStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaskStatus::New => "new",
+            TaskStatus::Running => "running",
+            TaskStatus::Successful => "successful",
+            TaskStatus::Failed => "failed",
+            TaskStatus::Unknown => "unknown",
+        }
+    }
+}
+
 impl StatusEnum for TaskStatus {
     fn from_string(s: &str) -> Self {
         match s.to_lowercase().as_str() {
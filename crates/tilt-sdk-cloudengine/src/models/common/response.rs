@@ -7,6 +7,7 @@ pub struct ListMeta {
 }
 
 #[derive(Debug, Deserialize, Default)]
+#[serde(bound(deserialize = "T: Deserialize<'de>"))]
 pub struct ListResponse<T> {
     #[serde(default)]
     pub list: Vec<T>,
@@ -20,6 +21,34 @@ impl<T> ListResponse<T> {
     }
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum PageResponse<T> {
+    Paged(ListResponse<T>),
+    Bare(Vec<T>),
+}
+
+impl<T> PageResponse<T> {
+    pub fn into_list(self) -> Vec<T> {
+        match self {
+            PageResponse::Paged(response) => response.list,
+            PageResponse::Bare(list) => list,
+        }
+    }
+
+    pub fn into_page(self, page: u32, per_page: u32) -> (Vec<T>, u32) {
+        match self {
+            PageResponse::Paged(response) => response.into_parts(),
+            PageResponse::Bare(list) => {
+                let total_count = list.len() as u32;
+                let skip = page.saturating_sub(1) as usize * per_page as usize;
+                let items = list.into_iter().skip(skip).take(per_page as usize);
+                (items.collect(), total_count)
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Default)]
 pub struct NestedEntity<T = String> {
     #[serde(default)]
@@ -1,13 +1,174 @@
+use crate::models::SshKeyId;
+use base64::Engine;
+use base64::engine::general_purpose::{STANDARD, STANDARD_NO_PAD};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
+use sha2::{Digest, Sha256};
+use std::str::FromStr;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SshKeys {
-    pub id: Uuid,
+    pub id: SshKeyId,
     pub name: String,
     pub login: String,
     pub public_keys: Vec<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: Option<DateTime<Utc>>,
 }
+
+impl SshKeys {
+    pub fn fingerprints(&self) -> Vec<String> {
+        self.public_keys
+            .iter()
+            .filter_map(|k| k.parse::<SshPublicKey>().ok())
+            .map(|k| k.fingerprint())
+            .collect()
+    }
+
+    pub fn has_fingerprint(&self, fingerprint: &str) -> bool {
+        self.fingerprints().iter().any(|f| f == fingerprint)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SshKeyAlgorithm {
+    Ed25519,
+    Rsa,
+    EcdsaP256,
+    EcdsaP384,
+    EcdsaP521,
+}
+
+impl SshKeyAlgorithm {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SshKeyAlgorithm::Ed25519 => "ssh-ed25519",
+            SshKeyAlgorithm::Rsa => "ssh-rsa",
+            SshKeyAlgorithm::EcdsaP256 => "ecdsa-sha2-nistp256",
+            SshKeyAlgorithm::EcdsaP384 => "ecdsa-sha2-nistp384",
+            SshKeyAlgorithm::EcdsaP521 => "ecdsa-sha2-nistp521",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "ssh-ed25519" => Some(SshKeyAlgorithm::Ed25519),
+            "ssh-rsa" => Some(SshKeyAlgorithm::Rsa),
+            "ecdsa-sha2-nistp256" => Some(SshKeyAlgorithm::EcdsaP256),
+            "ecdsa-sha2-nistp384" => Some(SshKeyAlgorithm::EcdsaP384),
+            "ecdsa-sha2-nistp521" => Some(SshKeyAlgorithm::EcdsaP521),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SshPublicKey {
+    pub algorithm: SshKeyAlgorithm,
+    pub key_data: Vec<u8>,
+    pub comment: Option<String>,
+}
+
+impl SshPublicKey {
+    pub fn fingerprint(&self) -> String {
+        let digest = Sha256::digest(&self.key_data);
+        format!("SHA256:{}", STANDARD_NO_PAD.encode(digest))
+    }
+
+    pub fn to_openssh(&self) -> String {
+        let mut line = format!(
+            "{} {}",
+            self.algorithm.as_str(),
+            STANDARD.encode(&self.key_data)
+        );
+        if let Some(comment) = &self.comment {
+            line.push(' ');
+            line.push_str(comment);
+        }
+        line
+    }
+}
+
+impl FromStr for SshPublicKey {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split_whitespace();
+        let type_name = parts.next().ok_or("empty SSH public key")?;
+        let algorithm = SshKeyAlgorithm::from_name(type_name)
+            .ok_or_else(|| format!("unsupported SSH key type '{}'", type_name))?;
+        let encoded = parts
+            .next()
+            .ok_or_else(|| format!("{} key is missing key data", type_name))?;
+        let key_data = STANDARD
+            .decode(encoded)
+            .map_err(|_| format!("{} key data is not valid base64", type_name))?;
+        let comment = parts.collect::<Vec<_>>().join(" ");
+
+        let mut reader = WireReader::new(&key_data);
+        let embedded = reader.string()?;
+        if embedded != type_name.as_bytes() {
+            return Err(format!(
+                "key data does not match declared type '{}'",
+                type_name
+            ));
+        }
+        match algorithm {
+            SshKeyAlgorithm::Ed25519 => {
+                if reader.string()?.len() != 32 {
+                    return Err("ed25519 public key must be 32 bytes".to_string());
+                }
+            }
+            SshKeyAlgorithm::Rsa => {
+                reader.string()?;
+                reader.string()?;
+            }
+            SshKeyAlgorithm::EcdsaP256
+            | SshKeyAlgorithm::EcdsaP384
+            | SshKeyAlgorithm::EcdsaP521 => {
+                let curve = reader.string()?;
+                if curve != type_name.trim_start_matches("ecdsa-sha2-").as_bytes() {
+                    return Err(format!("ECDSA curve does not match '{}'", type_name));
+                }
+                reader.string()?;
+            }
+        }
+        if !reader.is_empty() {
+            return Err(format!("{} key data has trailing bytes", type_name));
+        }
+
+        Ok(SshPublicKey {
+            algorithm,
+            key_data,
+            comment: (!comment.is_empty()).then_some(comment),
+        })
+    }
+}
+
+struct WireReader<'b> {
+    buf: &'b [u8],
+}
+
+impl<'b> WireReader<'b> {
+    fn new(buf: &'b [u8]) -> Self {
+        Self { buf }
+    }
+
+    fn string(&mut self) -> Result<&'b [u8], String> {
+        if self.buf.len() < 4 {
+            return Err("truncated SSH key data".to_string());
+        }
+        let (len, rest) = self.buf.split_at(4);
+        let len = u32::from_be_bytes([len[0], len[1], len[2], len[3]]) as usize;
+        if rest.len() < len {
+            return Err("truncated SSH key data".to_string());
+        }
+        let (value, rest) = rest.split_at(len);
+        self.buf = rest;
+        Ok(value)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+}
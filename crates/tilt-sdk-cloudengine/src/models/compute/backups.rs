@@ -1,9 +1,10 @@
+use crate::models::{AvailabilityZoneId, BackupId};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Backup {
-    pub id: Uuid,
+    pub id: BackupId,
     pub order_id: Uuid,
     pub action_id: Uuid,
     pub graph_id: Uuid,
@@ -13,7 +14,7 @@ pub struct Backup {
     pub source_object_name: String,
     pub source_object_type: String,
     pub last_backup_time: Option<String>,
-    pub availability_zone_id: String,
+    pub availability_zone_id: AvailabilityZoneId,
     pub availability_zone_name: String,
     pub size_gb: f64,
     pub provider: String,
@@ -1,9 +1,9 @@
+use crate::models::FlavorId;
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Flavors {
-    pub id: Uuid,
+    pub id: FlavorId,
     pub name: String,
     pub description: Option<String>,
     pub ram_mb: u64,
@@ -1,11 +1,11 @@
 use crate::StatusEnum;
+use crate::models::{ImageId, ImageUploadId};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Images {
-    pub id: Uuid,
+    pub id: ImageId,
     pub name: String,
     pub size_bytes: u64,
     pub disk_format: String,
@@ -52,13 +52,14 @@ impl StatusEnum for ImageStatus {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ImageUpload {
-    pub id: Uuid,
+    pub id: ImageUploadId,
     pub name: String,
     pub status: ImageUploadStatus,
     pub os_distro: Option<String>,
     pub os_version: Option<String>,
     pub create_dt: String,
     pub update_dt: String,
+    pub checksum: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -1,18 +1,18 @@
 use crate::StatusEnum;
+use crate::models::{FlavorId, ImageId, InstanceId};
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Instances {
-    pub id: Uuid,
+    pub id: InstanceId,
     pub name: String,
     pub status: InstanceStatus,
-    pub flavor_id: Uuid,
+    pub flavor_id: FlavorId,
     pub flavor_name: Option<String>,
     pub flavor_vcpus: Option<u64>,
     pub flavor_ram_mb: Option<u64>,
     pub flavor_gpus: Option<u64>,
-    pub image_id: Option<Uuid>,
+    pub image_id: Option<ImageId>,
     pub image_name: Option<String>,
     pub availability_zone: Option<String>,
     pub all_addresses: Vec<String>,
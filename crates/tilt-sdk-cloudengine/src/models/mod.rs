@@ -8,8 +8,16 @@ pub mod storage;
 pub use crate::log_schema_drift;
 
 pub use common::{
-    parse_datetime, AvailabilityZone, ListMeta, ListResponse, NestedEntity, PlacementPolicy,
-    Regions, SshKeys, StatusEnum, TaskStatus, Tasks,
+    AvailabilityZone, ListMeta, ListResponse, NestedEntity, PageResponse, PlacementPolicy,
+    PlacementPolicyType, Regions, SshKeyAlgorithm, SshKeys, SshPublicKey, StatusEnum, TaskError,
+    TaskStatus, Tasks, parse_datetime,
+};
+
+pub use common::{
+    AvailabilityZoneId, BackupId, FlavorId, FloatingIpId, ImageId, ImageUploadId, InstanceId,
+    NetworkId, NetworkRouterId, PlacementPolicyId, PortId, RegionId, RouteTableId,
+    RouterInterfaceId, SecurityGroupId, SecurityGroupRuleId, SnapshotId, SnatRouterId, SshKeyId,
+    SubnetId, TaskId, VipId, VolumeId, VolumeTypeId,
 };
 
 pub use compute::{
@@ -24,6 +32,6 @@ pub use network::{
     SecurityGroupRule, SecurityGroups, Subnets, VirtualIp, VirtualIpFixedIp, VirtualIps,
 };
 
-pub use routers::{NetworkRouter, RouterNic, RouterStatus, Routers};
+pub use routers::{NetworkRouter, NetworkRouterInterface, RouterNic, RouterStatus, Routers};
 
-pub use storage::{Snapshot, Snapshots, VolumeStatus, VolumeType, Volumes};
+pub use storage::{Snapshot, SnapshotStatus, Snapshots, VolumeStatus, VolumeType, Volumes};
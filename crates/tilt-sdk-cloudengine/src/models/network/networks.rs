@@ -1,3 +1,7 @@
+use crate::models::{
+    FloatingIpId, InstanceId, NetworkId, PortId, RegionId, SecurityGroupId, SecurityGroupRuleId,
+    SubnetId, VipId,
+};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -17,7 +21,7 @@ pub struct DhcpSettings {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Networks {
-    pub id: Uuid,
+    pub id: NetworkId,
     pub name: String,
     pub status: String,
     pub description: Option<String>,
@@ -30,12 +34,12 @@ pub type Network = Networks;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NetworkInterface {
-    pub id: Uuid,
-    pub network_id: Uuid,
+    pub id: PortId,
+    pub network_id: NetworkId,
     pub network_name: String,
     pub mac_address: String,
     pub ip_addresses: Vec<IpAddress>,
-    pub instance_id: Option<Uuid>,
+    pub instance_id: Option<InstanceId>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -47,7 +51,7 @@ pub struct IpAddress {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SecurityGroups {
-    pub id: Uuid,
+    pub id: SecurityGroupId,
     pub name: String,
     pub description: Option<String>,
     pub status: String,
@@ -59,32 +63,32 @@ pub type SecurityGroup = SecurityGroups;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SecurityGroupRule {
-    pub id: Uuid,
-    pub security_group_id: Uuid,
+    pub id: SecurityGroupRuleId,
+    pub security_group_id: SecurityGroupId,
     pub direction: String,
     pub protocol: String,
     pub ethertype: String,
     pub port_range_min: Option<u32>,
     pub port_range_max: Option<u32>,
     pub remote_ip_prefix: Option<String>,
-    pub remote_group_id: Option<Uuid>,
+    pub remote_group_id: Option<SecurityGroupId>,
     pub description: Option<String>,
     pub status: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VirtualIp {
-    pub id: Uuid,
+    pub id: VipId,
     pub name: String,
     pub mac_address: String,
     pub status: String,
     pub floating_ip: Option<FloatingIpInfo>,
     pub fixed_ips: Vec<VirtualIpFixedIp>,
-    pub network_id: Option<String>,
+    pub network_id: Option<NetworkId>,
     pub network_name: Option<String>,
-    pub subnet_id: Option<String>,
+    pub subnet_id: Option<SubnetId>,
     pub subnet_name: Option<String>,
-    pub region_id: Option<String>,
+    pub region_id: Option<RegionId>,
     pub region_name: Option<String>,
     pub l2_enabled: bool,
     pub address_mode: String,
@@ -94,13 +98,13 @@ pub struct VirtualIp {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VirtualIpFixedIp {
     pub id: Uuid,
-    pub subnet_id: Uuid,
+    pub subnet_id: SubnetId,
     pub ip_address: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FloatingIpInfo {
-    pub id: Uuid,
+    pub id: FloatingIpId,
     pub bandwidth: u64,
     pub ip_address: String,
 }
@@ -109,14 +113,14 @@ pub type VirtualIps = VirtualIp;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FloatingIp {
-    pub id: Uuid,
+    pub id: FloatingIpId,
     pub floating_ip_address: String,
     pub bandwidth: u64,
     pub status: String,
     pub parent_item_id: Option<Uuid>,
     pub parent_item_type: Option<String>,
     pub created_time: Option<String>,
-    pub region_id: Option<String>,
+    pub region_id: Option<RegionId>,
 }
 
 pub type FloatingIps = FloatingIp;
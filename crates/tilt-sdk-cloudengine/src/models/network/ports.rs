@@ -1,3 +1,4 @@
+use crate::models::{FlavorId, ImageId, InstanceId, NetworkId, PortId, SecurityGroupId, SubnetId};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -9,17 +10,17 @@ pub enum NetworkItem {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NicPort {
-    pub id: Uuid,
+    pub id: PortId,
     pub name: String,
     pub ip_address: Option<String>,
     pub mac_address: String,
-    pub network_id: Uuid,
+    pub network_id: NetworkId,
     pub network_name: String,
-    pub subnet_id: Option<Uuid>,
+    pub subnet_id: Option<SubnetId>,
     pub subnet_name: Option<String>,
-    pub security_group_ids: Vec<Uuid>,
+    pub security_group_ids: Vec<SecurityGroupId>,
     pub security_group_names: Vec<String>,
-    pub instance_id: Option<Uuid>,
+    pub instance_id: Option<InstanceId>,
     pub instance_name: Option<String>,
     pub state: String,
     pub availability_zone: Option<String>,
@@ -27,12 +28,12 @@ pub struct NicPort {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InstanceItem {
-    pub id: Uuid,
+    pub id: InstanceId,
     pub name: String,
     pub status: String,
-    pub flavor_id: Uuid,
+    pub flavor_id: FlavorId,
     pub flavor_name: String,
-    pub image_id: Uuid,
+    pub image_id: ImageId,
     pub image_name: String,
     pub ip_address: Option<String>,
     pub network_name: Option<String>,
@@ -42,17 +43,17 @@ pub struct InstanceItem {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Ports {
-    pub id: Uuid,
+    pub id: PortId,
     pub name: String,
     pub mac_address: String,
     pub ip_address: Option<String>,
-    pub subnet_id: Option<Uuid>,
+    pub subnet_id: Option<SubnetId>,
     pub subnet_name: Option<String>,
-    pub network_id: Uuid,
+    pub network_id: NetworkId,
     pub network_name: String,
-    pub security_group_ids: Vec<Uuid>,
+    pub security_group_ids: Vec<SecurityGroupId>,
     pub security_group_names: Vec<String>,
-    pub instance_id: Option<Uuid>,
+    pub instance_id: Option<InstanceId>,
     pub instance_name: Option<String>,
     pub state: String,
 }
@@ -60,8 +61,8 @@ pub struct Ports {
 impl NetworkItem {
     pub fn id(&self) -> Uuid {
         match self {
-            NetworkItem::Nic(nic) => nic.id,
-            NetworkItem::Instance(inst) => inst.id,
+            NetworkItem::Nic(nic) => nic.id.into(),
+            NetworkItem::Instance(inst) => inst.id.into(),
         }
     }
 
@@ -90,7 +91,7 @@ impl NetworkItem {
         }
     }
 
-    pub fn instance_id(&self) -> Option<uuid::Uuid> {
+    pub fn instance_id(&self) -> Option<InstanceId> {
         match self {
             NetworkItem::Nic(nic) => nic.instance_id,
             NetworkItem::Instance(inst) => Some(inst.id),
@@ -1,4 +1,5 @@
 use crate::models::StatusEnum;
+use crate::models::{NetworkId, RegionId, RouteTableId};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -46,7 +47,7 @@ pub struct RouteTableRoute {
 
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct RouteTableRegion {
-    pub id: Uuid,
+    pub id: RegionId,
     pub name: String,
     pub description: Option<String>,
 }
@@ -55,7 +56,7 @@ pub struct RouteTableRegion {
 pub struct RouteTableNetwork {
     pub name: String,
     pub status: String,
-    pub id: Uuid,
+    pub id: NetworkId,
     pub description: Option<String>,
     pub create_time: String,
     pub shared_from: Option<String>,
@@ -63,7 +64,7 @@ pub struct RouteTableNetwork {
 
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct RouteTables {
-    pub id: Uuid,
+    pub id: RouteTableId,
     pub name: String,
     pub description: Option<String>,
     pub routes: Vec<RouteTableRoute>,
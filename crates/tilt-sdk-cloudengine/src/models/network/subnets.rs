@@ -1,9 +1,9 @@
+use crate::models::{NetworkId, RegionId, SubnetId};
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Subnets {
-    pub id: Uuid,
+    pub id: SubnetId,
     pub name: String,
     pub status: String,
     pub cidr: String,
@@ -11,9 +11,9 @@ pub struct Subnets {
     pub gateway_ip: Option<String>,
     pub enable_dhcp: bool,
     pub dhcp_settings: Option<super::networks::DhcpSettings>,
-    pub network_id: Uuid,
+    pub network_id: NetworkId,
     pub network_name: String,
-    pub region_id: String,
+    pub region_id: RegionId,
     pub region_name: String,
     pub shared_from: Option<String>,
     pub shared_to: Vec<String>,
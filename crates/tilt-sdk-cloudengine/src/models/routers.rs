@@ -1,10 +1,10 @@
 use crate::models::StatusEnum;
+use crate::models::{NetworkRouterId, PortId, RouterInterfaceId, SnatRouterId, SubnetId};
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
 
-#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Default)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct RouterNic {
-    pub id: Uuid,
+    pub id: PortId,
     pub name: String,
     pub ip_address: String,
     pub network_name: Option<String>,
@@ -43,7 +43,7 @@ impl StatusEnum for RouterStatus {
 
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Routers {
-    pub id: Uuid,
+    pub id: SnatRouterId,
     pub name: String,
     pub status: RouterStatus,
     pub bandwidth: u64,
@@ -55,10 +55,20 @@ pub struct Routers {
 
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct NetworkRouter {
-    pub id: Uuid,
+    pub id: NetworkRouterId,
     pub name: String,
     pub description: Option<String>,
     pub status: String,
     pub create_time: String,
     pub update_time: Option<String>,
 }
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct NetworkRouterInterface {
+    pub id: RouterInterfaceId,
+    pub router_id: NetworkRouterId,
+    pub subnet_id: SubnetId,
+    pub port_id: Option<PortId>,
+    pub ip_address: String,
+    pub status: String,
+}
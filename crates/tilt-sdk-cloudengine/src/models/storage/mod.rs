@@ -1,5 +1,5 @@
 pub mod snapshots;
 pub mod volumes;
 
-pub use snapshots::{Snapshot, Snapshots};
+pub use snapshots::{Snapshot, SnapshotStatus, Snapshots};
 pub use volumes::{VolumeStatus, VolumeType, Volumes};
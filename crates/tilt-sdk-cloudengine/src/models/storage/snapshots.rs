@@ -1,15 +1,16 @@
+use crate::StatusEnum;
+use crate::models::{SnapshotId, VolumeId, VolumeTypeId};
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Snapshots {
-    pub id: Uuid,
+    pub id: SnapshotId,
     pub name: String,
     pub size: i32,
-    pub status: String,
-    pub volume_id: Uuid,
+    pub status: SnapshotStatus,
+    pub volume_id: VolumeId,
     pub volume_name: Option<String>,
-    pub volume_type_id: Option<Uuid>,
+    pub volume_type_id: Option<VolumeTypeId>,
     pub volume_type_name: Option<String>,
     pub availability_zone: Option<String>,
     pub created_at: String,
@@ -18,3 +19,35 @@ pub struct Snapshots {
 }
 
 pub type Snapshot = Snapshots;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SnapshotStatus {
+    #[serde(rename = "available")]
+    Available,
+    #[serde(rename = "creating")]
+    Creating,
+    #[serde(rename = "deleting")]
+    Deleting,
+    #[serde(rename = "restoring")]
+    Restoring,
+    #[serde(rename = "error")]
+    Error,
+    #[serde(rename = "error_deleting")]
+    ErrorDeleting,
+    #[serde(rename = "unknown")]
+    Unknown,
+}
+
+impl StatusEnum for SnapshotStatus {
+    fn from_string(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "available" => SnapshotStatus::Available,
+            "creating" => SnapshotStatus::Creating,
+            "deleting" => SnapshotStatus::Deleting,
+            "restoring" => SnapshotStatus::Restoring,
+            "error" => SnapshotStatus::Error,
+            "error_deleting" => SnapshotStatus::ErrorDeleting,
+            _ => SnapshotStatus::Unknown,
+        }
+    }
+}
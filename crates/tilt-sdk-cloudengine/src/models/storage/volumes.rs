@@ -1,24 +1,24 @@
 use crate::StatusEnum;
+use crate::models::{InstanceId, VolumeId, VolumeTypeId};
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VolumeType {
-    pub id: Uuid,
+    pub id: VolumeTypeId,
     pub name: String,
     pub description: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Volumes {
-    pub id: Uuid,
+    pub id: VolumeId,
     pub name: String,
     pub size: u64,
     pub status: VolumeStatus,
-    pub volume_type_id: Uuid,
+    pub volume_type_id: VolumeTypeId,
     pub volume_type_name: Option<String>,
     pub availability_zone: Option<String>,
-    pub attached_server_id: Option<Uuid>,
+    pub attached_server_id: Option<InstanceId>,
     pub attached_server_name: Option<String>,
     pub device: Option<String>,
     pub bootable: bool,
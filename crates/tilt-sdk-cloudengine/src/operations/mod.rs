@@ -1,94 +1,375 @@
-use crate::client::ComputeClient;
-use crate::models::Tasks;
-use std::{
-    future::Future,
-    pin::Pin,
-    task::{Context, Poll},
-};
-use tracing::info_span;
-use uuid::Uuid;
-
-const MAX_POLL_ATTEMPTS: u32 = 150;
-
-pub struct Operation<'a> {
-    task_id: Uuid,
-    client: ComputeClient<'a>,
-    poll_count: u32,
-    last_task: Option<Tasks>,
+use crate::client::{ComputeClient, Resource};
+use crate::error::ComputeError;
+use crate::models::{TaskId, TaskStatus, Tasks};
+use futures::future::BoxFuture;
+use futures::stream::{self, BoxStream, StreamExt};
+use std::future::{Future, IntoFuture};
+use std::sync::Arc;
+use std::time::Duration;
+use tilt_sdk::Client;
+use tokio::sync::watch;
+use tokio::time::Instant;
+use tracing::{Instrument, Span, debug, info_span};
+
+mod outcome;
+mod wait;
+
+pub use outcome::{DeleteResponse, MutationOutcome, TaskHandle};
+pub use wait::{WaitConfig, WaitStatus, wait_deleted, wait_until};
+
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+const DEFAULT_MAX_POLL_INTERVAL: Duration = Duration::from_secs(30);
+const DEFAULT_BACKOFF_FACTOR: f64 = 1.5;
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+const DEFAULT_MAX_TRANSIENT_RETRIES: u32 = 5;
+
+#[derive(Debug, Clone)]
+pub struct OperationOptions {
+    poll_interval: Duration,
+    max_poll_interval: Duration,
+    backoff_factor: f64,
+    timeout: Duration,
+    max_transient_retries: u32,
 }
 
-impl<'a> Operation<'a> {
-    pub fn new(task_id: Uuid, client: ComputeClient<'a>) -> Self {
+impl OperationOptions {
+    pub fn new() -> Self {
+        Self {
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            max_poll_interval: DEFAULT_MAX_POLL_INTERVAL,
+            backoff_factor: DEFAULT_BACKOFF_FACTOR,
+            timeout: DEFAULT_TIMEOUT,
+            max_transient_retries: DEFAULT_MAX_TRANSIENT_RETRIES,
+        }
+    }
+
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    pub fn max_poll_interval(mut self, max_poll_interval: Duration) -> Self {
+        self.max_poll_interval = max_poll_interval;
+        self
+    }
+
+    pub fn backoff_factor(mut self, backoff_factor: f64) -> Self {
+        self.backoff_factor = backoff_factor.max(1.0);
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn max_transient_retries(mut self, max_transient_retries: u32) -> Self {
+        self.max_transient_retries = max_transient_retries;
+        self
+    }
+
+    fn next_interval(&self, current: Duration) -> Duration {
+        current
+            .mul_f64(self.backoff_factor)
+            .min(self.max_poll_interval.max(self.poll_interval))
+    }
+}
+
+impl Default for OperationOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CancelHandle {
+    sender: Arc<watch::Sender<bool>>,
+}
+
+impl CancelHandle {
+    pub fn cancel(&self) {
+        self.sender.send_replace(true);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        *self.sender.borrow()
+    }
+}
+
+type ProgressCallback = Box<dyn Fn(&Tasks) + Send + Sync>;
+
+pub struct Operation {
+    task_id: TaskId,
+    client: ComputeClient,
+    options: OperationOptions,
+    cancel: Option<watch::Receiver<bool>>,
+    on_progress: Option<ProgressCallback>,
+    fetch_resource: bool,
+}
+
+#[derive(Debug)]
+pub enum OperationEvent {
+    Progress(Box<Tasks>),
+    Finished(Result<Box<Resource>, OperationError>),
+}
+
+impl Operation {
+    pub fn new(task_id: TaskId, client: ComputeClient) -> Self {
         Self {
             task_id,
             client,
-            poll_count: 0,
-            last_task: None,
+            options: OperationOptions::default(),
+            cancel: None,
+            on_progress: None,
+            fetch_resource: true,
+        }
+    }
+
+    /// Tracks a task started through one of the per-service clients.
+    pub(crate) fn for_task(task: Tasks, client: &Client) -> Self {
+        Self::new(task.id, ComputeClient::new(client))
+    }
+
+    pub fn task_id(&self) -> TaskId {
+        self.task_id
+    }
+
+    pub fn with_options(mut self, options: OperationOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub fn on_progress<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&Tasks) + Send + Sync + 'static,
+    {
+        self.on_progress = Some(Box::new(callback));
+        self
+    }
+
+    pub fn cancel_handle(&mut self) -> CancelHandle {
+        let (sender, receiver) = watch::channel(false);
+        self.cancel = Some(receiver);
+        CancelHandle {
+            sender: Arc::new(sender),
+        }
+    }
+
+    pub fn progress(self) -> BoxStream<'static, OperationEvent> {
+        let span = info_span!("operation_progress", task_id = %self.task_id);
+        let state = ProgressState {
+            span,
+            deadline: Instant::now() + self.options.timeout,
+            timeout: self.options.timeout,
+            cancel: self.cancel,
+            on_progress: self.on_progress,
+            poller: Poller {
+                interval: self.options.poll_interval,
+                task_id: self.task_id,
+                client: self.client,
+                options: self.options,
+                polled: false,
+                transient_failures: 0,
+                last: None,
+            },
+            fetch_resource: self.fetch_resource,
+            terminal: None,
+            finished: false,
+        };
+        stream::unfold(state, |mut state| async move {
+            let span = state.span.clone();
+            let event = state.next_event().instrument(span).await?;
+            Some((event, state))
+        })
+        .boxed()
+    }
+
+    pub async fn wait(self) -> Result<Resource, OperationError> {
+        let mut events = self.progress();
+        while let Some(event) = events.next().await {
+            if let OperationEvent::Finished(result) = event {
+                return result.map(|resource| *resource);
+            }
+        }
+        Err(OperationError::Cancelled)
+    }
+
+    pub async fn wait_task(mut self) -> Result<Tasks, OperationError> {
+        self.fetch_resource = false;
+        let mut events = self.progress();
+        let mut last = None;
+        while let Some(event) = events.next().await {
+            match event {
+                OperationEvent::Progress(task) => last = Some(*task),
+                OperationEvent::Finished(Err(e)) => return Err(e),
+                OperationEvent::Finished(Ok(_)) => {}
+            }
         }
+        last.ok_or(OperationError::Cancelled)
     }
 }
 
-impl<'a> Future for Operation<'a> {
-    type Output = Result<crate::client::Resource, OperationError>;
+impl IntoFuture for Operation {
+    type Output = Result<Resource, OperationError>;
+    type IntoFuture = BoxFuture<'static, Self::Output>;
 
-    fn poll(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let span = info_span!("operation_poll", task_id = %self.task_id);
-        let _guard = span.enter();
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.wait())
+    }
+}
 
-        self.poll_count += 1;
+struct ProgressState {
+    span: Span,
+    deadline: Instant,
+    timeout: Duration,
+    cancel: Option<watch::Receiver<bool>>,
+    on_progress: Option<ProgressCallback>,
+    poller: Poller,
+    fetch_resource: bool,
+    terminal: Option<Tasks>,
+    finished: bool,
+}
 
-        if self.poll_count > MAX_POLL_ATTEMPTS {
-            return Poll::Ready(Err(OperationError::Timeout));
+impl ProgressState {
+    async fn next_event(&mut self) -> Option<OperationEvent> {
+        if self.finished {
+            return None;
+        }
+        if let Some(task) = self.terminal.take() {
+            self.finished = true;
+            if task.status == TaskStatus::Successful && !self.fetch_resource {
+                return None;
+            }
+            let result = guarded(
+                &mut self.cancel,
+                self.deadline,
+                self.timeout,
+                self.poller.finish(task),
+            )
+            .await;
+            return Some(OperationEvent::Finished(result.map(Box::new)));
         }
 
-        let task: crate::models::Tasks =
-            match futures::executor::block_on(self.client.get_task(self.task_id)) {
-                Ok(task) => task,
-                Err(e) => {
-                    tracing::debug!(?e, "Failed to poll task, will retry");
-                    return Poll::Pending;
+        let next = guarded(
+            &mut self.cancel,
+            self.deadline,
+            self.timeout,
+            self.poller.next_change(),
+        )
+        .await;
+        match next {
+            Ok(task) => {
+                if let Some(callback) = &self.on_progress {
+                    callback(&task);
+                }
+                if matches!(task.status, TaskStatus::Successful | TaskStatus::Failed) {
+                    self.terminal = Some(task.clone());
                 }
-            };
+                Some(OperationEvent::Progress(Box::new(task)))
+            }
+            Err(e) => {
+                self.finished = true;
+                Some(OperationEvent::Finished(Err(e)))
+            }
+        }
+    }
+}
 
-        self.last_task = Some(task.clone());
+async fn guarded<T>(
+    cancel: &mut Option<watch::Receiver<bool>>,
+    deadline: Instant,
+    timeout: Duration,
+    future: impl Future<Output = Result<T, OperationError>>,
+) -> Result<T, OperationError> {
+    let cancelled = async {
+        match cancel {
+            Some(receiver) => {
+                if receiver.wait_for(|c| *c).await.is_err() {
+                    std::future::pending::<()>().await;
+                }
+            }
+            None => std::future::pending::<()>().await,
+        }
+    };
+    tokio::select! {
+        result = tokio::time::timeout_at(deadline, future) => {
+            result.unwrap_or(Err(OperationError::Timeout(timeout)))
+        }
+        _ = cancelled => {
+            debug!("Operation cancelled");
+            Err(OperationError::Cancelled)
+        }
+    }
+}
 
-        match task.status {
-            crate::models::TaskStatus::Successful => {
-                tracing::debug!("Task completed successfully, fetching resource");
-                let object_type = task.object_type;
-                let object_id = task.object_id;
-                match futures::executor::block_on(self.client.get_resource(&object_type, object_id))
-                {
-                    Ok(resource) => Poll::Ready(Ok(resource)),
-                    Err(e) => {
-                        let message =
-                            format!("failed to fetch resource after task completed: {}", e);
-                        tracing::debug!(?e, "Resource fetch failed");
-                        Poll::Ready(Err(OperationError::ResourceFetchFailed(message)))
+struct Poller {
+    task_id: TaskId,
+    client: ComputeClient,
+    options: OperationOptions,
+    interval: Duration,
+    polled: bool,
+    transient_failures: u32,
+    last: Option<Tasks>,
+}
+
+impl Poller {
+    async fn next_change(&mut self) -> Result<Tasks, OperationError> {
+        loop {
+            if self.polled {
+                tokio::time::sleep(self.interval).await;
+                self.interval = self.options.next_interval(self.interval);
+            }
+            self.polled = true;
+
+            match self.client.get_task(self.task_id).await {
+                Ok(task) => {
+                    self.transient_failures = 0;
+                    if self.last.as_ref() != Some(&task) {
+                        self.last = Some(task.clone());
+                        return Ok(task);
                     }
                 }
+                Err(e)
+                    if e.is_retryable()
+                        && self.transient_failures < self.options.max_transient_retries =>
+                {
+                    self.transient_failures += 1;
+                    let transient_failures = self.transient_failures;
+                    debug!(?e, transient_failures, "Transient poll error, retrying");
+                }
+                Err(e) => return Err(OperationError::PollFailed(e)),
             }
-            crate::models::TaskStatus::Failed => {
-                let message = task
-                    .error
-                    .clone()
-                    .unwrap_or_else(|| "Task failed without error message".to_string());
-                Poll::Ready(Err(OperationError::TaskFailed(message)))
-            }
-            crate::models::TaskStatus::Running | crate::models::TaskStatus::New => Poll::Pending,
-            _ => Poll::Pending,
         }
     }
+
+    async fn finish(&self, task: Tasks) -> Result<Resource, OperationError> {
+        if task.status == TaskStatus::Failed {
+            let message = task
+                .error
+                .map(|e| e.to_string())
+                .unwrap_or_else(|| "Task failed without error message".to_string());
+            return Err(OperationError::TaskFailed(message));
+        }
+        debug!("Task completed successfully, fetching resource");
+        self.client
+            .fetch_task_object(&task.object_type, task.object_id)
+            .await
+            .map_err(|e| OperationError::ResourceFetchFailed(e.to_string()))
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
 pub enum OperationError {
-    #[error("operation timed out after max polling attempts")]
-    Timeout,
-    #[error("operation is still running, call .await or .poll() again")]
-    NotComplete,
+    #[error("operation timed out after {0:?}")]
+    Timeout(Duration),
+    #[error("operation was cancelled")]
+    Cancelled,
+    #[error("resource reached terminal state: {0}")]
+    TerminalState(String),
     #[error("task failed: {0}")]
     TaskFailed(String),
+    #[error("failed to poll task: {0}")]
+    PollFailed(ComputeError),
     #[error("failed to fetch resource after task completed: {0}")]
     ResourceFetchFailed(String),
 }
@@ -0,0 +1,98 @@
+use super::{Operation, OperationError};
+use crate::client::ComputeClient;
+use crate::client::responses::tasks::TaskWrapper;
+use crate::models::{TaskId, TaskStatus, Tasks};
+use serde::Serialize;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TaskHandle {
+    pub task_id: TaskId,
+    pub object_type: String,
+    pub object_id: Uuid,
+    pub status: TaskStatus,
+}
+
+impl TaskHandle {
+    pub fn operation(&self, client: ComputeClient) -> Operation {
+        Operation::new(self.task_id, client)
+    }
+}
+
+impl From<Tasks> for TaskHandle {
+    fn from(task: Tasks) -> Self {
+        Self {
+            task_id: task.id,
+            object_type: task.object_type,
+            object_id: task.object_id,
+            status: task.status,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum MutationOutcome {
+    Completed,
+    Accepted(TaskHandle),
+    NoContent,
+}
+
+/// What a DELETE endpoint answers with once it accepts the request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeleteResponse {
+    /// Order-backed deletes run asynchronously and return the task tracking them.
+    Task,
+    /// Plain VPC/compute deletes finish synchronously with an empty or informational body.
+    Empty,
+}
+
+impl MutationOutcome {
+    /// Parses a DELETE body according to the shape the endpoint is documented to return.
+    ///
+    /// An empty body is always `NoContent`. For [`DeleteResponse::Task`] any other body
+    /// must be a task, bare or wrapped in `data`; a body that does not parse as one is
+    /// an error rather than a silent `Completed`.
+    pub fn parse(expected: DeleteResponse, body: serde_json::Value) -> serde_json::Result<Self> {
+        let body = match body {
+            serde_json::Value::Null => return Ok(MutationOutcome::NoContent),
+            serde_json::Value::Object(map) if map.is_empty() => {
+                return Ok(MutationOutcome::NoContent);
+            }
+            body => body,
+        };
+        match expected {
+            DeleteResponse::Empty => Ok(MutationOutcome::Completed),
+            DeleteResponse::Task => {
+                let task = match body {
+                    serde_json::Value::Object(mut map) if map.contains_key("data") => {
+                        map.remove("data").unwrap_or_default()
+                    }
+                    body => body,
+                };
+                let wrapper = serde_json::from_value::<TaskWrapper>(task)?;
+                Ok(MutationOutcome::Accepted(Tasks::from(wrapper).into()))
+            }
+        }
+    }
+
+    pub fn task(&self) -> Option<&TaskHandle> {
+        match self {
+            MutationOutcome::Accepted(handle) => Some(handle),
+            MutationOutcome::Completed | MutationOutcome::NoContent => None,
+        }
+    }
+
+    pub fn is_accepted(&self) -> bool {
+        self.task().is_some()
+    }
+
+    pub async fn wait(self, client: ComputeClient) -> Result<Option<Tasks>, OperationError> {
+        match self {
+            MutationOutcome::Accepted(handle) => {
+                handle.operation(client).wait_task().await.map(Some)
+            }
+            MutationOutcome::Completed | MutationOutcome::NoContent => Ok(None),
+        }
+    }
+}
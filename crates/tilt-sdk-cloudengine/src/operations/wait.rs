@@ -0,0 +1,90 @@
+use super::{OperationError, OperationOptions};
+use crate::error::Result;
+use std::future::Future;
+use tracing::debug;
+
+pub type WaitConfig = OperationOptions;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WaitStatus {
+    Ready,
+    Pending,
+    Failed(String),
+}
+
+impl From<bool> for WaitStatus {
+    fn from(ready: bool) -> Self {
+        if ready {
+            WaitStatus::Ready
+        } else {
+            WaitStatus::Pending
+        }
+    }
+}
+
+pub async fn wait_until<T, G, Fut, P>(
+    mut getter: G,
+    mut predicate: P,
+    config: WaitConfig,
+) -> std::result::Result<T, OperationError>
+where
+    G: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+    P: FnMut(&T) -> WaitStatus,
+{
+    let timeout = config.timeout;
+    let poll = async {
+        let mut interval = config.poll_interval;
+        let mut transient_failures = 0;
+        loop {
+            match getter().await {
+                Ok(value) => {
+                    transient_failures = 0;
+                    match predicate(&value) {
+                        WaitStatus::Ready => return Ok(value),
+                        WaitStatus::Failed(reason) => {
+                            return Err(OperationError::TerminalState(reason));
+                        }
+                        WaitStatus::Pending => {}
+                    }
+                }
+                Err(e) if e.is_retryable() && transient_failures < config.max_transient_retries => {
+                    transient_failures += 1;
+                    debug!(?e, transient_failures, "Transient wait error, retrying");
+                }
+                Err(e) => return Err(OperationError::PollFailed(e)),
+            }
+            tokio::time::sleep(interval).await;
+            interval = config.next_interval(interval);
+        }
+    };
+    tokio::time::timeout(timeout, poll)
+        .await
+        .unwrap_or(Err(OperationError::Timeout(timeout)))
+}
+
+pub async fn wait_deleted<T, G, Fut>(
+    mut getter: G,
+    config: WaitConfig,
+) -> std::result::Result<(), OperationError>
+where
+    G: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    wait_until(
+        || {
+            let fetch = getter();
+            async move {
+                match fetch.await {
+                    Ok(_) => Ok(false),
+                    Err(e) if e.is_not_found() => Ok(true),
+                    Err(e) => Err(e),
+                }
+            }
+        },
+        |gone| WaitStatus::from(*gone),
+        config,
+    )
+    .await
+    .map(|_| ())
+}
@@ -0,0 +1,136 @@
+//! Tests for backup order actions
+
+use crate::common::client;
+use tilt_sdk_cloudengine::client::compute::{OrderActionAttrs, OrderActionRequest};
+use tilt_sdk_cloudengine::client::responses::OrderItemRef;
+use tilt_sdk_cloudengine::client::{BackupRestoreTarget, BackupSource};
+use tilt_sdk_cloudengine::{ComputeClient, Service};
+use uuid::Uuid;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[test]
+fn test_restore_target_new_serialization() {
+    let target = BackupRestoreTarget::New {
+        name: "restored-db".to_string(),
+    };
+    let value = serde_json::to_value(&target).unwrap();
+    assert_eq!(value, serde_json::json!({"mode": "new", "name": "restored-db"}));
+}
+
+#[test]
+fn test_order_action_request_shape() {
+    let order_id = Uuid::from_u128(1);
+    let item_id = Uuid::from_u128(2);
+    let request = OrderActionRequest {
+        project_name: "proj".to_string(),
+        id: order_id,
+        item_id,
+        order: OrderActionAttrs {
+            attrs: BackupRestoreTarget::Existing(BackupSource::Volume(Uuid::from_u128(3).into())),
+        },
+    };
+    let value = serde_json::to_value(&request).unwrap();
+
+    assert_eq!(value["project_name"], "proj");
+    assert_eq!(value["id"], order_id.to_string());
+    assert_eq!(value["item_id"], item_id.to_string());
+    assert_eq!(value["order"]["attrs"]["mode"], "existing");
+    assert_eq!(value["order"]["attrs"]["object_id"], Uuid::from_u128(3).to_string());
+}
+
+#[test]
+fn test_order_item_ref_requires_order_and_item_ids() {
+    let item: OrderItemRef = serde_json::from_value(serde_json::json!({
+        "item_id": Uuid::from_u128(1),
+        "order_id": Uuid::from_u128(2),
+        "type": "instance"
+    }))
+    .unwrap();
+    assert_eq!(item.order_id, Uuid::from_u128(2));
+
+    let missing = serde_json::from_value::<OrderItemRef>(serde_json::json!({
+        "item_id": Uuid::from_u128(1)
+    }));
+    assert!(missing.is_err());
+}
+
+#[tokio::test]
+async fn test_backup_order_action_errors_are_attributed_to_order_service() {
+    let server = MockServer::start().await;
+    let backup_id = Uuid::from_u128(1);
+    let order_id = Uuid::from_u128(2);
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/order-service/api/v1/projects/test-project/compute/backup_containers/{}",
+            backup_id
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "item_id": backup_id,
+            "order_id": order_id
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path(format!(
+            "/order-service/api/v1/projects/test-project/orders/{}/actions/delete_backup_container",
+            order_id
+        )))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    let err = compute
+        .delete_backup(backup_id.into())
+        .await
+        .unwrap_err();
+    assert_eq!(err.service, Service::OrderService);
+}
+
+#[tokio::test]
+async fn test_create_backup_uses_typed_source() {
+    let server = MockServer::start().await;
+    let volume_id = Uuid::from_u128(5);
+    let order_id = Uuid::from_u128(6);
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/order-service/api/v1/projects/test-project/compute/volumes/{}",
+            volume_id
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "item_id": volume_id,
+            "order_id": order_id
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path(format!(
+            "/order-service/api/v1/projects/test-project/orders/{}/actions/create_backup",
+            order_id
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": {
+                "id": Uuid::from_u128(7),
+                "object_type": "volume",
+                "object_id": volume_id,
+                "status": "new"
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    let operation = compute
+        .create_backup(BackupSource::Volume(volume_id.into()))
+        .await
+        .unwrap();
+    assert_eq!(operation.task_id(), Uuid::from_u128(7));
+}
@@ -0,0 +1,13 @@
+//! Shared helpers for integration tests
+
+use tilt_sdk::ClientBuilder;
+use wiremock::MockServer;
+
+pub fn client(server: &MockServer) -> tilt_sdk::Client {
+    ClientBuilder::new()
+        .base_url(&server.uri())
+        .token("token")
+        .project("test-project")
+        .build()
+        .unwrap()
+}
@@ -0,0 +1,120 @@
+//! Tests for floating IP order actions against a local stand-in server
+
+use crate::common::client;
+use tilt_sdk_cloudengine::ComputeClient;
+use tilt_sdk_cloudengine::client::FloatingIpTarget;
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const FIP_ID: &str = "00000000-0000-0000-0000-000000000001";
+const ORDER_ID: &str = "00000000-0000-0000-0000-000000000002";
+const PORT_ID: &str = "00000000-0000-0000-0000-000000000003";
+const TASK_ID: &str = "00000000-0000-0000-0000-000000000004";
+
+#[tokio::test]
+async fn test_associate_floating_ip_sends_order_action() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/vpc/api/v1/projects/test-project/public-ip-addresses/{}",
+            FIP_ID
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "item_id": FIP_ID,
+            "order_id": ORDER_ID,
+            "data": {"state": "on", "config": {"floating_ip_address": "203.0.113.10", "bandwidth": 100}}
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path(format!(
+            "/order-service/api/v1/projects/test-project/orders/{}/actions/associate_public_ip",
+            ORDER_ID
+        )))
+        .and(body_partial_json(serde_json::json!({
+            "id": ORDER_ID,
+            "item_id": FIP_ID,
+            "order": {"attrs": {"parent_item_id": PORT_ID, "parent_item_type": "nic"}}
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": {"id": TASK_ID, "status": "new"}
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    compute
+        .associate_floating_ip(
+            FIP_ID.parse().unwrap(),
+            FloatingIpTarget::Port(PORT_ID.parse().unwrap()),
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_allocate_floating_ip_rejects_zero_bandwidth() {
+    let server = MockServer::start().await;
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    let err = compute
+        .allocate_floating_ip("00000000-0000-0000-0000-000000000009".parse().unwrap(), 0)
+        .await
+        .err()
+        .unwrap();
+
+    assert!(err.message.contains("bandwidth"));
+}
+
+#[tokio::test]
+async fn test_floating_ip_action_rejects_missing_or_nil_order_reference() {
+    let server = MockServer::start().await;
+    let fip_path = format!(
+        "/vpc/api/v1/projects/test-project/public-ip-addresses/{}",
+        FIP_ID
+    );
+    Mock::given(method("GET"))
+        .and(path(fip_path.clone()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "item_id": FIP_ID,
+            "order_id": "00000000-0000-0000-0000-000000000000"
+        })))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(fip_path.clone()))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({"item_id": FIP_ID})),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("PATCH"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    let err = compute
+        .disassociate_floating_ip(FIP_ID.parse().unwrap())
+        .await
+        .err()
+        .unwrap();
+    assert!(err.message.contains("no order reference"));
+    assert_eq!(err.endpoint.as_deref(), Some(fip_path.as_str()));
+
+    assert!(
+        compute
+            .disassociate_floating_ip(FIP_ID.parse().unwrap())
+            .await
+            .is_err()
+    );
+}
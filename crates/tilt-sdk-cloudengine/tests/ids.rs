@@ -0,0 +1,59 @@
+//! Tests for typed resource identifiers
+
+use crate::common::client;
+use tilt_sdk_cloudengine::client::ListOptions;
+use tilt_sdk_cloudengine::models::Subnets;
+use tilt_sdk_cloudengine::{ComputeClient, InstanceId, NetworkId, RegionId};
+use uuid::Uuid;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[test]
+fn test_ids_parse_display_and_serialize_transparently() {
+    let raw = "9b2f0d4e-6a3c-4e1b-8f5d-2c7a9e0b1d3f";
+    let id: InstanceId = raw.parse().unwrap();
+    assert_eq!(id.to_string(), raw);
+    assert_eq!(id, Uuid::parse_str(raw).unwrap());
+    assert_eq!(serde_json::to_value(id).unwrap(), serde_json::json!(raw));
+    assert!("not-a-uuid".parse::<InstanceId>().is_err());
+
+    let region: RegionId = serde_json::from_value(serde_json::json!("ru-central2")).unwrap();
+    assert_eq!(region, "ru-central2");
+    assert_eq!(
+        RegionId::from(Uuid::nil()).as_str(),
+        Uuid::nil().to_string()
+    );
+}
+
+#[tokio::test]
+async fn test_list_subnets_filters_by_typed_network_id() {
+    let server = MockServer::start().await;
+    let network_id = NetworkId::new(Uuid::from_u128(7));
+    Mock::given(method("GET"))
+        .and(path("/vpc/api/v1/projects/test-project/subnets"))
+        .and(query_param("network_id", network_id.to_string()))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+                "id": Uuid::from_u128(1),
+                "name": "app",
+                "cidr": "10.0.0.0/24",
+                "network": {"id": Uuid::from_u128(7), "name": "net"},
+                "region": {"id": "ru-central2", "name": "Moscow"}
+            }])),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    let subnets: Vec<Subnets> = compute
+        .list_subnets(ListOptions::all(), Some(network_id))
+        .await
+        .unwrap();
+
+    assert_eq!(subnets.len(), 1);
+    assert_eq!(subnets[0].network_id, network_id);
+    assert_eq!(subnets[0].region_id, "ru-central2");
+}
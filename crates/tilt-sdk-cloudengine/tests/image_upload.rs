@@ -0,0 +1,336 @@
+//! Tests for chunked image upload against a local stand-in server
+
+use crate::common::client;
+use std::io::Cursor;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tilt_sdk::SdkError;
+use tilt_sdk_cloudengine::client::{
+    DiskFormat, ImageSource, ImageUploadOptions, ImageUploadRequest, UploadProgress,
+};
+use tilt_sdk_cloudengine::{ComputeClient, ImageUploadStatus};
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const UPLOAD_ID: &str = "00000000-0000-0000-0000-000000000001";
+const BASE: &str = "/image-uploader/api/v1/projects/test-project/image-uploads";
+// sha256 of b"0123456789"
+const CHECKSUM: &str = "84d89877f0d4041efb6bf91a16f0248f2fd573e6af05c19f96bedb9f882f7882";
+
+fn request() -> ImageUploadRequest {
+    ImageUploadRequest {
+        name: "golden".to_string(),
+        disk_format: DiskFormat::Qcow2,
+        os_distro: Some("ubuntu".to_string()),
+        os_version: Some("22.04".to_string()),
+    }
+}
+
+fn options() -> ImageUploadOptions {
+    ImageUploadOptions::new()
+        .chunk_size(4)
+        .retry_delay(Duration::from_millis(1))
+        .poll_interval(Duration::from_millis(1))
+}
+
+fn upload_json(status: &str, checksum: &str) -> serde_json::Value {
+    serde_json::json!({
+        "id": UPLOAD_ID,
+        "name": "golden",
+        "status": status,
+        "checksum": checksum,
+    })
+}
+
+async fn mount_lifecycle(server: &MockServer, checksum: &str) {
+    Mock::given(method("POST"))
+        .and(path(BASE))
+        .respond_with(ResponseTemplate::new(200).set_body_json(upload_json("pending", checksum)))
+        .mount(server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(format!("{}/{}/complete", BASE, UPLOAD_ID)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(upload_json("pending", checksum)))
+        .mount(server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("{}/{}", BASE, UPLOAD_ID)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(upload_json("active", checksum)))
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_upload_image_sends_chunks_and_reports_progress() {
+    let server = MockServer::start().await;
+    mount_lifecycle(&server, CHECKSUM).await;
+    for range in ["bytes 0-3/10", "bytes 4-7/10", "bytes 8-9/10"] {
+        Mock::given(method("PUT"))
+            .and(path(format!("{}/{}/data", BASE, UPLOAD_ID)))
+            .and(header("Content-Range", range))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+    let seen: Arc<Mutex<Vec<UploadProgress>>> = Arc::default();
+    let recorder = seen.clone();
+
+    let upload = compute
+        .upload_image(
+            ImageSource::reader(Cursor::new(b"0123456789".to_vec())),
+            request(),
+            options().on_progress(move |p| recorder.lock().unwrap().push(p)),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(upload.status, ImageUploadStatus::Active);
+    let uploaded: Vec<u64> = seen
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|p| p.uploaded_bytes)
+        .collect();
+    assert_eq!(uploaded, vec![0, 4, 8, 10]);
+}
+
+#[tokio::test]
+async fn test_upload_image_resumes_from_server_offset() {
+    let server = MockServer::start().await;
+    mount_lifecycle(&server, CHECKSUM).await;
+    Mock::given(method("PUT"))
+        .and(path(format!("{}/{}/data", BASE, UPLOAD_ID)))
+        .and(header("Content-Range", "bytes 4-7/10"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path(format!("{}/{}/data", BASE, UPLOAD_ID)))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("{}/{}/offset", BASE, UPLOAD_ID)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"offset": 4})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    let upload = compute
+        .upload_image(
+            ImageSource::reader(Cursor::new(b"0123456789".to_vec())),
+            request(),
+            options(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(upload.status, ImageUploadStatus::Active);
+}
+
+#[tokio::test]
+async fn test_upload_image_rejects_checksum_mismatch() {
+    let server = MockServer::start().await;
+    mount_lifecycle(&server, "deadbeef").await;
+    Mock::given(method("PUT"))
+        .and(path(format!("{}/{}/data", BASE, UPLOAD_ID)))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    let err = compute
+        .upload_image(
+            ImageSource::reader(Cursor::new(b"0123456789".to_vec())),
+            request(),
+            options(),
+        )
+        .await
+        .unwrap_err();
+
+    assert!(err.message.contains("checksum mismatch"));
+    assert!(err.source.is_some());
+}
+
+#[tokio::test]
+async fn test_import_image_rejects_non_http_url() {
+    let server = MockServer::start().await;
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    let err = compute
+        .import_image_from_url("file:///tmp/image.qcow2", request())
+        .await
+        .unwrap_err();
+
+    assert!(err.message.contains("invalid image source URL"));
+}
+
+#[tokio::test]
+async fn test_upload_image_treats_missing_offset_as_chunk_end() {
+    let server = MockServer::start().await;
+    mount_lifecycle(&server, CHECKSUM).await;
+    Mock::given(method("PUT"))
+        .and(path(format!("{}/{}/data", BASE, UPLOAD_ID)))
+        .and(header("Content-Range", "bytes 4-7/10"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path(format!("{}/{}/data", BASE, UPLOAD_ID)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("{}/{}/offset", BASE, UPLOAD_ID)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+    let seen: Arc<Mutex<Vec<UploadProgress>>> = Arc::default();
+    let recorder = seen.clone();
+
+    let upload = compute
+        .upload_image(
+            ImageSource::reader(Cursor::new(b"0123456789".to_vec())),
+            request(),
+            options().on_progress(move |p| recorder.lock().unwrap().push(p)),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(upload.status, ImageUploadStatus::Active);
+    let uploaded: Vec<u64> = seen
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|p| p.uploaded_bytes)
+        .collect();
+    assert_eq!(uploaded, vec![0, 4, 8, 10]);
+}
+
+#[tokio::test]
+async fn test_upload_image_rejects_offset_that_does_not_advance() {
+    let server = MockServer::start().await;
+    mount_lifecycle(&server, CHECKSUM).await;
+    Mock::given(method("PUT"))
+        .and(path(format!("{}/{}/data", BASE, UPLOAD_ID)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"offset": 0})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    let err = compute
+        .upload_image(
+            ImageSource::reader(Cursor::new(b"0123456789".to_vec())),
+            request(),
+            options(),
+        )
+        .await
+        .unwrap_err();
+
+    assert!(err.message.contains("server confirmed offset 0"));
+    assert!(err.source.is_some());
+}
+
+#[tokio::test]
+async fn test_upload_image_reports_failed_upload_as_server_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(BASE))
+        .respond_with(ResponseTemplate::new(200).set_body_json(upload_json("pending", CHECKSUM)))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(format!("{}/{}/complete", BASE, UPLOAD_ID)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(upload_json("pending", CHECKSUM)))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("{}/{}", BASE, UPLOAD_ID)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(upload_json("failed", CHECKSUM)))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path(format!("{}/{}/data", BASE, UPLOAD_ID)))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    let err = compute
+        .upload_image(
+            ImageSource::reader(Cursor::new(b"0123456789".to_vec())),
+            request(),
+            options(),
+        )
+        .await
+        .unwrap_err();
+
+    assert!(err.message.contains("ended as Failed"));
+    assert!(matches!(err.source, Some(SdkError::Unexpected(_))));
+}
+
+#[tokio::test]
+async fn test_upload_image_waits_for_rate_limit_retry_after() {
+    let server = MockServer::start().await;
+    mount_lifecycle(&server, CHECKSUM).await;
+    Mock::given(method("PUT"))
+        .and(path(format!("{}/{}/data", BASE, UPLOAD_ID)))
+        .and(header("Content-Range", "bytes 4-7/10"))
+        .respond_with(ResponseTemplate::new(429).set_body_json(serde_json::json!({"message": "0"})))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path(format!("{}/{}/data", BASE, UPLOAD_ID)))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("{}/{}/offset", BASE, UPLOAD_ID)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"offset": 4})))
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    // The server's zero-second retry_after wins over the much longer local delay.
+    let upload = tokio::time::timeout(
+        Duration::from_secs(5),
+        compute.upload_image(
+            ImageSource::reader(Cursor::new(b"0123456789".to_vec())),
+            request(),
+            options().retry_delay(Duration::from_secs(60)),
+        ),
+    )
+    .await
+    .expect("retry_after was ignored")
+    .unwrap();
+
+    assert_eq!(upload.status, ImageUploadStatus::Active);
+}
@@ -0,0 +1,212 @@
+//! Tests for uniform list options across list endpoints
+
+use crate::common::client;
+use tilt_sdk_cloudengine::ComputeClient;
+use tilt_sdk_cloudengine::client::{ListOptions, PortFilter};
+use tilt_sdk_cloudengine::models::{AvailabilityZoneId, NetworkItem};
+use uuid::Uuid;
+use wiremock::matchers::{method, path, query_param, query_param_is_missing};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn port_json(id: u128, instance: Option<u128>) -> serde_json::Value {
+    let mut port = serde_json::json!({
+        "item_id": Uuid::from_u128(id),
+        "type": "nic",
+        "data": {"config": {"name": format!("port-{}", id), "fixed_ips": []}}
+    });
+    if let Some(instance) = instance {
+        port["data"]["parent_item"] = serde_json::json!({
+            "item_id": Uuid::from_u128(instance),
+            "type": "instance",
+            "data": {"config": {"id": Uuid::from_u128(instance), "name": "vm"}}
+        });
+    }
+    port
+}
+
+#[tokio::test]
+async fn test_unpaginated_endpoint_honours_limit_and_page() {
+    let server = MockServer::start().await;
+    let networks: Vec<_> = (1..=5u128)
+        .map(|i| serde_json::json!({"id": Uuid::from_u128(i), "name": format!("net-{}", i)}))
+        .collect();
+    Mock::given(method("GET"))
+        .and(path("/vpc/api/v1/projects/test-project/networks"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(networks))
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    let page = compute
+        .list_networks(ListOptions::new().limit(2).page(2))
+        .await
+        .unwrap();
+    let names: Vec<_> = page.iter().map(|n| n.name.as_str()).collect();
+    assert_eq!(names, vec!["net-3", "net-4"]);
+
+    let all = compute.list_networks(ListOptions::all()).await.unwrap();
+    assert_eq!(all.len(), 5);
+}
+
+#[tokio::test]
+async fn test_collection_without_total_count_is_fetched_once() {
+    let server = MockServer::start().await;
+    let vips: Vec<_> = (1..=150u128)
+        .map(|i| serde_json::json!({"item_id": Uuid::from_u128(i)}))
+        .collect();
+    Mock::given(method("GET"))
+        .and(path(
+            "/vpc/api/v1/projects/test-project/virtual-ip-addresses",
+        ))
+        .and(query_param_is_missing("page"))
+        .and(query_param_is_missing("per_page"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"list": vips})))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    let all = compute.list_vips(ListOptions::all()).await.unwrap();
+    assert_eq!(all.len(), 150);
+
+    let second = compute.list_vips(ListOptions::new().page(2)).await.unwrap();
+    assert_eq!(second.len(), 50);
+    assert_eq!(second[0].id, Uuid::from_u128(101));
+}
+
+#[tokio::test]
+async fn test_list_ports_fetches_all_pages_through_shared_engine() {
+    let server = MockServer::start().await;
+    let ports_path = "/vpc/api/v1/projects/test-project/network-interfaces";
+    Mock::given(method("GET"))
+        .and(path(ports_path))
+        .and(query_param("page", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "list": [port_json(1, Some(11)), port_json(2, None)],
+            "meta": {"total_count": 3}
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(ports_path))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "list": [port_json(3, Some(13))],
+            "meta": {"total_count": 3}
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    let items = compute
+        .list_ports(ListOptions::all(), PortFilter::Instance)
+        .await
+        .unwrap();
+
+    let instances = items
+        .iter()
+        .filter(|item| matches!(item, NetworkItem::Instance(_)))
+        .count();
+    let nics = items
+        .iter()
+        .filter(|item| matches!(item, NetworkItem::Nic(_)))
+        .count();
+    assert_eq!(instances, 2);
+    assert_eq!(nics, 2);
+}
+
+#[tokio::test]
+async fn test_list_flavors_adds_zone_filter_to_collection_query() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/flavors"))
+        .and(query_param("include", "total_count"))
+        .and(query_param("availability_zone_id", "ru-central2-a"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "list": [{"id": Uuid::from_u128(1), "name": "m1.small"}],
+            "meta": {"total_count": 1}
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    let zone = AvailabilityZoneId::new("ru-central2-a");
+    let flavors = compute
+        .list_flavors(ListOptions::all(), Some(&zone))
+        .await
+        .unwrap();
+    assert_eq!(flavors.len(), 1);
+    assert_eq!(flavors[0].name, "m1.small");
+}
+
+#[tokio::test]
+async fn test_list_images_applies_options_to_project_and_shared_images() {
+    let server = MockServer::start().await;
+    let image = |id: u128| serde_json::json!({"data": {"config": {"id": Uuid::from_u128(id)}}});
+    Mock::given(method("GET"))
+        .and(path("/compute/api/v1/projects/test-project/images"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "list": [image(1), image(2)],
+            "meta": {"total_count": 2}
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/compute/api/v1/projects/test-project/shared-images"))
+        .and(query_param("include", "total_count"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "list": [image(3)],
+            "meta": {"total_count": 1}
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    let images = compute
+        .list_images(ListOptions::new().limit(2).page(2), true)
+        .await
+        .unwrap();
+    let ids: Vec<Uuid> = images.iter().map(|i| Uuid::from(i.id)).collect();
+    assert_eq!(ids, vec![Uuid::from_u128(3)]);
+}
+
+#[tokio::test]
+async fn test_list_router_interfaces_honours_limit() {
+    let server = MockServer::start().await;
+    let router_id = Uuid::from_u128(9);
+    let interfaces: Vec<_> = (1..=3u128)
+        .map(|i| serde_json::json!({"id": Uuid::from_u128(i), "ip_address": format!("10.0.0.{}", i)}))
+        .collect();
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/vpc/api/v1/projects/test-project/routers/{}/interfaces",
+            router_id
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(interfaces))
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    let interfaces = compute
+        .list_router_interfaces(router_id.into(), ListOptions::new().limit(2))
+        .await
+        .unwrap();
+    let ips: Vec<_> = interfaces.iter().map(|i| i.ip_address.as_str()).collect();
+    assert_eq!(ips, vec!["10.0.0.1", "10.0.0.2"]);
+}
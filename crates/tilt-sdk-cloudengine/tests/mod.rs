@@ -1,4 +1,26 @@
 //! Tests for tilt-sdk-cloudengine crate
 
+mod backups;
+mod common;
+mod floating_ips;
+mod ids;
+mod image_upload;
+mod list_options;
+mod mutation_outcome;
+mod operations;
+mod owned_client;
 mod paginate;
+mod placement_policies;
 mod ports;
+mod prefetch;
+mod queries;
+mod resources;
+mod route_tables;
+mod routers;
+mod security_groups;
+mod snapshots;
+mod ssh_keys;
+mod subnets;
+mod tasks;
+mod vips;
+mod waiters;
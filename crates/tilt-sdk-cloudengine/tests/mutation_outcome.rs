@@ -0,0 +1,161 @@
+//! Tests for typed delete results
+
+use crate::common::client;
+use tilt_sdk_cloudengine::{ComputeClient, DeleteResponse, MutationOutcome, TaskStatus};
+use uuid::Uuid;
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn task_json(status: &str) -> serde_json::Value {
+    serde_json::json!({
+        "data": {
+            "id": "00000000-0000-0000-0000-000000000001",
+            "object_type": "public_ip",
+            "object_id": "00000000-0000-0000-0000-000000000002",
+            "initiator": "user@example.com",
+            "status": status,
+            "created": "2024-01-01T00:00:00Z"
+        }
+    })
+}
+
+#[test]
+fn test_mutation_outcome_parse() {
+    for expected in [DeleteResponse::Empty, DeleteResponse::Task] {
+        assert_eq!(
+            MutationOutcome::parse(expected, serde_json::Value::Null).unwrap(),
+            MutationOutcome::NoContent
+        );
+        assert_eq!(
+            MutationOutcome::parse(expected, serde_json::json!({})).unwrap(),
+            MutationOutcome::NoContent
+        );
+    }
+    assert_eq!(
+        MutationOutcome::parse(
+            DeleteResponse::Empty,
+            serde_json::json!({"id": Uuid::nil(), "status": "deleted"})
+        )
+        .unwrap(),
+        MutationOutcome::Completed
+    );
+    assert_eq!(
+        MutationOutcome::parse(DeleteResponse::Empty, task_json("new")).unwrap(),
+        MutationOutcome::Completed
+    );
+
+    let outcome = MutationOutcome::parse(DeleteResponse::Task, task_json("new")).unwrap();
+    let handle = outcome.task().expect("task handle");
+    assert_eq!(handle.task_id, Uuid::from_u128(1));
+    assert_eq!(handle.object_id, Uuid::from_u128(2));
+    assert_eq!(handle.status, TaskStatus::New);
+
+    let bare = task_json("new")["data"].clone();
+    assert!(
+        MutationOutcome::parse(DeleteResponse::Task, bare)
+            .unwrap()
+            .is_accepted()
+    );
+}
+
+#[test]
+fn test_mutation_outcome_rejects_malformed_task() {
+    let malformed = serde_json::json!({
+        "data": {"id": "not-a-uuid", "object_type": "public_ip", "status": "new"}
+    });
+    assert!(MutationOutcome::parse(DeleteResponse::Task, malformed).is_err());
+}
+
+#[tokio::test]
+async fn test_delete_outcomes_per_endpoint() {
+    let server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(
+            "/vpc/api/v1/projects/test-project/subnets/00000000-0000-0000-0000-000000000003",
+        ))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path(
+            "/vpc/api/v1/projects/test-project/public-ip-addresses/00000000-0000-0000-0000-000000000002",
+        ))
+        .respond_with(ResponseTemplate::new(202).set_body_json(task_json("new")))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(
+            "/order-service/api/v1/projects/test-project/tasks/00000000-0000-0000-0000-000000000001",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(task_json("successful")))
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    let outcome = compute
+        .delete_subnet(Uuid::from_u128(3).into())
+        .await
+        .unwrap();
+    assert_eq!(outcome, MutationOutcome::NoContent);
+
+    let outcome = compute.delete_fip(Uuid::from_u128(2).into()).await.unwrap();
+    assert!(outcome.is_accepted());
+    let task = outcome
+        .wait(ComputeClient::new(&client))
+        .await
+        .unwrap()
+        .expect("completed task");
+    assert_eq!(task.status, TaskStatus::Successful);
+}
+
+#[tokio::test]
+async fn test_delete_fip_errors_on_malformed_task() {
+    let server = MockServer::start().await;
+    let endpoint = "/vpc/api/v1/projects/test-project/public-ip-addresses/00000000-0000-0000-0000-000000000002";
+    Mock::given(method("DELETE"))
+        .and(path(endpoint))
+        .respond_with(
+            ResponseTemplate::new(202)
+                .set_body_json(serde_json::json!({"data": {"id": "pending", "status": "new"}})),
+        )
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    let err = compute
+        .delete_fip(Uuid::from_u128(2).into())
+        .await
+        .unwrap_err();
+    assert_eq!(err.endpoint.as_deref(), Some(endpoint));
+    assert!(matches!(err.source, Some(tilt_sdk::SdkError::Json(_))));
+}
+
+#[tokio::test]
+async fn test_share_image_outcome() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/compute/api/v1/projects/test-project/shared-images"))
+        .and(body_json(serde_json::json!({
+            "image_id": Uuid::from_u128(5),
+            "projects": ["other-project"]
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({"status": "shared"})),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    let outcome = compute
+        .share_image(Uuid::from_u128(5).into(), &["other-project"])
+        .await
+        .unwrap();
+    assert_eq!(outcome, MutationOutcome::Completed);
+}
@@ -0,0 +1,181 @@
+//! Tests for async task polling
+
+use crate::common::client;
+use futures::StreamExt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tilt_sdk_cloudengine::operations::{
+    Operation, OperationError, OperationEvent, OperationOptions,
+};
+use tilt_sdk_cloudengine::{ComputeClient, Resource, TaskStatus};
+use uuid::Uuid;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const TASK_PATH: &str =
+    "/order-service/api/v1/projects/test-project/tasks/00000000-0000-0000-0000-000000000001";
+
+fn task_json(status: &str) -> serde_json::Value {
+    serde_json::json!({
+        "data": {
+            "id": "00000000-0000-0000-0000-000000000001",
+            "object_type": "placement_policy",
+            "object_id": "00000000-0000-0000-0000-000000000002",
+            "initiator": "user@example.com",
+            "status": status,
+            "created": "2024-01-01T00:00:00Z",
+            "error": if status == "failed" { serde_json::json!("quota exceeded") } else { serde_json::Value::Null }
+        }
+    })
+}
+
+fn fast_options() -> OperationOptions {
+    OperationOptions::new()
+        .poll_interval(Duration::from_millis(5))
+        .max_poll_interval(Duration::from_millis(20))
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_operation_polls_through_transient_errors_to_resource() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(TASK_PATH))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(TASK_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(task_json("running")))
+        .up_to_n_times(2)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(TASK_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(task_json("successful")))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(
+            "/api/v1/projects/test-project/placement-policies/00000000-0000-0000-0000-000000000002",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "item_id": "00000000-0000-0000-0000-000000000002",
+            "data": {"state": "on", "config": {"name": "spread", "policy_type": "anti-affinity"}}
+        })))
+        .mount(&server)
+        .await;
+
+    let resource = Operation::new(
+        Uuid::from_u128(1).into(),
+        ComputeClient::new(&client(&server)),
+    )
+    .with_options(fast_options())
+    .await
+    .unwrap();
+    match resource {
+        Resource::PlacementPolicy(policy) => assert_eq!(policy.name, "spread"),
+        other => panic!("unexpected resource: {:?}", other),
+    }
+
+    let failed = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(TASK_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(task_json("failed")))
+        .mount(&failed)
+        .await;
+    let err = Operation::new(
+        Uuid::from_u128(1).into(),
+        ComputeClient::new(&client(&failed)),
+    )
+    .with_options(fast_options())
+    .await
+    .err()
+    .unwrap();
+    assert!(matches!(err, OperationError::TaskFailed(ref m) if m == "quota exceeded"));
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_operation_timeout_cancellation_and_fatal_errors() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(TASK_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(task_json("running")))
+        .mount(&server)
+        .await;
+    let client = client(&server);
+
+    let err = Operation::new(Uuid::from_u128(1).into(), ComputeClient::new(&client))
+        .with_options(fast_options().timeout(Duration::from_millis(50)))
+        .await
+        .err()
+        .unwrap();
+    assert!(matches!(err, OperationError::Timeout(_)));
+
+    let mut operation = Operation::new(Uuid::from_u128(1).into(), ComputeClient::new(&client))
+        .with_options(fast_options());
+    let cancel = operation.cancel_handle();
+    let canceller = async {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        cancel.cancel();
+    };
+    let (result, _) = tokio::join!(operation.wait(), canceller);
+    assert!(matches!(result, Err(OperationError::Cancelled)));
+
+    let missing = Operation::new(Uuid::from_u128(9).into(), ComputeClient::new(&client))
+        .with_options(fast_options())
+        .await
+        .err()
+        .unwrap();
+    assert!(matches!(missing, OperationError::PollFailed(_)));
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_operation_progress_reports_status_changes() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(TASK_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(task_json("new")))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(TASK_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(task_json("running")))
+        .up_to_n_times(3)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(TASK_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(task_json("failed")))
+        .mount(&server)
+        .await;
+    let client = client(&server);
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let recorder = Arc::clone(&seen);
+    let events: Vec<OperationEvent> =
+        Operation::new(Uuid::from_u128(1).into(), ComputeClient::new(&client))
+            .with_options(fast_options())
+            .on_progress(move |task| recorder.lock().unwrap().push(task.status.clone()))
+            .progress()
+            .collect()
+            .await;
+
+    let statuses: Vec<TaskStatus> = events
+        .iter()
+        .filter_map(|e| match e {
+            OperationEvent::Progress(task) => Some(task.status.clone()),
+            OperationEvent::Finished(_) => None,
+        })
+        .collect();
+    assert_eq!(
+        statuses,
+        vec![TaskStatus::New, TaskStatus::Running, TaskStatus::Failed]
+    );
+    assert_eq!(*seen.lock().unwrap(), statuses);
+    assert!(matches!(
+        events.last(),
+        Some(OperationEvent::Finished(Err(OperationError::TaskFailed(_))))
+    ));
+}
@@ -0,0 +1,82 @@
+//! Tests for owned clients shared across spawned tasks
+
+use crate::common::client;
+use std::future::IntoFuture;
+use std::time::Duration;
+use tilt_sdk_cloudengine::client::{compute, network, portal, storage};
+use tilt_sdk_cloudengine::operations::{Operation, OperationOptions};
+use tilt_sdk_cloudengine::{ComputeClient, NetworkId, Resource};
+use uuid::Uuid;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn assert_shareable<T: Clone + Send + Sync + 'static>() {}
+
+fn assert_spawnable<T: Send + 'static>() {}
+
+#[test]
+fn test_clients_are_clone_send_sync_static() {
+    assert_shareable::<tilt_sdk::Client>();
+    assert_shareable::<ComputeClient>();
+    assert_shareable::<compute::ComputeClient>();
+    assert_shareable::<network::NetworkClient>();
+    assert_shareable::<storage::StorageClient>();
+    assert_shareable::<portal::PortalClient>();
+    assert_spawnable::<Operation>();
+}
+
+#[tokio::test]
+async fn test_cloned_client_and_operation_run_in_spawned_tasks() {
+    let server = MockServer::start().await;
+    let network_id = Uuid::from_u128(2);
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/vpc/api/v1/projects/test-project/networks/{}",
+            network_id
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": network_id,
+            "name": "shared",
+            "status": "ACTIVE"
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/order-service/api/v1/projects/test-project/tasks/{}",
+            Uuid::from_u128(1)
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": {
+                "id": Uuid::from_u128(1),
+                "object_type": "network",
+                "object_id": network_id,
+                "status": "successful"
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let compute = ComputeClient::new(&client(&server));
+
+    let lookups: Vec<_> = (0..3)
+        .map(|_| {
+            let network = compute.network().clone();
+            tokio::spawn(async move { network.get_network(NetworkId::new(network_id)).await })
+        })
+        .collect();
+    for lookup in lookups {
+        assert_eq!(lookup.await.unwrap().unwrap().name, "shared");
+    }
+
+    let operation = Operation::new(Uuid::from_u128(1).into(), compute.clone())
+        .with_options(OperationOptions::new().poll_interval(Duration::from_millis(5)));
+    let resource = tokio::spawn(operation.into_future())
+        .await
+        .unwrap()
+        .unwrap();
+    match resource {
+        Resource::Network(network) => assert_eq!(network.id, network_id),
+        other => panic!("unexpected resource: {:?}", other),
+    }
+}
@@ -1,6 +1,8 @@
 //! Tests for pagination module
 
-use tilt_sdk_cloudengine::client::paginate::paginate;
+use futures::{StreamExt, TryStreamExt};
+use std::cell::Cell;
+use tilt_sdk_cloudengine::client::paginate::{paginate, paginate_stream};
 use tilt_sdk_cloudengine::error::Service;
 
 #[tokio::test]
@@ -53,3 +55,80 @@ async fn test_pagination_without_limit_fetches_all() {
 
     assert_eq!(result, vec![1, 2, 3, 4, 5, 6]);
 }
+
+#[tokio::test]
+async fn test_paginate_stream_stops_fetching_when_dropped_early() {
+    let fetches = Cell::new(0);
+
+    let result: Vec<u32> =
+        paginate_stream(None, None, Service::ComputeApi, "/test", |page, limit| {
+            fetches.set(fetches.get() + 1);
+            async move {
+                let start = (page - 1) * limit;
+                Ok(((start..start + limit).collect(), 1_000))
+            }
+        })
+        .take(3)
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(result, vec![0, 1, 2]);
+    assert_eq!(fetches.get(), 1);
+}
+
+#[tokio::test]
+async fn test_paginate_stream_has_no_page_cap() {
+    let result: Vec<u32> = paginate_stream(
+        None,
+        None,
+        Service::ComputeApi,
+        "/test",
+        |page, _limit| async move { Ok((vec![page], 250)) },
+    )
+    .try_collect()
+    .await
+    .unwrap();
+
+    assert_eq!(result.len(), 250);
+    assert_eq!(result.last(), Some(&250));
+}
+
+#[tokio::test]
+async fn test_paginate_stream_without_total_stops_at_short_page() {
+    let fetches = Cell::new(0);
+
+    let result: Vec<u32> = paginate_stream(None, None, Service::VpcApi, "/test", |page, limit| {
+        fetches.set(fetches.get() + 1);
+        async move {
+            let start = (page - 1) * limit;
+            let end = (start + limit).min(150);
+            Ok(((start..end).collect(), 0))
+        }
+    })
+    .try_collect()
+    .await
+    .unwrap();
+
+    assert_eq!(result.len(), 150);
+    assert_eq!(fetches.get(), 2);
+}
+
+#[tokio::test]
+async fn test_page_without_limit_uses_default_page_size() {
+    let result = paginate(
+        None,
+        Some(2),
+        Service::ComputeApi,
+        "/test",
+        |page, limit| async move {
+            let start = (page - 1) * limit;
+            Ok(((start..start + limit).collect::<Vec<u32>>(), 1_000))
+        },
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.len(), 100);
+    assert_eq!(result.first(), Some(&100));
+}
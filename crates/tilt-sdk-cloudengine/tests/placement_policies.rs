@@ -0,0 +1,125 @@
+//! Tests for placement policy responses and requests
+
+use crate::common::client;
+use tilt_sdk_cloudengine::client::responses::PlacementPolicyWrapper;
+use tilt_sdk_cloudengine::client::{CreatePlacementPolicyRequest, ListOptions};
+use tilt_sdk_cloudengine::{ComputeClient, PlacementPolicy, PlacementPolicyType};
+use uuid::Uuid;
+use wiremock::matchers::{body_partial_json, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const POLICY_JSON: &str = r#"{
+    "item_id": "00000000-0000-0000-0000-000000000001",
+    "created_row_dt": "2024-01-01T00:00:00Z",
+    "data": {
+        "state": "on",
+        "config": {
+            "name": "db-spread",
+            "policy_type": "soft-anti-affinity",
+            "availability_zone": {
+                "id": "az-1",
+                "name": "ru-central2-a",
+                "description": ""
+            }
+        }
+    }
+}"#;
+
+#[test]
+fn test_placement_policy_wrapper_parses_policy_type() {
+    let wrapper: PlacementPolicyWrapper =
+        serde_json::from_str(POLICY_JSON).expect("Failed to deserialize");
+    let policy: PlacementPolicy = wrapper.into();
+
+    assert_eq!(policy.policy_type, PlacementPolicyType::SoftAntiAffinity);
+    assert!(policy.policy_type.is_soft());
+    assert!(policy.policy_type.is_anti_affinity());
+    assert_eq!(policy.availability_zone.as_deref(), Some("ru-central2-a"));
+
+    let unknown: PlacementPolicyWrapper = serde_json::from_str(
+        r#"{"data": {"state": "on", "config": {"name": "legacy", "policy_type": null}}}"#,
+    )
+    .expect("Failed to deserialize");
+    let unknown: PlacementPolicy = unknown.into();
+    assert_eq!(unknown.policy_type, PlacementPolicyType::Unknown);
+}
+
+#[tokio::test]
+async fn test_create_placement_policy() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(
+            "/compute/api/v1/projects/test-project/placement-policies",
+        ))
+        .and(body_partial_json(serde_json::json!({
+            "name": "db-spread",
+            "policy_type": "soft-anti-affinity",
+            "availability_zone": "ru-central2-a"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_string(POLICY_JSON))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    let policy = compute
+        .create_placement_policy(CreatePlacementPolicyRequest {
+            name: "db-spread".to_string(),
+            policy_type: PlacementPolicyType::SoftAntiAffinity,
+            availability_zone: Some("ru-central2-a".to_string()),
+        })
+        .await
+        .unwrap();
+    assert_eq!(policy.id, Uuid::from_u128(1));
+
+    let err = compute
+        .create_placement_policy(CreatePlacementPolicyRequest {
+            name: "db-spread".to_string(),
+            policy_type: PlacementPolicyType::Unknown,
+            availability_zone: None,
+        })
+        .await
+        .err()
+        .unwrap();
+    assert!(err.message.contains("type"));
+}
+
+#[tokio::test]
+async fn test_list_placement_policy_instances_paginates() {
+    let server = MockServer::start().await;
+    let policy_id = Uuid::from_u128(1);
+    let instances_path = format!(
+        "/compute/api/v1/projects/test-project/placement-policies/{}/instances",
+        policy_id
+    );
+    for page in 1..=2u128 {
+        Mock::given(method("GET"))
+            .and(path(instances_path.as_str()))
+            .and(query_param("page", page.to_string()))
+            .and(query_param("per_page", "100"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "list": (0..2u128)
+                    .map(|i| serde_json::json!({
+                        "item_id": Uuid::from_u128(page * 10 + i),
+                        "data": {"config": {"id": Uuid::from_u128(page * 10 + i), "name": "vm"}}
+                    }))
+                    .collect::<Vec<_>>(),
+                "meta": {"total_count": 4}
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    let instances = compute
+        .list_placement_policy_instances(policy_id.into(), ListOptions::all())
+        .await
+        .unwrap();
+    let ids: Vec<Uuid> = instances.iter().map(|i| Uuid::from(i.id)).collect();
+    assert_eq!(ids, [10, 11, 20, 21].map(Uuid::from_u128));
+}
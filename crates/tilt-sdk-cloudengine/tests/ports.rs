@@ -1,7 +1,12 @@
 //! Tests for ports responses
 
+use crate::common::client;
+use tilt_sdk_cloudengine::ComputeClient;
 use tilt_sdk_cloudengine::client::responses::PortWrapper;
-use tilt_sdk_cloudengine::models::Ports;
+use tilt_sdk_cloudengine::client::{CreatePortRequest, ListOptions, PortFilter};
+use tilt_sdk_cloudengine::models::{NetworkItem, Ports};
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[test]
 fn test_port_state_deserialization() {
@@ -153,3 +158,141 @@ fn test_parse_single_port_with_parent_item() {
         assert_eq!(parent.type_field, "instance");
     }
 }
+
+#[tokio::test]
+async fn test_create_port_rejects_fixed_ip_outside_subnet() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/vpc/api/v1/projects/test-project/subnets"))
+        .and(query_param("network_id", "00000000-0000-0000-0000-000000000006"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+            "id": "00000000-0000-0000-0000-000000000005",
+            "name": "app",
+            "cidr": "10.0.0.0/24",
+            "enable_dhcp": true
+        }])))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    let err = compute
+        .create_port(CreatePortRequest {
+            name: "eth1".to_string(),
+            network_id: "00000000-0000-0000-0000-000000000006".parse().unwrap(),
+            subnet_id: "00000000-0000-0000-0000-000000000005".parse().unwrap(),
+            fixed_ip: Some("10.0.1.10".to_string()),
+            security_group_ids: vec![],
+        })
+        .await
+        .err()
+        .unwrap();
+
+    assert!(err.message.contains("not a usable address"));
+}
+
+#[tokio::test]
+async fn test_create_port_checks_subnet_network_without_fixed_ip() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/vpc/api/v1/projects/test-project/subnets"))
+        .and(query_param("network_id", "00000000-0000-0000-0000-000000000006"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let compute = ComputeClient::new(&client(&server));
+    let err = compute
+        .create_port(CreatePortRequest {
+            name: "eth1".to_string(),
+            network_id: "00000000-0000-0000-0000-000000000006".parse().unwrap(),
+            subnet_id: "00000000-0000-0000-0000-000000000005".parse().unwrap(),
+            fixed_ip: None,
+            security_group_ids: vec![],
+        })
+        .await
+        .err()
+        .unwrap();
+
+    assert!(err.message.contains("does not belong to network"));
+}
+
+#[tokio::test]
+async fn test_create_port_rejects_invalid_subnet_cidr() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/vpc/api/v1/projects/test-project/subnets"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+            "id": "00000000-0000-0000-0000-000000000005",
+            "name": "app",
+            "cidr": "10.0.0.0",
+            "enable_dhcp": true
+        }])))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let compute = ComputeClient::new(&client(&server));
+    let err = compute
+        .create_port(CreatePortRequest {
+            name: "eth1".to_string(),
+            network_id: "00000000-0000-0000-0000-000000000006".parse().unwrap(),
+            subnet_id: "00000000-0000-0000-0000-000000000005".parse().unwrap(),
+            fixed_ip: Some("10.0.0.10".to_string()),
+            security_group_ids: vec![],
+        })
+        .await
+        .err()
+        .unwrap();
+
+    assert!(err.message.contains("invalid CIDR"));
+}
+
+#[tokio::test]
+async fn test_instance_filter_keeps_nics_of_instance_ports() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/vpc/api/v1/projects/test-project/network-interfaces"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "list": [{
+                "item_id": "00000000-0000-0000-0000-000000000001",
+                "type": "nic",
+                "data": {
+                    "config": {"name": "eth0", "fixed_ips": []},
+                    "parent_item": {
+                        "item_id": "00000000-0000-0000-0000-000000000003",
+                        "type": "instance",
+                        "data": {"config": {"id": "00000000-0000-0000-0000-000000000003", "name": "vm"}}
+                    }
+                }
+            }],
+            "meta": {"total_count": 1}
+        })))
+        .mount(&server)
+        .await;
+
+    let compute = ComputeClient::new(&client(&server));
+    let items = compute
+        .list_ports(ListOptions::all(), PortFilter::Instance)
+        .await
+        .unwrap();
+
+    assert_eq!(items.len(), 2);
+    assert!(matches!(&items[0], NetworkItem::Nic(nic) if nic.name == "eth0"));
+    assert!(matches!(&items[1], NetworkItem::Instance(instance) if instance.name == "vm"));
+}
@@ -0,0 +1,141 @@
+//! Tests for concurrent page prefetching
+
+use futures::TryStreamExt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tilt_sdk::ClientBuilder;
+use tilt_sdk_cloudengine::ComputeClient;
+use tilt_sdk_cloudengine::client::paginate::paginate_stream_prefetch;
+use tilt_sdk_cloudengine::client::{ListOptions, PrefetchOptions, TaskFilter};
+use tilt_sdk_cloudengine::error::Service;
+use uuid::Uuid;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_prefetch_bounds_concurrency_and_keeps_order() {
+    let in_flight = Arc::new(AtomicUsize::new(0));
+    let max_in_flight = Arc::new(AtomicUsize::new(0));
+
+    let result: Vec<u32> = paginate_stream_prefetch(
+        PrefetchOptions::new().concurrency(2),
+        Service::ComputeApi,
+        "/test",
+        |page, _limit| {
+            let in_flight = in_flight.clone();
+            let max_in_flight = max_in_flight.clone();
+            async move {
+                let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                max_in_flight.fetch_max(current, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(u64::from(10 - page) * 5)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                Ok(((page * 100..page * 100 + 100).collect(), 600))
+            }
+        },
+    )
+    .try_collect()
+    .await
+    .unwrap();
+
+    assert_eq!(result.len(), 600);
+    assert!(result.windows(2).all(|w| w[0] < w[1]));
+    assert_eq!(max_in_flight.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_list_tasks_with_prefetch_fetches_remaining_pages() {
+    let server = MockServer::start().await;
+    let tasks_path = "/order-service/api/v1/projects/test-project/tasks";
+    for (page, delay) in [(1, 0), (2, 150), (3, 0)] {
+        let list: Vec<_> = (0..if page == 3 { 50 } else { 100 })
+            .map(|i| {
+                serde_json::json!({
+                    "id": Uuid::from_u128(page * 1_000 + i),
+                    "object_type": "instance",
+                    "object_id": "00000000-0000-0000-0000-0000000000aa",
+                    "status": "successful"
+                })
+            })
+            .collect();
+        Mock::given(method("GET"))
+            .and(path(tasks_path))
+            .and(query_param("page", page.to_string()))
+            .and(query_param("per_page", "100"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({
+                        "list": list,
+                        "meta": {"total_count": 250}
+                    }))
+                    .set_delay(Duration::from_millis(delay)),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    let client = ClientBuilder::new()
+        .base_url(&server.uri())
+        .token("token")
+        .project("test-project")
+        .rate_limit(1_000)
+        .build()
+        .unwrap();
+    assert_eq!(client.http().rate_limit(), Some(1_000));
+    let compute = ComputeClient::new(&client).with_prefetch(PrefetchOptions::new().concurrency(4));
+
+    let tasks = compute
+        .list_tasks(TaskFilter::new(), ListOptions::all())
+        .await
+        .unwrap();
+
+    assert_eq!(tasks.len(), 250);
+    assert_eq!(tasks[0].id, Uuid::from_u128(1_000));
+    assert_eq!(tasks[100].id, Uuid::from_u128(2_000));
+    assert_eq!(tasks[249].id, Uuid::from_u128(3_049));
+}
+
+#[tokio::test]
+async fn test_prefetch_sizes_pages_from_first_response() {
+    let requested = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let seen = requested.clone();
+
+    // The server caps pages at 50 items even though 100 were requested.
+    let result: Vec<u32> = paginate_stream_prefetch(
+        PrefetchOptions::new(),
+        Service::ComputeApi,
+        "/test",
+        move |page, _limit| {
+            seen.lock().unwrap().push(page);
+            async move {
+                let start = (page - 1) * 50;
+                Ok(((start..(start + 50).min(120)).collect(), 120))
+            }
+        },
+    )
+    .try_collect()
+    .await
+    .unwrap();
+
+    assert_eq!(result, (0..120).collect::<Vec<_>>());
+    assert_eq!(*requested.lock().unwrap(), vec![1, 2, 3]);
+}
+
+#[tokio::test]
+async fn test_prefetch_without_total_pages_until_short_page() {
+    let result: Vec<u32> = paginate_stream_prefetch(
+        PrefetchOptions::new(),
+        Service::VpcApi,
+        "/test",
+        |page, limit| async move {
+            let start = (page - 1) * limit;
+            Ok(((start..(start + limit).min(250)).collect(), 0))
+        },
+    )
+    .try_collect()
+    .await
+    .unwrap();
+
+    assert_eq!(result, (0..250).collect::<Vec<_>>());
+}
@@ -0,0 +1,189 @@
+//! Tests for typed resource queries
+
+use crate::common::client;
+use futures::TryStreamExt;
+use tilt_sdk_cloudengine::client::{
+    InstanceQuery, ListOptions, NetworkQuery, SortDirection, SortField, SubnetQuery,
+};
+use tilt_sdk_cloudengine::{ComputeClient, InstanceStatus};
+use uuid::Uuid;
+use wiremock::matchers::{method, path, query_param, query_param_is_missing};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn instance_json(id: u128, name: &str, status: &str) -> serde_json::Value {
+    serde_json::json!({
+        "item_id": Uuid::from_u128(id),
+        "data": {
+            "config": {
+                "id": Uuid::from_u128(id),
+                "name": name,
+                "status": status,
+                "flavor": {"id": Uuid::from_u128(99), "name": "b5.large.2"},
+                "availability_zone": {"id": "az-1", "name": "ru-central2-a"}
+            },
+            "state": status
+        },
+        "created_row_dt": "2026-01-13T19:33:45.133236"
+    })
+}
+
+#[tokio::test]
+async fn test_find_instances_filters_and_sorts_client_side() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(
+            "/order-service/api/v1/projects/test-project/compute/instances",
+        ))
+        .and(query_param("page", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "list": [
+                instance_json(1, "web-1", "active"),
+                instance_json(2, "db-1", "active"),
+                instance_json(3, "web-2", "active"),
+                instance_json(4, "web-3", "stopped")
+            ],
+            "meta": {"total_count": 4}
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    let instances = compute
+        .find_instances(
+            InstanceQuery::new()
+                .name_contains("web")
+                .status_in([InstanceStatus::Active])
+                .sort_by(SortField::Name, SortDirection::Desc),
+            ListOptions::all(),
+        )
+        .await
+        .unwrap();
+
+    let names: Vec<_> = instances.iter().map(|i| i.name.as_str()).collect();
+    assert_eq!(names, vec!["web-2", "web-1"]);
+
+    let requests = server.received_requests().await.unwrap();
+    let keys: Vec<_> = requests[0]
+        .url
+        .query_pairs()
+        .map(|(k, _)| k.into_owned())
+        .collect();
+    assert_eq!(keys, vec!["include", "page", "per_page"]);
+}
+
+#[tokio::test]
+async fn test_find_instances_rejects_unsupported_or_paged_filters() {
+    let server = MockServer::start().await;
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    let err = compute
+        .find_instances(
+            InstanceQuery::new().network(Uuid::from_u128(7).into()),
+            ListOptions::all(),
+        )
+        .await
+        .unwrap_err();
+    assert!(err.message.contains("unsupported"));
+
+    let err = compute
+        .find_instances_stream(
+            InstanceQuery::new().sort_by(SortField::Name, SortDirection::Asc),
+            ListOptions::all(),
+        )
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap_err();
+    assert!(err.message.contains("sort"));
+
+    let err = compute
+        .find_instances(
+            InstanceQuery::new().name_contains("web"),
+            ListOptions::new().limit(10),
+        )
+        .await
+        .unwrap_err();
+    assert!(err.message.contains("page limit"));
+    assert!(server.received_requests().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_find_subnets_sends_network_and_rechecks_results() {
+    let server = MockServer::start().await;
+    let network_id = Uuid::from_u128(3);
+    Mock::given(method("GET"))
+        .and(path("/vpc/api/v1/projects/test-project/subnets"))
+        .and(query_param("network_id", network_id.to_string()))
+        .and(query_param_is_missing("per_page"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {
+                "id": Uuid::from_u128(1),
+                "name": "app",
+                "cidr": "10.0.0.0/24",
+                "network": {"id": network_id, "name": "net"}
+            },
+            {
+                "id": Uuid::from_u128(2),
+                "name": "stray",
+                "cidr": "10.1.0.0/24",
+                "network": {"id": Uuid::from_u128(4), "name": "other"}
+            }
+        ])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    let subnets = compute
+        .find_subnets(
+            SubnetQuery::new().network(network_id.into()),
+            ListOptions::new().limit(5),
+        )
+        .await
+        .unwrap();
+    let names: Vec<_> = subnets.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, vec!["app"]);
+}
+
+#[tokio::test]
+async fn test_find_networks_filters_and_sorts_client_side() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/vpc/api/v1/projects/test-project/networks"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {"id": Uuid::from_u128(1), "name": "prod-a", "status": "ACTIVE"},
+            {"id": Uuid::from_u128(2), "name": "dev-a", "status": "ACTIVE"},
+            {"id": Uuid::from_u128(3), "name": "prod-b", "status": "ACTIVE"}
+        ])))
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    let networks = compute
+        .find_networks(
+            NetworkQuery::new()
+                .name_contains("PROD")
+                .sort_by(SortField::Name, SortDirection::Desc),
+            ListOptions::all(),
+        )
+        .await
+        .unwrap();
+    let names: Vec<_> = networks.iter().map(|n| n.name.as_str()).collect();
+    assert_eq!(names, vec!["prod-b", "prod-a"]);
+
+    let err = compute
+        .find_networks(
+            NetworkQuery::new().availability_zone("ru-central2-a"),
+            ListOptions::all(),
+        )
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("availability zone"));
+}
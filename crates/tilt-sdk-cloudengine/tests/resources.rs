@@ -0,0 +1,163 @@
+//! Tests for the generic CloudResource API
+
+use crate::common::client;
+use serde::Deserialize;
+use tilt_sdk_cloudengine::client::{CloudResource, DeletableResource, ListOptions};
+use tilt_sdk_cloudengine::models::{Networks, Subnets, Volumes};
+use tilt_sdk_cloudengine::{ComputeClient, MutationOutcome, Service, VolumeId};
+use uuid::Uuid;
+use wiremock::matchers::{method, path, query_param, query_param_is_missing};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_generic_get_list_and_delete() {
+    let server = MockServer::start().await;
+    let volume_id = VolumeId::new(Uuid::from_u128(1));
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/order-service/api/v1/projects/test-project/compute/volumes/{}",
+            volume_id
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": {"config": {"id": volume_id, "name": "data", "size": 20}}
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/vpc/api/v1/projects/test-project/subnets"))
+        .and(query_param_is_missing("page"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+                "id": Uuid::from_u128(2),
+                "name": "app",
+                "cidr": "10.0.0.0/24",
+                "network": {"id": Uuid::from_u128(3), "name": "net"},
+                "region": {"id": "ru-central2", "name": "Moscow"}
+            }])),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path(format!(
+            "/vpc/api/v1/projects/test-project/networks/{}",
+            Uuid::from_u128(3)
+        )))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/vpc/api/v1/projects/test-project/networks/{}",
+            Uuid::from_u128(3)
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": Uuid::from_u128(3),
+            "name": "net",
+            "status": "ACTIVE"
+        })))
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    let volume = compute.get::<Volumes>(volume_id).await.unwrap();
+    assert_eq!(volume.name, "data");
+    assert_eq!(volume.size, 20);
+
+    let subnets = compute.list::<Subnets>(ListOptions::all()).await.unwrap();
+    assert_eq!(subnets.len(), 1);
+    assert_eq!(subnets[0].network_id, Uuid::from_u128(3));
+
+    compute
+        .delete::<Networks>(Uuid::from_u128(3).into())
+        .await
+        .unwrap();
+    let network = compute
+        .get::<Networks>(Uuid::from_u128(3).into())
+        .await
+        .unwrap();
+    assert_eq!(network.name, "net");
+}
+
+#[derive(Debug, Deserialize)]
+struct Bucket {
+    id: Uuid,
+    name: String,
+}
+
+impl CloudResource for Bucket {
+    type Id = Uuid;
+    type Wrapper = Bucket;
+    type ListItem = Bucket;
+
+    const SERVICE: Service = Service::OrderService;
+    const KIND: &'static str = "bucket";
+
+    fn collection_path(project: &str) -> String {
+        format!("/s3/api/v1/projects/{}/buckets", project)
+    }
+}
+
+impl DeletableResource for Bucket {}
+
+#[tokio::test]
+async fn test_custom_resource_only_needs_trait_impl() {
+    let server = MockServer::start().await;
+    let bucket_id = Uuid::from_u128(9);
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/s3/api/v1/projects/test-project/buckets/{}",
+            bucket_id
+        )))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({"id": bucket_id, "name": "logs"})),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/s3/api/v1/projects/test-project/buckets"))
+        .and(query_param("include", "total_count"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "list": [
+                {"id": Uuid::from_u128(9), "name": "logs"},
+                {"id": Uuid::from_u128(10), "name": "media"}
+            ],
+            "meta": {"total_count": 2}
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path(format!(
+            "/s3/api/v1/projects/test-project/buckets/{}",
+            bucket_id
+        )))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    let bucket = compute.get::<Bucket>(bucket_id).await.unwrap();
+    assert_eq!(bucket.id, bucket_id);
+    assert_eq!(bucket.name, "logs");
+
+    let names: Vec<_> = compute
+        .list::<Bucket>(ListOptions::all())
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|b| b.name)
+        .collect();
+    assert_eq!(names, vec!["logs", "media"]);
+
+    assert!(Bucket::matches_kind("Bucket"));
+    assert!(matches!(
+        compute.delete::<Bucket>(bucket_id).await.unwrap(),
+        MutationOutcome::NoContent
+    ));
+}
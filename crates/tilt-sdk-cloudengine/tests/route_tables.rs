@@ -0,0 +1,167 @@
+//! Tests for route table validation
+
+use crate::common::client;
+use tilt_sdk_cloudengine::ComputeClient;
+use tilt_sdk_cloudengine::client::network::validate_routes;
+use tilt_sdk_cloudengine::client::{Cidr, CreateRouteTableRequest, NextHopType, RouteSpec};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn subnets() -> Vec<Cidr> {
+    vec!["10.0.0.0/24".parse().unwrap()]
+}
+
+#[test]
+fn test_validate_routes_accepts_disjoint_prefixes() {
+    let routes = vec![
+        RouteSpec::new("192.168.0.0/24", "10.0.0.5", NextHopType::IpAddress),
+        RouteSpec::new("192.168.1.0/24", "10.0.0.6", NextHopType::IpAddress),
+    ];
+    assert!(validate_routes(&routes, &subnets()).is_ok());
+}
+
+#[test]
+fn test_validate_routes_rejects_conflicts() {
+    let duplicate = vec![
+        RouteSpec::new("192.168.0.0/24", "10.0.0.5", NextHopType::IpAddress),
+        RouteSpec::new("192.168.0.0/24", "10.0.0.6", NextHopType::IpAddress),
+    ];
+    assert!(
+        validate_routes(&duplicate, &subnets())
+            .unwrap_err()
+            .contains("duplicate")
+    );
+
+    let overlapping = vec![
+        RouteSpec::new("192.168.0.0/16", "10.0.0.5", NextHopType::IpAddress),
+        RouteSpec::new("192.168.4.0/24", "10.0.0.6", NextHopType::IpAddress),
+    ];
+    assert!(
+        validate_routes(&overlapping, &subnets())
+            .unwrap_err()
+            .contains("overlaps")
+    );
+
+    let outside = vec![RouteSpec::new(
+        "192.168.0.0/24",
+        "172.16.0.1",
+        NextHopType::IpAddress,
+    )];
+    assert!(
+        validate_routes(&outside, &subnets())
+            .unwrap_err()
+            .contains("outside")
+    );
+}
+
+#[test]
+fn test_validate_routes_rejects_ip_next_hop_without_networks() {
+    let routes = vec![RouteSpec::new(
+        "192.168.0.0/24",
+        "10.0.0.5",
+        NextHopType::IpAddress,
+    )];
+    assert!(
+        validate_routes(&routes, &[])
+            .unwrap_err()
+            .contains("without associated networks")
+    );
+    assert!(validate_routes(&[], &[]).is_ok());
+}
+
+#[test]
+fn test_validate_routes_rejects_malformed_ip_next_hop() {
+    let routes = vec![RouteSpec::new(
+        "192.168.0.0/24",
+        "gateway",
+        NextHopType::IpAddress,
+    )];
+    assert!(
+        validate_routes(&routes, &subnets())
+            .unwrap_err()
+            .contains("not an IP address")
+    );
+
+    let routes = vec![RouteSpec::new(
+        "192.168.0.0/24",
+        "eth1",
+        NextHopType::Interface,
+    )];
+    assert!(validate_routes(&routes, &[]).is_ok());
+}
+
+#[tokio::test]
+async fn test_disassociate_network_revalidates_remaining_routes() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(
+            "/vpc/api/v1/projects/test-project/network_route_tables/00000000-0000-0000-0000-000000000001",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "00000000-0000-0000-0000-000000000001",
+            "name": "main",
+            "status": "available",
+            "create_date": "2024-01-01T00:00:00Z",
+            "region": {"id": "00000000-0000-0000-0000-000000000009", "name": "ru-central2"},
+            "routes": [{"prefix": "192.168.0.0/24", "next_hop": "10.0.0.5", "next_hop_type": "ip_address"}],
+            "networks": [{
+                "id": "00000000-0000-0000-0000-000000000006",
+                "name": "app",
+                "status": "available",
+                "create_time": "2024-01-01T00:00:00Z"
+            }]
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let compute = ComputeClient::new(&client(&server));
+    let err = compute
+        .disassociate_network(
+            "00000000-0000-0000-0000-000000000001".parse().unwrap(),
+            "00000000-0000-0000-0000-000000000006".parse().unwrap(),
+        )
+        .await
+        .unwrap_err();
+
+    assert!(err.message.contains("without associated networks"));
+}
+
+#[tokio::test]
+async fn test_create_route_table_rejects_invalid_subnet_cidr() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/vpc/api/v1/projects/test-project/subnets"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+                "id": "00000000-0000-0000-0000-000000000005",
+                "name": "app",
+                "cidr": "not-a-cidr",
+                "enable_dhcp": true
+            }])),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let compute = ComputeClient::new(&client(&server));
+    let err = compute
+        .create_route_table(CreateRouteTableRequest {
+            name: "main".to_string(),
+            description: None,
+            routes: vec![],
+            network_ids: vec!["00000000-0000-0000-0000-000000000006".parse().unwrap()],
+        })
+        .await
+        .unwrap_err();
+
+    assert!(err.message.contains("invalid CIDR"));
+}
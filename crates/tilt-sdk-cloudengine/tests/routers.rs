@@ -0,0 +1,60 @@
+//! Tests for router responses and provisioning requests
+
+use crate::common::client;
+use tilt_sdk_cloudengine::ComputeClient;
+use tilt_sdk_cloudengine::client::responses::NetworkRouterInterfaceWrapper;
+use tilt_sdk_cloudengine::client::{AddRouterInterfaceRequest, CreateSnatRouterRequest};
+use tilt_sdk_cloudengine::models::NetworkRouterInterface;
+use uuid::Uuid;
+use wiremock::MockServer;
+
+#[test]
+fn test_network_router_interface_wrapper_to_model() {
+    let json = r#"{
+        "id": "00000000-0000-0000-0000-000000000001",
+        "router_id": "00000000-0000-0000-0000-000000000002",
+        "subnet_id": "00000000-0000-0000-0000-000000000003",
+        "port_id": "00000000-0000-0000-0000-000000000004",
+        "ip_address": "10.0.0.1",
+        "status": "active"
+    }"#;
+
+    let wrapper: NetworkRouterInterfaceWrapper =
+        serde_json::from_str(json).expect("Failed to deserialize");
+    let interface: NetworkRouterInterface = wrapper.into();
+
+    assert_eq!(interface.ip_address, "10.0.0.1");
+    assert_eq!(interface.subnet_id, Uuid::from_u128(3));
+    assert_eq!(interface.port_id, Some(Uuid::from_u128(4).into()));
+}
+
+#[tokio::test]
+async fn test_create_snat_router_rejects_zero_bandwidth() {
+    let server = MockServer::start().await;
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    let err = compute
+        .create_snat_router(CreateSnatRouterRequest {
+            name: "egress".to_string(),
+            availability_zone: "ru-central2-a".to_string(),
+            bandwidth: 0,
+            subnet_ids: vec![Uuid::nil().into()],
+        })
+        .await
+        .err()
+        .unwrap();
+
+    assert!(err.message.contains("bandwidth"));
+}
+
+#[test]
+fn test_add_router_interface_request_serializes_ip() {
+    let request = AddRouterInterfaceRequest {
+        subnet_id: Uuid::from_u128(3).into(),
+        ip_address: Some("10.0.0.1".parse().unwrap()),
+    };
+
+    let body = serde_json::to_value(&request).unwrap();
+    assert_eq!(body["ip_address"], "10.0.0.1");
+}
@@ -0,0 +1,97 @@
+//! Tests for security group rule validation and sync diffing
+
+use tilt_sdk_cloudengine::client::{EtherType, RuleDiff, RuleProtocol, SecurityGroupRuleSpec};
+use tilt_sdk_cloudengine::models::SecurityGroupRule;
+use uuid::Uuid;
+
+fn rule(id: u128, protocol: &str, port: Option<u32>, prefix: Option<&str>) -> SecurityGroupRule {
+    SecurityGroupRule {
+        id: Uuid::from_u128(id).into(),
+        security_group_id: Uuid::nil().into(),
+        direction: "ingress".to_string(),
+        protocol: protocol.to_string(),
+        ethertype: "IPv4".to_string(),
+        port_range_min: port,
+        port_range_max: port,
+        remote_ip_prefix: prefix.map(str::to_string),
+        remote_group_id: None,
+        description: None,
+        status: Some("active".to_string()),
+    }
+}
+
+#[test]
+fn test_rule_builder_validation() {
+    let ssh = SecurityGroupRuleSpec::ingress()
+        .protocol(RuleProtocol::Tcp)
+        .port(22)
+        .remote_ip_prefix("0.0.0.0/0")
+        .build()
+        .unwrap();
+    assert_eq!(ssh.port_range_min, Some(22));
+
+    let err = SecurityGroupRuleSpec::ingress()
+        .protocol(RuleProtocol::Icmp)
+        .port(22)
+        .build()
+        .unwrap_err();
+    assert!(err.contains("requires tcp or udp"));
+
+    assert!(SecurityGroupRuleSpec::ingress().port(22).build().is_err());
+    assert!(
+        SecurityGroupRuleSpec::ingress()
+            .protocol(RuleProtocol::Udp)
+            .port_range(100, 10)
+            .build()
+            .is_err()
+    );
+    assert!(
+        SecurityGroupRuleSpec::egress()
+            .remote_ip_prefix("10.0.0.0/8")
+            .remote_group(Uuid::nil().into())
+            .build()
+            .is_err()
+    );
+    assert!(
+        SecurityGroupRuleSpec::egress()
+            .ethertype(EtherType::IPv6)
+            .remote_ip_prefix("10.0.0.0/8")
+            .build()
+            .is_err()
+    );
+}
+
+#[test]
+fn test_rule_diff_is_minimal() {
+    let current = vec![
+        rule(1, "tcp", Some(22), Some("0.0.0.0/0")),
+        rule(2, "tcp", Some(80), Some("0.0.0.0/0")),
+        rule(3, "icmp", None, None),
+    ];
+    let desired = vec![
+        SecurityGroupRuleSpec::ingress()
+            .protocol(RuleProtocol::Tcp)
+            .port(22)
+            .remote_ip_prefix("0.0.0.0/0")
+            .description("ssh")
+            .build()
+            .unwrap(),
+        SecurityGroupRuleSpec::ingress()
+            .protocol(RuleProtocol::Tcp)
+            .port(443)
+            .remote_ip_prefix("0.0.0.0/0")
+            .build()
+            .unwrap(),
+        SecurityGroupRuleSpec::ingress()
+            .protocol(RuleProtocol::Icmp)
+            .build()
+            .unwrap(),
+    ];
+
+    let diff = RuleDiff::compute(&current, &desired);
+
+    assert_eq!(diff.to_add.len(), 1);
+    assert_eq!(diff.to_add[0].port_range_min, Some(443));
+    assert_eq!(diff.to_remove, vec![Uuid::from_u128(2)]);
+    assert!(RuleDiff::compute(&current[..1], &desired[..1]).is_empty());
+}
@@ -0,0 +1,143 @@
+//! Tests for snapshots responses
+
+use crate::common::client;
+use tilt_sdk_cloudengine::client::responses::SnapshotWrapper;
+use tilt_sdk_cloudengine::models::{SnapshotStatus, Snapshots};
+use tilt_sdk_cloudengine::{ComputeClient, Service};
+use uuid::Uuid;
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[test]
+fn test_snapshot_wrapper_to_snapshots_model() {
+    let json = r#"{
+        "item_id": "00000000-0000-0000-0000-000000000001",
+        "type": "snapshot",
+        "data": {
+            "state": "on",
+            "config": {
+                "id": "00000000-0000-0000-0000-000000000001",
+                "name": "nightly-data",
+                "size": 20,
+                "status": "available",
+                "volume_id": "00000000-0000-0000-0000-000000000002",
+                "created_at": "2026-01-13T19:33:45.133236",
+                "volume_type": {"id": "00000000-0000-0000-0000-000000000003", "name": "ssd"},
+                "availability_zone": {"id": "az-001", "name": "ru-central2-a"}
+            }
+        }
+    }"#;
+
+    let wrapper: SnapshotWrapper = serde_json::from_str(json).expect("Failed to deserialize");
+    let snapshot: Snapshots = wrapper.into();
+
+    assert_eq!(snapshot.name, "nightly-data");
+    assert_eq!(snapshot.status, SnapshotStatus::Available);
+    assert_eq!(snapshot.volume_type_name, Some("ssd".to_string()));
+    assert_eq!(snapshot.availability_zone, Some("ru-central2-a".to_string()));
+}
+
+#[test]
+fn test_snapshot_unknown_status() {
+    let json = r#"{
+        "item_id": "00000000-0000-0000-0000-000000000001",
+        "data": {"config": {"status": "backing-up"}}
+    }"#;
+
+    let wrapper: SnapshotWrapper = serde_json::from_str(json).expect("Failed to deserialize");
+    let snapshot: Snapshots = wrapper.into();
+
+    assert_eq!(snapshot.status, SnapshotStatus::Unknown);
+}
+
+fn snapshot_task(object_id: Uuid) -> serde_json::Value {
+    serde_json::json!({
+        "data": {
+            "id": Uuid::from_u128(9),
+            "object_type": "snapshot",
+            "object_id": object_id,
+            "status": "new"
+        }
+    })
+}
+
+#[tokio::test]
+async fn test_create_snapshot_posts_to_compute_api() {
+    let server = MockServer::start().await;
+    let volume_id = Uuid::from_u128(2);
+    Mock::given(method("POST"))
+        .and(path("/compute/api/v1/projects/test-project/snapshots"))
+        .and(body_json(serde_json::json!({
+            "volume_id": volume_id,
+            "name": "nightly-data",
+            "description": "before upgrade"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(snapshot_task(volume_id)))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let compute = ComputeClient::new(&client(&server));
+    let operation = compute
+        .create_snapshot(volume_id.into(), "nightly-data", Some("before upgrade"))
+        .await
+        .unwrap();
+    assert_eq!(operation.task_id(), Uuid::from_u128(9));
+}
+
+#[tokio::test]
+async fn test_restore_snapshot_errors_are_attributed_to_compute_api() {
+    let server = MockServer::start().await;
+    let snapshot_id = Uuid::from_u128(1);
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/compute/api/v1/projects/test-project/snapshots/{}/restore",
+            snapshot_id
+        )))
+        .and(body_json(serde_json::json!({"name": "restored"})))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let compute = ComputeClient::new(&client(&server));
+    let err = compute
+        .restore_volume_from_snapshot(snapshot_id.into(), "restored")
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err.service, Service::ComputeApi);
+}
+
+#[tokio::test]
+async fn test_delete_snapshot_uses_order_action() {
+    let server = MockServer::start().await;
+    let snapshot_id = Uuid::from_u128(1);
+    let order_id = Uuid::from_u128(3);
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/order-service/api/v1/projects/test-project/compute/snapshots/{}",
+            snapshot_id
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "item_id": snapshot_id,
+            "order_id": order_id
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path(format!(
+            "/order-service/api/v1/projects/test-project/orders/{}/actions/delete_snapshot",
+            order_id
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(snapshot_task(snapshot_id)))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let compute = ComputeClient::new(&client(&server));
+    let outcome = compute.delete_snapshot(snapshot_id.into()).await.unwrap();
+    let handle = outcome.task().expect("task handle");
+    assert_eq!(handle.object_id, snapshot_id);
+}
@@ -0,0 +1,110 @@
+//! Tests for SSH public key validation and fingerprinting
+
+use crate::common::client;
+use chrono::Utc;
+use tilt_sdk::SdkError;
+use tilt_sdk_cloudengine::client::SshKeyUpdate;
+use tilt_sdk_cloudengine::models::{SshKeyAlgorithm, SshKeys, SshPublicKey};
+use tilt_sdk_cloudengine::{ComputeClient, Service};
+use uuid::Uuid;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const ED25519: &str = "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAILuAhsAtX646ZYnwNy/DyV2s1rfLEj8h5VlecxFHlRiT ops@example";
+const ECDSA: &str = "ecdsa-sha2-nistp256 AAAAE2VjZHNhLXNoYTItbmlzdHAyNTYAAAAIbmlzdHAyNTYAAABBBNve4qQakFxPpssOlkH+VkvFYi4ONpJW0Kk6c4YpumYVTEIxszIY6B+ZEWN/0j7+z+vMGND+VKQTa0YzwzC/vMA=";
+
+#[test]
+fn test_parse_openssh_public_keys() {
+    let ed25519: SshPublicKey = ED25519.parse().unwrap();
+    assert_eq!(ed25519.algorithm, SshKeyAlgorithm::Ed25519);
+    assert_eq!(ed25519.comment.as_deref(), Some("ops@example"));
+    assert_eq!(ed25519.to_openssh(), ED25519);
+
+    let ecdsa: SshPublicKey = ECDSA.parse().unwrap();
+    assert_eq!(ecdsa.algorithm, SshKeyAlgorithm::EcdsaP256);
+    assert_eq!(ecdsa.comment, None);
+
+    assert!("ssh-dss AAAAB3NzaC1kc3M=".parse::<SshPublicKey>().is_err());
+    assert!("ssh-ed25519 not-base64!".parse::<SshPublicKey>().is_err());
+    assert!(
+        ED25519
+            .replace("ssh-ed25519", "ssh-rsa")
+            .parse::<SshPublicKey>()
+            .is_err()
+    );
+}
+
+#[test]
+fn test_ssh_keys_fingerprints_match_ssh_keygen() {
+    let keys = SshKeys {
+        id: Uuid::nil().into(),
+        name: "ops".to_string(),
+        login: "ops".to_string(),
+        public_keys: vec![ED25519.to_string(), ECDSA.to_string(), "garbage".to_string()],
+        created_at: Utc::now(),
+        updated_at: None,
+    };
+
+    assert_eq!(
+        keys.fingerprints(),
+        vec![
+            "SHA256:jQInyEOMsKjyuwI7g2I98K8u89V2uuir2DaQRqGrHO8".to_string(),
+            "SHA256:RSNYc4jzNmg2bzlaHi3gff9JywZBvyp9xdctdL/cxwg".to_string(),
+        ]
+    );
+    assert!(keys.has_fingerprint("SHA256:jQInyEOMsKjyuwI7g2I98K8u89V2uuir2DaQRqGrHO8"));
+}
+
+#[tokio::test]
+async fn test_import_ssh_key_reports_io_error_with_path() {
+    let server = MockServer::start().await;
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+    let missing = std::env::temp_dir().join(format!("missing-{}.pub", Uuid::new_v4()));
+
+    let err = compute
+        .import_ssh_key_from_file(&missing, "ops", "ops")
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err.source, Some(SdkError::Io(_))));
+    assert_eq!(err.service, Service::Local);
+    assert_eq!(err.endpoint, Some(missing.display().to_string()));
+}
+
+#[tokio::test]
+async fn test_update_ssh_key_rejects_unmatched_removal() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(
+            "/portal/api/v2/projects/test-project/ssh_keys/00000000-0000-0000-0000-000000000001",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "00000000-0000-0000-0000-000000000001",
+            "name": "ops",
+            "login": "ops",
+            "public_keys": [ED25519]
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("PATCH"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let compute = ComputeClient::new(&client(&server));
+    let err = compute
+        .update_ssh_key(
+            Uuid::from_u128(1).into(),
+            SshKeyUpdate {
+                name: None,
+                add_public_keys: vec![ECDSA.to_string()],
+                remove_public_keys: vec!["SHA256:unknown".to_string()],
+            },
+        )
+        .await
+        .unwrap_err();
+
+    assert!(err.message.contains("SHA256:unknown"));
+}
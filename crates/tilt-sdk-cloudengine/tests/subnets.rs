@@ -0,0 +1,61 @@
+//! Tests for subnet and CIDR validation
+
+use std::net::IpAddr;
+use tilt_sdk_cloudengine::client::{Cidr, CreateSubnetRequest};
+use tilt_sdk_cloudengine::models::{DhcpDns, DhcpSettings};
+use uuid::Uuid;
+
+fn request(cidr: &str, gateway: Option<&str>) -> CreateSubnetRequest {
+    CreateSubnetRequest {
+        network_id: Uuid::nil().into(),
+        name: "app".to_string(),
+        cidr: cidr.to_string(),
+        gateway_ip: gateway.map(str::to_string),
+        enable_dhcp: true,
+        description: None,
+        dhcp_settings: Some(DhcpSettings {
+            dns: DhcpDns {
+                method: "default".to_string(),
+                reverse_resolution: false,
+            },
+            domain_name: Some("internal.example".to_string()),
+            ntp_servers: vec!["10.0.0.2".to_string()],
+        }),
+    }
+}
+
+#[test]
+fn test_cidr_parsing_and_containment() {
+    let cidr: Cidr = "10.0.0.0/24".parse().unwrap();
+    assert!(cidr.contains(&"10.0.0.200".parse::<IpAddr>().unwrap()));
+    assert!(!cidr.contains(&"10.0.1.1".parse::<IpAddr>().unwrap()));
+    assert!(cidr.overlaps(&"10.0.0.128/25".parse().unwrap()));
+    assert!(!cidr.overlaps(&"10.0.1.0/24".parse().unwrap()));
+
+    assert!("10.0.0.5/24".parse::<Cidr>().is_err());
+    assert!("10.0.0.0/33".parse::<Cidr>().is_err());
+    assert!("10.0.0.0".parse::<Cidr>().is_err());
+    assert!("fd00::/64".parse::<Cidr>().is_ok());
+}
+
+#[test]
+fn test_create_subnet_request_validation() {
+    assert!(request("10.0.0.0/24", Some("10.0.0.1")).validate().is_ok());
+    assert!(request("10.0.0.0/24", None).validate().is_ok());
+
+    let err = request("10.0.0.0/24", Some("10.0.1.1"))
+        .validate()
+        .unwrap_err();
+    assert!(err.contains("not a usable address"));
+    assert!(
+        request("10.0.0.0/24", Some("10.0.0.255"))
+            .validate()
+            .is_err()
+    );
+    assert!(request("10.0.0.0/24", Some("fd00::1")).validate().is_err());
+    assert!(request("not-a-cidr", None).validate().is_err());
+
+    let mut bad_domain = request("10.0.0.0/24", None);
+    bad_domain.dhcp_settings.as_mut().unwrap().domain_name = Some("bad..domain".to_string());
+    assert!(bad_domain.validate().is_err());
+}
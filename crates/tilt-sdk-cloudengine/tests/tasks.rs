@@ -0,0 +1,162 @@
+//! Tests for task responses and filtered task listing
+
+use crate::common::client;
+use tilt_sdk_cloudengine::client::responses::tasks::TaskWrapper;
+use tilt_sdk_cloudengine::client::{ListOptions, TaskFilter};
+use tilt_sdk_cloudengine::{ComputeClient, TaskStatus, Tasks, Volumes};
+use uuid::Uuid;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn task_json(id: u128, status: &str) -> serde_json::Value {
+    serde_json::json!({
+        "id": Uuid::from_u128(id),
+        "object_type": "instance",
+        "object_id": "00000000-0000-0000-0000-0000000000aa",
+        "initiator": "ops@example.com",
+        "status": status,
+        "created": "2024-01-01T00:00:00Z",
+        "updated": "2024-01-01T00:01:30Z"
+    })
+}
+
+#[test]
+fn test_task_wrapper_keeps_error_duration_and_payloads() {
+    let mut json = task_json(1, "failed");
+    json["error"] = serde_json::json!({
+        "code": "QuotaExceeded",
+        "message": "not enough vCPU quota",
+        "details": {"requested": 8, "available": 4}
+    });
+    json["metadata"] = serde_json::json!({"flavor": "m1.large"});
+    json["result"] = serde_json::json!({"ok": false});
+
+    let wrapper: TaskWrapper = serde_json::from_value(json).expect("Failed to deserialize");
+    let task: Tasks = wrapper.into();
+
+    assert_eq!(task.duration_seconds, Some(90));
+    let error = task.error.expect("structured error");
+    assert_eq!(error.code.as_deref(), Some("QuotaExceeded"));
+    assert_eq!(error.message, "not enough vCPU quota");
+    assert_eq!(error.details.unwrap()["available"], 4);
+    assert_eq!(task.metadata.unwrap()["flavor"], "m1.large");
+    assert_eq!(task.result.unwrap()["ok"], false);
+
+    let mut json = task_json(2, "running");
+    json["created"] = serde_json::json!("not a date");
+    json["error"] = serde_json::json!("timed out");
+    let task: Tasks = serde_json::from_value::<TaskWrapper>(json).unwrap().into();
+    assert_eq!(task.created_at, None);
+    assert_eq!(task.duration_seconds, None);
+    assert_eq!(task.error.unwrap().to_string(), "timed out");
+}
+
+#[tokio::test]
+async fn test_list_tasks_applies_filter_and_paginates() {
+    let server = MockServer::start().await;
+    let tasks_path = "/order-service/api/v1/projects/test-project/tasks";
+    Mock::given(method("GET"))
+        .and(path(tasks_path))
+        .and(query_param("status", "failed"))
+        .and(query_param("object_type", "instance"))
+        .and(query_param("initiator", "ops@example.com"))
+        .and(query_param("page", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "list": [task_json(1, "failed")],
+            "meta": {"total_count": 2}
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(tasks_path))
+        .and(query_param("status", "failed"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "list": [task_json(2, "failed")],
+            "meta": {"total_count": 2}
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    let tasks = compute
+        .list_tasks(
+            TaskFilter::new()
+                .status(TaskStatus::Failed)
+                .object_type("instance")
+                .initiator("ops@example.com"),
+            ListOptions::all(),
+        )
+        .await
+        .unwrap();
+
+    let ids: Vec<Uuid> = tasks.iter().map(|t| t.id.into()).collect();
+    assert_eq!(ids, vec![Uuid::from_u128(1), Uuid::from_u128(2)]);
+}
+
+#[tokio::test]
+async fn test_list_tasks_filters_by_typed_object() {
+    let server = MockServer::start().await;
+    let volume_id = Uuid::from_u128(5);
+    let mut volume_task = task_json(1, "successful");
+    volume_task["object_type"] = serde_json::json!("volume");
+    volume_task["object_id"] = serde_json::json!(volume_id);
+    Mock::given(method("GET"))
+        .and(path("/order-service/api/v1/projects/test-project/tasks"))
+        .and(query_param("object_type", "volume"))
+        .and(query_param("object_id", volume_id.to_string()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "list": [volume_task, task_json(2, "successful")],
+            "meta": {"total_count": 2}
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    let tasks = compute
+        .list_tasks(
+            TaskFilter::new().object::<Volumes>(volume_id.into()),
+            ListOptions::all(),
+        )
+        .await
+        .unwrap();
+    let ids: Vec<Uuid> = tasks.iter().map(|t| t.id.into()).collect();
+    assert_eq!(ids, vec![Uuid::from_u128(1)]);
+}
+
+#[tokio::test]
+async fn test_list_tasks_rechecks_filters_the_server_ignored() {
+    let server = MockServer::start().await;
+    let mut other_initiator = task_json(3, "failed");
+    other_initiator["initiator"] = serde_json::json!("ci@example.com");
+    Mock::given(method("GET"))
+        .and(path("/order-service/api/v1/projects/test-project/tasks"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "list": [task_json(1, "failed"), task_json(2, "running"), other_initiator],
+            "meta": {"total_count": 3}
+        })))
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    let tasks = compute
+        .list_tasks(
+            TaskFilter::new()
+                .status(TaskStatus::Failed)
+                .initiator("ops@example.com"),
+            ListOptions::all(),
+        )
+        .await
+        .unwrap();
+    let ids: Vec<Uuid> = tasks.iter().map(|t| t.id.into()).collect();
+    assert_eq!(ids, vec![Uuid::from_u128(1)]);
+}
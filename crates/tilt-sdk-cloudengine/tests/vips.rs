@@ -0,0 +1,111 @@
+//! Tests for virtual IP requests
+
+use crate::common::client;
+use tilt_sdk_cloudengine::ComputeClient;
+use tilt_sdk_cloudengine::client::{CreateVipRequest, VipAddressMode};
+use uuid::Uuid;
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const VIP_ID: &str = "00000000-0000-0000-0000-000000000001";
+const ORDER_ID: &str = "00000000-0000-0000-0000-000000000002";
+const PORT_ID: &str = "00000000-0000-0000-0000-000000000003";
+
+fn request(fixed_ip: Option<&str>, address_mode: VipAddressMode) -> CreateVipRequest {
+    CreateVipRequest {
+        name: "keepalived".to_string(),
+        network_id: Uuid::nil().into(),
+        subnet_id: Uuid::nil().into(),
+        fixed_ip: fixed_ip.map(str::to_string),
+        l2_enabled: true,
+        address_mode,
+    }
+}
+
+#[test]
+fn test_create_vip_request_validation() {
+    assert!(
+        request(Some("10.0.0.10"), VipAddressMode::Static)
+            .validate()
+            .is_ok()
+    );
+    assert!(request(None, VipAddressMode::Dhcp).validate().is_ok());
+    assert!(request(None, VipAddressMode::Static).validate().is_err());
+    assert!(
+        request(Some("10.0.0"), VipAddressMode::Static)
+            .validate()
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_bind_vip_to_ports_sends_order_action() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/vpc/api/v1/projects/test-project/virtual-ip-addresses/{}",
+            VIP_ID
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "item_id": VIP_ID,
+            "order_id": ORDER_ID,
+            "type": "vip"
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path(format!(
+            "/order-service/api/v1/projects/test-project/orders/{}/actions/bind_ports",
+            ORDER_ID
+        )))
+        .and(body_partial_json(serde_json::json!({
+            "item_id": VIP_ID,
+            "order": {"attrs": {"port_ids": [PORT_ID]}}
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": {"id": "00000000-0000-0000-0000-000000000004", "status": "new"}
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    compute
+        .bind_vip_to_ports(
+            VIP_ID.parse().unwrap(),
+            &[PORT_ID.parse().unwrap()],
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_create_vip_rejects_fixed_ip_outside_subnet() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/vpc/api/v1/projects/test-project/subnets"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+            "id": Uuid::nil(),
+            "name": "app",
+            "cidr": "10.0.0.0/24",
+            "enable_dhcp": true
+        }])))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let compute = ComputeClient::new(&client(&server));
+    let err = compute
+        .create_vip(request(Some("10.0.1.10"), VipAddressMode::Static))
+        .await
+        .err()
+        .unwrap();
+
+    assert!(err.message.contains("not a usable address"));
+}
@@ -0,0 +1,95 @@
+//! Tests for resource status waiters
+
+use crate::common::client;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+use tilt_sdk_cloudengine::models::PlacementPolicy;
+use tilt_sdk_cloudengine::operations::{OperationError, WaitConfig, WaitStatus, wait_until};
+use tilt_sdk_cloudengine::{ComputeClient, ComputeError, Service};
+use uuid::Uuid;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn fast_config() -> WaitConfig {
+    WaitConfig::new()
+        .poll_interval(Duration::from_millis(5))
+        .timeout(Duration::from_secs(2))
+}
+
+#[tokio::test]
+async fn test_wait_until_ready_and_terminal_states() {
+    let calls = AtomicU32::new(0);
+    let value = wait_until(
+        || async { Ok::<_, ComputeError>(calls.fetch_add(1, Ordering::SeqCst) + 1) },
+        |n| WaitStatus::from(*n >= 3),
+        fast_config(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(value, 3);
+
+    let err = wait_until(
+        || async { Ok::<_, ComputeError>("error") },
+        |status| match *status {
+            "error" => WaitStatus::Failed("volume is in error state".to_string()),
+            _ => WaitStatus::Pending,
+        },
+        fast_config(),
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(err, OperationError::TerminalState(_)));
+
+    let err = wait_until(
+        || async {
+            Err::<(), _>(ComputeError::validation(
+                Service::ComputeApi,
+                None,
+                "bad request".to_string(),
+            ))
+        },
+        |_| WaitStatus::Ready,
+        fast_config(),
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(err, OperationError::PollFailed(_)));
+
+    let err = wait_until(
+        || async { Ok::<_, ComputeError>(()) },
+        |_| WaitStatus::Pending,
+        fast_config().timeout(Duration::from_millis(30)),
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(err, OperationError::Timeout(_)));
+}
+
+#[tokio::test]
+async fn test_wait_deleted_treats_not_found_as_success() {
+    let server = MockServer::start().await;
+    let policy_path =
+        "/api/v1/projects/test-project/placement-policies/00000000-0000-0000-0000-000000000001";
+    Mock::given(method("GET"))
+        .and(path(policy_path))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "item_id": "00000000-0000-0000-0000-000000000001",
+            "data": {"state": "deleting", "config": {"name": "spread"}}
+        })))
+        .up_to_n_times(2)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(policy_path))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let compute = ComputeClient::new(&client);
+
+    compute
+        .wait_deleted::<PlacementPolicy>(Uuid::from_u128(1).into(), fast_config())
+        .await
+        .unwrap();
+}
@@ -1,28 +1,34 @@
 use crate::{error::Result, http::ReqwestClient};
+use std::sync::Arc;
 use std::time::Duration;
 use url::Url;
 
+// Cloning shares the underlying connection pool and rate limiter.
 #[derive(Clone)]
 pub struct Client {
+    inner: Arc<ClientInner>,
+}
+
+struct ClientInner {
     http: ReqwestClient,
     project: String,
 }
 
 impl Client {
     pub fn http(&self) -> &ReqwestClient {
-        &self.http
+        &self.inner.http
     }
 
     pub fn project(&self) -> &str {
-        &self.project
+        &self.inner.project
     }
 
     pub fn base_url(&self) -> &Url {
-        self.http.base_url()
+        self.inner.http.base_url()
     }
 
     pub fn token(&self) -> Option<&str> {
-        self.http.token()
+        self.inner.http.token()
     }
 }
 
@@ -31,6 +37,7 @@ pub struct ClientBuilder {
     token: Option<String>,
     project: Option<String>,
     timeout: Option<Duration>,
+    rate_limit: Option<u32>,
 }
 
 impl ClientBuilder {
@@ -40,6 +47,7 @@ impl ClientBuilder {
             token: None,
             project: None,
             timeout: None,
+            rate_limit: None,
         }
     }
 
@@ -63,6 +71,11 @@ impl ClientBuilder {
         self
     }
 
+    pub fn rate_limit(mut self, requests_per_second: u32) -> Self {
+        self.rate_limit = Some(requests_per_second);
+        self
+    }
+
     pub fn build(self) -> Result<Client> {
         let base_url = self.base_url.unwrap_or_else(|| {
             Url::parse("https://api.t1.cloud").expect("Default base URL should be valid")
@@ -74,13 +87,19 @@ impl ClientBuilder {
             http = http.with_timeout(timeout);
         }
 
+        if let Some(rate_limit) = self.rate_limit {
+            http = http.with_rate_limit(rate_limit);
+        }
+
         let project = self
             .project
             .ok_or_else(|| crate::error::SdkError::Validation {
                 message: "project is required".to_string(),
             })?;
 
-        Ok(Client { http, project })
+        Ok(Client {
+            inner: Arc::new(ClientInner { http, project }),
+        })
     }
 
     pub fn build_from_env(self) -> Result<Client> {
@@ -103,6 +122,12 @@ impl ClientBuilder {
             client = client.timeout(Duration::from_secs(timeout));
         }
 
+        if let Ok(rate_limit_str) = std::env::var("TILT_RATE_LIMIT")
+            && let Ok(rate_limit) = rate_limit_str.parse()
+        {
+            client = client.rate_limit(rate_limit);
+        }
+
         client.build()
     }
 }
@@ -39,6 +39,16 @@ pub enum SdkError {
     Unexpected(#[from] UnexpectedError),
 }
 
+impl SdkError {
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            SdkError::Http(http) => http.status.is_server_error(),
+            SdkError::Io(_) | SdkError::Timeout { .. } | SdkError::RateLimited { .. } => true,
+            _ => false,
+        }
+    }
+}
+
 #[derive(Debug, Error)]
 pub struct HttpError {
     pub status: StatusCode,
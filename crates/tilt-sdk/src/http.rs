@@ -2,7 +2,10 @@ use crate::error::{HttpError, ProviderError, SdkError};
 use http::StatusCode;
 use reqwest::RequestBuilder;
 use serde::de::DeserializeOwned;
+use std::sync::Arc;
 use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
 use tracing::{Instrument, debug, info_span, warn};
 
 const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
@@ -27,12 +30,47 @@ fn configure_proxy(builder: reqwest::ClientBuilder) -> reqwest::ClientBuilder {
     builder
 }
 
+/// Spaces requests evenly so that at most `requests_per_second` start each second.
+#[derive(Debug)]
+pub struct RateLimiter {
+    requests_per_second: u32,
+    interval: Duration,
+    next_slot: Mutex<Instant>,
+}
+
+impl RateLimiter {
+    pub fn new(requests_per_second: u32) -> Self {
+        let requests_per_second = requests_per_second.max(1);
+        Self {
+            requests_per_second,
+            interval: Duration::from_secs(1) / requests_per_second,
+            next_slot: Mutex::new(Instant::now()),
+        }
+    }
+
+    pub fn requests_per_second(&self) -> u32 {
+        self.requests_per_second
+    }
+
+    /// Waits until the next free slot; slots are `1s / requests_per_second` apart.
+    pub async fn acquire(&self) {
+        let slot = {
+            let mut next_slot = self.next_slot.lock().await;
+            let slot = (*next_slot).max(Instant::now());
+            *next_slot = slot + self.interval;
+            slot
+        };
+        tokio::time::sleep_until(slot).await;
+    }
+}
+
 #[derive(Clone)]
 pub struct ReqwestClient {
     base_url: url::Url,
     token: Option<String>,
     inner: reqwest::Client,
     timeout: Duration,
+    rate_limiter: Option<Arc<RateLimiter>>,
 }
 
 impl ReqwestClient {
@@ -52,6 +90,7 @@ impl ReqwestClient {
             token: _token,
             inner,
             timeout: DEFAULT_TIMEOUT,
+            rate_limiter: None,
         }
     }
 
@@ -60,6 +99,17 @@ impl ReqwestClient {
         self
     }
 
+    pub fn with_rate_limit(mut self, requests_per_second: u32) -> Self {
+        self.rate_limiter = Some(Arc::new(RateLimiter::new(requests_per_second)));
+        self
+    }
+
+    pub fn rate_limit(&self) -> Option<u32> {
+        self.rate_limiter
+            .as_ref()
+            .map(|limiter| limiter.requests_per_second())
+    }
+
     pub fn base_url(&self) -> &url::Url {
         &self.base_url
     }
@@ -126,6 +176,49 @@ impl ReqwestClient {
             .await
     }
 
+    pub async fn put<B: serde::Serialize, T: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<T, SdkError> {
+        self.send_request::<B, T>(http::Method::PUT, path, Some(body), None)
+            .await
+    }
+
+    pub async fn patch<B: serde::Serialize, T: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<T, SdkError> {
+        self.send_request::<B, T>(http::Method::PATCH, path, Some(body), None)
+            .await
+    }
+
+    pub async fn put_bytes<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        headers: &[(&str, &str)],
+        body: Vec<u8>,
+    ) -> Result<T, SdkError> {
+        let span = info_span!(
+            "http_request",
+            method = %http::Method::PUT,
+            path = path,
+            bytes = body.len()
+        );
+        async move {
+            let mut builder = self
+                .build_request(http::Method::PUT, path, None)
+                .header(http::header::CONTENT_TYPE, "application/octet-stream");
+            for (name, value) in headers {
+                builder = builder.header(*name, *value);
+            }
+            self.execute(builder.body(body)).await
+        }
+        .instrument(span)
+        .await
+    }
+
     pub async fn delete<T: DeserializeOwned>(&self, path: &str) -> Result<T, SdkError> {
         self.send_request::<(), T>(http::Method::DELETE, path, None, None)
             .await
@@ -148,38 +241,49 @@ impl ReqwestClient {
                 builder
             };
 
-            match builder.send().await {
-                Ok(response) => {
-                    let status = response.status();
-
-                    if status.is_success() {
-                        if status == StatusCode::NO_CONTENT {
-                            let val: std::result::Result<T, serde_json::Error> =
-                                serde_json::from_value(serde_json::Value::Null);
-                            return val.map_err(SdkError::Json);
-                        }
-                        let text = response.text().await.map_err(|e| self.map_reqwest_error(e))?;
-                        debug!("Server response ({} bytes): {}", text.len(), text);
-                        let value: T = serde_json::from_str(&text).map_err(SdkError::Json)?;
-                        return Ok(value);
+            self.execute(builder).await
+        }
+        .instrument(span)
+        .await
+    }
+
+    async fn execute<T: DeserializeOwned>(&self, builder: RequestBuilder) -> Result<T, SdkError> {
+        if let Some(limiter) = &self.rate_limiter {
+            limiter.acquire().await;
+        }
+        match builder.send().await {
+            Ok(response) => {
+                let status = response.status();
+
+                if status.is_success() {
+                    if status == StatusCode::NO_CONTENT {
+                        let val: std::result::Result<T, serde_json::Error> =
+                            serde_json::from_value(serde_json::Value::Null);
+                        return val.map_err(SdkError::Json);
+                    }
+                    let text = response.text().await.map_err(|e| self.map_reqwest_error(e))?;
+                    debug!("Server response ({} bytes): {}", text.len(), text);
+                    if text.trim().is_empty() {
+                        return serde_json::from_value(serde_json::Value::Null)
+                            .map_err(SdkError::Json);
                     }
+                    let value: T = serde_json::from_str(&text).map_err(SdkError::Json)?;
+                    return Ok(value);
+                }
 
-                    let request_id = response
-                        .headers()
-                        .get("x-request-id")
-                        .and_then(|h| h.to_str().ok())
-                        .map(String::from);
+                let request_id = response
+                    .headers()
+                    .get("x-request-id")
+                    .and_then(|h| h.to_str().ok())
+                    .map(String::from);
 
-                    let body = response.text().await.ok();
+                let body = response.text().await.ok();
 
-                    debug!("Server error response: {:?}", body);
-                    self.handle_error::<T>(status, &body, request_id)
-                }
-                Err(e) => Err(self.map_reqwest_error(e)),
+                debug!("Server error response: {:?}", body);
+                self.handle_error::<T>(status, &body, request_id)
             }
+            Err(e) => Err(self.map_reqwest_error(e)),
         }
-        .instrument(span)
-        .await
     }
 
     fn handle_error<T>(
@@ -1,3 +1,4 @@
 //! Tests for tilt-core crate
 
 mod logging;
+mod rate_limit;
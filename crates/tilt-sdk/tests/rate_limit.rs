@@ -0,0 +1,63 @@
+//! Tests for request rate limiting
+
+use std::sync::Arc;
+use std::time::Duration;
+use tilt_sdk::http::RateLimiter;
+use tokio::time::Instant;
+
+#[tokio::test(start_paused = true)]
+async fn test_rate_limiter_spaces_sequential_requests() {
+    let limiter = RateLimiter::new(10);
+    let start = Instant::now();
+
+    let mut started = Vec::new();
+    for _ in 0..5 {
+        limiter.acquire().await;
+        started.push(start.elapsed());
+    }
+
+    let expected: Vec<_> = (0..5).map(|i| Duration::from_millis(i * 100)).collect();
+    assert_eq!(started, expected);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_rate_limiter_spaces_concurrent_requests() {
+    let limiter = Arc::new(RateLimiter::new(4));
+    let start = Instant::now();
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let limiter = limiter.clone();
+            tokio::spawn(async move {
+                limiter.acquire().await;
+                start.elapsed()
+            })
+        })
+        .collect();
+    let mut started = Vec::new();
+    for handle in handles {
+        started.push(handle.await.unwrap());
+    }
+    started.sort();
+
+    let expected: Vec<_> = (0..4).map(|i| Duration::from_millis(i * 250)).collect();
+    assert_eq!(started, expected);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_rate_limiter_does_not_bank_idle_time() {
+    let limiter = RateLimiter::new(2);
+    limiter.acquire().await;
+    tokio::time::sleep(Duration::from_secs(5)).await;
+
+    let start = Instant::now();
+    limiter.acquire().await;
+    assert_eq!(start.elapsed(), Duration::ZERO);
+    limiter.acquire().await;
+    assert_eq!(start.elapsed(), Duration::from_millis(500));
+}
+
+#[test]
+fn test_rate_limiter_clamps_zero_rate() {
+    assert_eq!(RateLimiter::new(0).requests_per_second(), 1);
+}
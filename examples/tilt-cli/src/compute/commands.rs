@@ -1,6 +1,7 @@
 use cloudengine::{
-    ComputeError, AvailabilityZone, Flavors, Images, Instances, PlacementPolicy, Regions, Service, Tasks,
+    ComputeError, AvailabilityZone, AvailabilityZoneId, Flavors, Images, Instances, PlacementPolicy, Regions, Service, Tasks,
 };
+use cloudengine::client::ListOptions;
 use tilt_sdk_cloudengine as cloudengine;
 
 use crate::output::{
@@ -9,38 +10,46 @@ use crate::output::{
 };
 
 pub async fn list_servers(
-    client: &cloudengine::ComputeClient<'_>,
+    client: &cloudengine::ComputeClient,
     limit: Option<u32>,
     page: Option<u32>,
 ) -> Result<Vec<Instances>, ComputeError> {
-    client.list_instances(limit, page).await
+    client.list_instances(ListOptions::paged(limit, page)).await
 }
 
 pub async fn list_flavors(
-    client: &cloudengine::ComputeClient<'_>,
+    client: &cloudengine::ComputeClient,
     limit: Option<u32>,
     page: Option<u32>,
     az: Option<&str>,
 ) -> Result<Vec<Flavors>, ComputeError> {
-    client.list_flavors(limit, page, az).await
+    let az = az.map(AvailabilityZoneId::from);
+    client.list_flavors(ListOptions::paged(limit, page), az.as_ref()).await
 }
 
 pub async fn list_project_images(
-    client: &cloudengine::ComputeClient<'_>,
+    client: &cloudengine::ComputeClient,
     limit: Option<u32>,
     page: Option<u32>,
 ) -> Result<Vec<Images>, ComputeError> {
-    client.list_project_images(limit, page).await
+    client.list_project_images(ListOptions::paged(limit, page)).await
 }
 
 pub async fn list_tasks(
-    client: &cloudengine::ComputeClient<'_>,
+    client: &cloudengine::ComputeClient,
+    limit: Option<u32>,
+    page: Option<u32>,
 ) -> Result<Vec<Tasks>, ComputeError> {
-    client.list_tasks().await
+    client
+        .list_tasks(
+            cloudengine::client::TaskFilter::new(),
+            ListOptions::paged(limit, page),
+        )
+        .await
 }
 
 pub async fn show_task(
-    client: &cloudengine::ComputeClient<'_>,
+    client: &cloudengine::ComputeClient,
     task_id: &str,
 ) -> Result<Tasks, ComputeError> {
     let id = task_id
@@ -50,19 +59,19 @@ pub async fn show_task(
 }
 
 pub async fn list_regions(
-    client: &cloudengine::ComputeClient<'_>,
+    client: &cloudengine::ComputeClient,
 ) -> Result<Vec<Regions>, ComputeError> {
-    client.list_regions().await
+    client.list_regions(ListOptions::all()).await
 }
 
 pub async fn list_availability_zones(
-    client: &cloudengine::ComputeClient<'_>,
+    client: &cloudengine::ComputeClient,
 ) -> Result<Vec<AvailabilityZone>, ComputeError> {
-    client.list_availability_zones().await
+    client.list_availability_zones(ListOptions::all()).await
 }
 
 pub async fn show_image(
-    client: &cloudengine::ComputeClient<'_>,
+    client: &cloudengine::ComputeClient,
     image_id: &str,
 ) -> Result<Images, ComputeError> {
     let id = image_id.parse().map_err(|e| {
@@ -194,7 +203,7 @@ pub fn format_region_rows(regions: &[Regions]) -> String {
     let rows: Vec<RegionRow> = regions
         .iter()
         .map(|r| RegionRow {
-            id: r.id.clone(),
+            id: r.id.to_string(),
             name: r.name.clone(),
             description: format_opt_ref(&r.description),
         })
@@ -211,14 +220,17 @@ pub fn format_task_rows(tasks: &[Tasks], long: bool) -> String {
                     .duration_seconds
                     .map(|d| format!("{}s", d))
                     .unwrap_or_else(|| "-".to_string());
-                let error = format_opt(t.error.clone());
+                let error = format_opt(t.error.as_ref().map(|e| e.to_string()));
                 TaskRowDetail {
                     id: t.id.to_string(),
                     object_type: t.object_type.clone(),
                     object_id: t.object_id.to_string(),
                     status: format!("{:?}", t.status),
                     initiator: t.initiator.clone(),
-                    created_at: t.created_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+                    created_at: format_opt(
+                        t.created_at
+                            .map(|c| c.format("%Y-%m-%d %H:%M:%S").to_string()),
+                    ),
                     duration_s: duration,
                     error,
                 }
@@ -239,7 +251,10 @@ pub fn format_task_rows(tasks: &[Tasks], long: bool) -> String {
                     object_id: t.object_id.to_string(),
                     status: format!("{:?}", t.status),
                     initiator: t.initiator.clone(),
-                    created_at: t.created_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+                    created_at: format_opt(
+                        t.created_at
+                            .map(|c| c.format("%Y-%m-%d %H:%M:%S").to_string()),
+                    ),
                     duration_s: duration,
                 }
             })
@@ -249,9 +264,9 @@ pub fn format_task_rows(tasks: &[Tasks], long: bool) -> String {
 }
 
 pub async fn list_placement_policies(
-    client: &cloudengine::ComputeClient<'_>,
+    client: &cloudengine::ComputeClient,
 ) -> Result<Vec<PlacementPolicy>, ComputeError> {
-    client.list_placement_policies().await
+    client.list_placement_policies(ListOptions::all()).await
 }
 
 pub fn format_placement_rows(policies: &[PlacementPolicy]) -> String {
@@ -260,7 +275,7 @@ pub fn format_placement_rows(policies: &[PlacementPolicy]) -> String {
         .map(|p| PlacementRow {
             id: p.id.to_string(),
             name: p.name.clone(),
-            policy_type: format_opt_ref(&p.policy_type),
+            policy_type: p.policy_type.to_string(),
             availability_zone: format_opt_ref(&p.availability_zone),
         })
         .collect();
@@ -271,7 +286,7 @@ pub fn format_availability_zone_rows(zones: &[AvailabilityZone]) -> String {
     let rows: Vec<RegionRow> = zones
         .iter()
         .map(|z| RegionRow {
-            id: z.id.clone(),
+            id: z.id.to_string(),
             name: z.name.clone(),
             description: format_opt_ref(&z.description),
         })
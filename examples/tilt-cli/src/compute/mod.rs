@@ -129,7 +129,7 @@ pub struct PlacementListOpts {
 }
 
 pub async fn handle_server_action(
-    compute: &cloudengine::ComputeClient<'_>,
+    compute: &cloudengine::ComputeClient,
     action: ServerAction,
     format: Option<OutputFormat>,
 ) {
@@ -163,7 +163,7 @@ pub async fn handle_server_action(
 }
 
 pub async fn handle_flavor_action(
-    compute: &cloudengine::ComputeClient<'_>,
+    compute: &cloudengine::ComputeClient,
     action: FlavorAction,
     format: Option<OutputFormat>,
 ) {
@@ -202,7 +202,7 @@ pub async fn handle_flavor_action(
 }
 
 pub async fn handle_image_action(
-    compute: &cloudengine::ComputeClient<'_>,
+    compute: &cloudengine::ComputeClient,
     action: ImageAction,
     format: Option<OutputFormat>,
 ) {
@@ -251,12 +251,18 @@ pub async fn handle_image_action(
 }
 
 pub async fn handle_task_action(
-    compute: &cloudengine::ComputeClient<'_>,
+    compute: &cloudengine::ComputeClient,
     action: TaskAction,
     format: Option<OutputFormat>,
 ) {
     match action {
-        TaskAction::List { list_opts } => match commands::list_tasks(compute).await {
+        TaskAction::List { list_opts } => match commands::list_tasks(
+            compute,
+            list_opts.limit,
+            list_opts.page,
+        )
+        .await
+        {
             Ok(tasks) => {
                 let table = commands::format_task_rows(&tasks, list_opts.long);
                 match format.unwrap_or(OutputFormat::Table) {
@@ -303,7 +309,7 @@ pub async fn handle_task_action(
 }
 
 pub async fn handle_placement_action(
-    compute: &cloudengine::ComputeClient<'_>,
+    compute: &cloudengine::ComputeClient,
     action: PlacementAction,
     format: Option<OutputFormat>,
 ) {
@@ -348,7 +354,7 @@ pub struct AzListOpts {
 }
 
     pub async fn handle_az_action(
-    compute: &cloudengine::ComputeClient<'_>,
+    compute: &cloudengine::ComputeClient,
     action: AzAction,
     format: Option<OutputFormat>,
 ) {
@@ -1,20 +1,21 @@
 use cloudengine::client::portal::{CreateSshKeyRequest, SshKeyDetails};
-use cloudengine::{ComputeError, SshKeys};
+use cloudengine::{ComputeError, MutationOutcome, SshKeys};
+use cloudengine::client::ListOptions;
 use tilt_sdk_cloudengine as cloudengine;
 
 use crate::output::SshKeyRow;
 use crate::output::format_table;
 
 pub async fn list_ssh_keys(
-    client: &cloudengine::ComputeClient<'_>,
+    client: &cloudengine::ComputeClient,
     limit: Option<u32>,
     page: Option<u32>,
 ) -> Result<Vec<SshKeys>, ComputeError> {
-    client.list_ssh_keys(limit, page).await
+    client.list_ssh_keys(ListOptions::paged(limit, page)).await
 }
 
 pub async fn create_ssh_key(
-    client: &cloudengine::ComputeClient<'_>,
+    client: &cloudengine::ComputeClient,
     name: String,
     login: String,
     public_keys: Vec<String>,
@@ -30,9 +31,9 @@ pub async fn create_ssh_key(
 }
 
 pub async fn delete_ssh_key(
-    client: &cloudengine::ComputeClient<'_>,
+    client: &cloudengine::ComputeClient,
     ssh_key_id: &str,
-) -> Result<serde_json::Value, ComputeError> {
+) -> Result<MutationOutcome, ComputeError> {
     let id = ssh_key_id.parse().map_err(|_| {
         ComputeError::validation(
             cloudengine::Service::PortalApi,
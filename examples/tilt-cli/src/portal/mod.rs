@@ -54,7 +54,7 @@ pub struct SshKeyDeleteOpts {
 }
 
 pub async fn handle_ssh_key_action(
-    compute: &cloudengine::ComputeClient<'_>,
+    compute: &cloudengine::ComputeClient,
     action: SshKeyAction,
     format: Option<OutputFormat>,
 ) {
@@ -110,13 +110,13 @@ pub async fn handle_ssh_key_action(
         }
         SshKeyAction::Delete { delete_opts } => {
             match commands::delete_ssh_key(compute, &delete_opts.id).await {
-                Ok(json) => {
+                Ok(outcome) => {
                     match delete_opts.format.unwrap_or(OutputFormat::Table) {
                         OutputFormat::Table => {
                             println!("SSH key {} deleted successfully", delete_opts.id);
                         }
                         OutputFormat::Json => {
-                            println!("{}", json);
+                            println!("{}", serde_json::to_string_pretty(&outcome).unwrap());
                         }
                     }
                 }
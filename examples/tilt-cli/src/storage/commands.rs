@@ -1,4 +1,5 @@
 use cloudengine::{ComputeError, Service, Snapshots, Volumes};
+use cloudengine::client::ListOptions;
 use tilt_sdk_cloudengine as cloudengine;
 
 use crate::output::{
@@ -7,23 +8,23 @@ use crate::output::{
 use cloudengine::models::Backups;
 
 pub async fn list_volumes(
-    client: &cloudengine::ComputeClient<'_>,
+    client: &cloudengine::ComputeClient,
     limit: Option<u32>,
     page: Option<u32>,
 ) -> Result<Vec<Volumes>, ComputeError> {
-    client.list_volumes(limit, page).await
+    client.list_volumes(ListOptions::paged(limit, page)).await
 }
 
 pub async fn list_snapshots(
-    client: &cloudengine::ComputeClient<'_>,
+    client: &cloudengine::ComputeClient,
     limit: Option<u32>,
     page: Option<u32>,
 ) -> Result<Vec<Snapshots>, ComputeError> {
-    client.list_snapshots(limit, page).await
+    client.list_snapshots(ListOptions::paged(limit, page)).await
 }
 
 pub async fn show_volume(
-    client: &cloudengine::ComputeClient<'_>,
+    client: &cloudengine::ComputeClient,
     volume_id: &str,
 ) -> Result<Volumes, ComputeError> {
     let id = volume_id.parse().map_err(|e| {
@@ -99,7 +100,7 @@ pub fn format_snapshot_rows(snapshots: &[Snapshots]) -> String {
             id: s.id.to_string(),
             name: s.name.clone(),
             size: format!("{} GB", s.size),
-            status: s.status.clone(),
+            status: format!("{:?}", s.status),
             volume: format_opt(s.volume_name.clone().or(s.volume_type_name.clone())),
             created: format_date(&s.created_at),
         })
@@ -108,11 +109,11 @@ pub fn format_snapshot_rows(snapshots: &[Snapshots]) -> String {
 }
 
 pub async fn list_backups(
-    client: &cloudengine::ComputeClient<'_>,
+    client: &cloudengine::ComputeClient,
     limit: Option<u32>,
     page: Option<u32>,
 ) -> Result<Vec<Backups>, ComputeError> {
-    client.list_backups(limit, page).await
+    client.list_backups(ListOptions::paged(limit, page)).await
 }
 
 pub fn format_backup_rows(backups: &[Backups], long: bool) -> String {
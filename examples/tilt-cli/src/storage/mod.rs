@@ -79,7 +79,7 @@ pub struct BackupListOpts {
 }
 
 pub async fn handle_volume_action(
-    compute: &cloudengine::ComputeClient<'_>,
+    compute: &cloudengine::ComputeClient,
     action: VolumeAction,
     format: Option<OutputFormat>,
 ) {
@@ -128,7 +128,7 @@ pub async fn handle_volume_action(
 }
 
 pub async fn handle_snapshot_action(
-    compute: &cloudengine::ComputeClient<'_>,
+    compute: &cloudengine::ComputeClient,
     action: SnapshotAction,
     format: Option<OutputFormat>,
 ) {
@@ -164,7 +164,7 @@ pub async fn handle_snapshot_action(
 }
 
 pub async fn handle_backup_action(
-    compute: &cloudengine::ComputeClient<'_>,
+    compute: &cloudengine::ComputeClient,
     action: BackupAction,
     format: Option<OutputFormat>,
 ) {
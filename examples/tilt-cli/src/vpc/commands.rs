@@ -1,31 +1,41 @@
-use cloudengine::client::PortFilter;
+use cloudengine::client::{ListOptions, PortFilter};
 use cloudengine::models::{FloatingIps, NetworkItem, RouteTables, SecurityGroupRule, VirtualIps};
-use cloudengine::{ComputeError, Networks, SecurityGroups, Subnets};
+use cloudengine::{ComputeError, MutationOutcome, Networks, SecurityGroups, Subnets};
 use tilt_sdk_cloudengine as cloudengine;
 
 use crate::output::{
     FipRow, FipRowLong, InstanceRow, NetworkItemRow, NetworkRow, NetworkRowLong, NicRow, NetworkRouterRow, NetworkRouterRowLong,
     RouteTableRow, RouterRow, SecurityGroupRowLong, SecurityGroupRuleRow, SubnetRow, SubnetRowLong, VipRow,
-    VipRowLong, SecurityGroupRow, format_date, format_opt_ref, format_port_tree, format_router_tree, format_table,
+    VipRowLong, SecurityGroupRow, format_date, format_opt, format_opt_ref, format_port_tree, format_router_tree, format_table,
 };
 
 pub async fn list_networks(
-    client: &cloudengine::ComputeClient<'_>,
+    client: &cloudengine::ComputeClient,
 ) -> Result<Vec<Networks>, ComputeError> {
-    client.list_networks().await
+    client.list_networks(ListOptions::all()).await
 }
 
 pub async fn list_subnets(
-    client: &cloudengine::ComputeClient<'_>,
+    client: &cloudengine::ComputeClient,
     network_id: Option<&str>,
 ) -> Result<Vec<Subnets>, ComputeError> {
-    client.list_subnets(network_id).await
+    let network_id = match network_id {
+        Some(id) => Some(id.parse().map_err(|_| {
+            ComputeError::validation(
+                cloudengine::Service::VpcApi,
+                None,
+                format!("Invalid network ID: {}", id),
+            )
+        })?),
+        None => None,
+    };
+    client.list_subnets(ListOptions::all(), network_id).await
 }
 
 pub async fn delete_subnet(
-    client: &cloudengine::ComputeClient<'_>,
+    client: &cloudengine::ComputeClient,
     subnet_id: &str,
-) -> Result<serde_json::Value, ComputeError> {
+) -> Result<MutationOutcome, ComputeError> {
     let id = subnet_id.parse().map_err(|_| {
         ComputeError::validation(
             cloudengine::Service::VpcApi,
@@ -37,9 +47,9 @@ pub async fn delete_subnet(
 }
 
 pub async fn delete_network(
-    client: &cloudengine::ComputeClient<'_>,
+    client: &cloudengine::ComputeClient,
     network_id: &str,
-) -> Result<serde_json::Value, ComputeError> {
+) -> Result<MutationOutcome, ComputeError> {
     let id = network_id.parse().map_err(|_| {
         ComputeError::validation(
             cloudengine::Service::VpcApi,
@@ -51,9 +61,9 @@ pub async fn delete_network(
 }
 
 pub async fn delete_fip(
-    client: &cloudengine::ComputeClient<'_>,
+    client: &cloudengine::ComputeClient,
     fip_id: &str,
-) -> Result<serde_json::Value, ComputeError> {
+) -> Result<MutationOutcome, ComputeError> {
     let id = fip_id.parse().map_err(|_| {
         ComputeError::validation(
             cloudengine::Service::VpcApi,
@@ -65,9 +75,9 @@ pub async fn delete_fip(
 }
 
 pub async fn delete_vip(
-    client: &cloudengine::ComputeClient<'_>,
+    client: &cloudengine::ComputeClient,
     vip_id: &str,
-) -> Result<serde_json::Value, ComputeError> {
+) -> Result<MutationOutcome, ComputeError> {
     let id = vip_id.parse().map_err(|_| {
         ComputeError::validation(
             cloudengine::Service::VpcApi,
@@ -79,9 +89,9 @@ pub async fn delete_vip(
 }
 
 pub async fn delete_security_group(
-    client: &cloudengine::ComputeClient<'_>,
+    client: &cloudengine::ComputeClient,
     security_group_id: &str,
-) -> Result<serde_json::Value, ComputeError> {
+) -> Result<MutationOutcome, ComputeError> {
     let id = security_group_id.parse().map_err(|_| {
         ComputeError::validation(
             cloudengine::Service::VpcApi,
@@ -93,9 +103,9 @@ pub async fn delete_security_group(
 }
 
 pub async fn delete_route_table(
-    client: &cloudengine::ComputeClient<'_>,
+    client: &cloudengine::ComputeClient,
     route_table_id: &str,
-) -> Result<serde_json::Value, ComputeError> {
+) -> Result<MutationOutcome, ComputeError> {
     let id = route_table_id.parse().map_err(|_| {
         ComputeError::validation(
             cloudengine::Service::VpcApi,
@@ -107,7 +117,7 @@ pub async fn delete_route_table(
 }
 
 pub async fn list_ports(
-    client: &cloudengine::ComputeClient<'_>,
+    client: &cloudengine::ComputeClient,
     limit: Option<u32>,
     page: Option<u32>,
     filter: PortFilter,
@@ -116,13 +126,13 @@ pub async fn list_ports(
         PortFilter::Instance => (None, None),
         _ => (limit, page),
     };
-    client.list_ports(limit, page, filter).await
+    client.list_ports(ListOptions::paged(limit, page), filter).await
 }
 
 pub async fn delete_port(
-    client: &cloudengine::ComputeClient<'_>,
+    client: &cloudengine::ComputeClient,
     port_id: &str,
-) -> Result<serde_json::Value, ComputeError> {
+) -> Result<MutationOutcome, ComputeError> {
     let id = port_id.parse().map_err(|_| {
         ComputeError::validation(
             cloudengine::Service::VpcApi,
@@ -134,11 +144,11 @@ pub async fn delete_port(
 }
 
 pub async fn list_security_groups(
-    client: &cloudengine::ComputeClient<'_>,
+    client: &cloudengine::ComputeClient,
     limit: Option<u32>,
     page: Option<u32>,
 ) -> Result<Vec<SecurityGroups>, ComputeError> {
-    client.list_security_groups(limit, page).await
+    client.list_security_groups(ListOptions::paged(limit, page)).await
 }
 
 pub fn format_network_rows(networks: &[Networks], long: bool) -> String {
@@ -359,40 +369,40 @@ pub enum RoutersListResult {
 }
 
 pub async fn list_routers(
-    client: &cloudengine::ComputeClient<'_>,
+    client: &cloudengine::ComputeClient,
     r#type: crate::vpc::RouterType,
 ) -> Result<RoutersListResult, ComputeError> {
     match r#type {
         crate::vpc::RouterType::Snat => {
-            let snat_routers = client.list_routers().await?;
+            let snat_routers = client.list_routers(ListOptions::all()).await?;
             Ok(RoutersListResult::Snat(snat_routers))
         }
         crate::vpc::RouterType::Network => {
-            let network_routers = client.list_network_routers(None, None).await?;
+            let network_routers = client.list_network_routers(ListOptions::all()).await?;
             Ok(RoutersListResult::Network(network_routers))
         }
     }
 }
 
 pub async fn delete_router(
-    client: &cloudengine::ComputeClient<'_>,
+    client: &cloudengine::ComputeClient,
     router_id: &str,
     r#type: crate::vpc::RouterType,
-) -> Result<serde_json::Value, ComputeError> {
-    let id = router_id.parse().map_err(|_| {
+) -> Result<MutationOutcome, ComputeError> {
+    let invalid = |_| {
         ComputeError::validation(
             cloudengine::Service::VpcApi,
             None,
             format!("Invalid router ID: {}", router_id),
         )
-    })?;
+    };
 
     match r#type {
         crate::vpc::RouterType::Snat => {
-            client.delete_router(id).await
+            client.delete_router(router_id.parse().map_err(invalid)?).await
         }
         crate::vpc::RouterType::Network => {
-            client.delete_network_router(id).await
+            client.delete_network_router(router_id.parse().map_err(invalid)?).await
         }
     }
 }
@@ -451,11 +461,11 @@ pub fn format_router_rows(
 }
 
 pub async fn list_route_tables(
-    client: &cloudengine::ComputeClient<'_>,
+    client: &cloudengine::ComputeClient,
     limit: Option<u32>,
     page: Option<u32>,
 ) -> Result<Vec<RouteTables>, ComputeError> {
-    client.list_route_tables(limit, page).await
+    client.list_route_tables(ListOptions::paged(limit, page)).await
 }
 
 pub fn format_route_table_rows(tables: &[RouteTables]) -> String {
@@ -480,7 +490,7 @@ pub fn format_route_table_rows(tables: &[RouteTables]) -> String {
 }
 
 pub async fn show_security_group_rules(
-    client: &cloudengine::ComputeClient<'_>,
+    client: &cloudengine::ComputeClient,
     security_group_id: &str,
 ) -> Result<Vec<SecurityGroupRule>, ComputeError> {
     let id = security_group_id.parse().map_err(|_| {
@@ -490,7 +500,7 @@ pub async fn show_security_group_rules(
             format!("Invalid security group ID: {}", security_group_id),
         )
     })?;
-    client.list_security_group_rules(id).await
+    client.list_security_group_rules(id, ListOptions::all()).await
 }
 
 pub fn format_security_group_rules(rules: &[SecurityGroupRule]) -> String {
@@ -521,9 +531,9 @@ pub fn format_security_group_rules(rules: &[SecurityGroupRule]) -> String {
 }
 
 pub async fn list_vips(
-    client: &cloudengine::ComputeClient<'_>,
+    client: &cloudengine::ComputeClient,
 ) -> Result<Vec<VirtualIps>, ComputeError> {
-    client.list_vips().await
+    client.list_vips(ListOptions::all()).await
 }
 
 pub fn format_vip_rows(vips: &[VirtualIps], long: bool) -> String {
@@ -550,11 +560,11 @@ pub fn format_vip_rows(vips: &[VirtualIps], long: bool) -> String {
                         "{}\n└── subnet: {} ({})\n└── network: {} ({})\n└── region: {} ({})",
                         v.id,
                         format_opt_ref(&v.subnet_name),
-                        format_opt_ref(&v.subnet_id),
+                        format_opt(v.subnet_id.as_ref().map(ToString::to_string)),
                         format_opt_ref(&v.network_name),
-                        format_opt_ref(&v.network_id),
+                        format_opt(v.network_id.as_ref().map(ToString::to_string)),
                         format_opt_ref(&v.region_name),
-                        format_opt_ref(&v.region_id)
+                        format_opt(v.region_id.as_ref().map(ToString::to_string))
                     ),
                     name: v.name.clone(),
                     status: v.status.clone(),
@@ -597,9 +607,9 @@ pub fn format_vip_rows(vips: &[VirtualIps], long: bool) -> String {
 }
 
 pub async fn list_floating_ips(
-    client: &cloudengine::ComputeClient<'_>,
+    client: &cloudengine::ComputeClient,
 ) -> Result<Vec<FloatingIps>, ComputeError> {
-    client.list_floating_ips().await
+    client.list_floating_ips(ListOptions::all()).await
 }
 
 pub fn format_fip_rows(fips: &[FloatingIps], long: bool) -> String {
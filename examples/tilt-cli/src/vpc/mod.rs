@@ -301,7 +301,7 @@ pub struct FipDeleteOpts {
 }
 
 pub async fn handle_network_action(
-    compute: &cloudengine::ComputeClient<'_>,
+    compute: &cloudengine::ComputeClient,
     action: NetworkAction,
     format: Option<OutputFormat>,
 ) {
@@ -329,13 +329,13 @@ pub async fn handle_network_action(
         },
         NetworkAction::Delete { delete_opts } => {
             match commands::delete_network(compute, &delete_opts.network_id).await {
-                Ok(json) => {
+                Ok(outcome) => {
                     match delete_opts.format.unwrap_or(OutputFormat::Table) {
                         OutputFormat::Table => {
                             println!("Network {} deleted successfully", delete_opts.network_id);
                         }
                         OutputFormat::Json => {
-                            println!("{}", json);
+                            println!("{}", serde_json::to_string_pretty(&outcome).unwrap());
                         }
                     }
                 }
@@ -349,7 +349,7 @@ pub async fn handle_network_action(
 }
 
 pub async fn handle_subnet_action(
-    compute: &cloudengine::ComputeClient<'_>,
+    compute: &cloudengine::ComputeClient,
     action: SubnetAction,
     format: Option<OutputFormat>,
 ) {
@@ -379,13 +379,13 @@ pub async fn handle_subnet_action(
         }
         SubnetAction::Delete { delete_opts } => {
             match commands::delete_subnet(compute, &delete_opts.subnet_id).await {
-                Ok(json) => {
+                Ok(outcome) => {
                     match delete_opts.format.unwrap_or(OutputFormat::Table) {
                         OutputFormat::Table => {
                             println!("Subnet {} deleted successfully", delete_opts.subnet_id);
                         }
                         OutputFormat::Json => {
-                            println!("{}", json);
+                            println!("{}", serde_json::to_string_pretty(&outcome).unwrap());
                         }
                     }
                 }
@@ -399,7 +399,7 @@ pub async fn handle_subnet_action(
 }
 
 pub async fn handle_port_action(
-    compute: &cloudengine::ComputeClient<'_>,
+    compute: &cloudengine::ComputeClient,
     action: PortAction,
     format: Option<OutputFormat>,
 ) {
@@ -448,13 +448,13 @@ pub async fn handle_port_action(
         }
         PortAction::Delete { delete_opts } => {
             match commands::delete_port(compute, &delete_opts.port_id).await {
-                Ok(json) => {
+                Ok(outcome) => {
                     match delete_opts.format.unwrap_or(OutputFormat::Table) {
                         OutputFormat::Table => {
                             println!("Port {} deleted successfully", delete_opts.port_id);
                         }
                         OutputFormat::Json => {
-                            println!("{}", json);
+                            println!("{}", serde_json::to_string_pretty(&outcome).unwrap());
                         }
                     }
                 }
@@ -468,7 +468,7 @@ pub async fn handle_port_action(
 }
 
 pub async fn handle_security_group_action(
-    compute: &cloudengine::ComputeClient<'_>,
+    compute: &cloudengine::ComputeClient,
     action: SecurityGroupAction,
     format: Option<OutputFormat>,
 ) {
@@ -525,13 +525,13 @@ pub async fn handle_security_group_action(
         }
         SecurityGroupAction::Delete { delete_opts } => {
             match commands::delete_security_group(compute, &delete_opts.id).await {
-                Ok(json) => {
+                Ok(outcome) => {
                     match delete_opts.format.unwrap_or(OutputFormat::Table) {
                         OutputFormat::Table => {
                             println!("Security group {} deleted successfully", delete_opts.id);
                         }
                         OutputFormat::Json => {
-                            println!("{}", json);
+                            println!("{}", serde_json::to_string_pretty(&outcome).unwrap());
                         }
                     }
                 }
@@ -545,7 +545,7 @@ pub async fn handle_security_group_action(
 }
 
 pub async fn handle_region_action(
-    compute: &cloudengine::ComputeClient<'_>,
+    compute: &cloudengine::ComputeClient,
     action: RegionAction,
     format: Option<OutputFormat>,
 ) {
@@ -579,7 +579,7 @@ pub async fn handle_region_action(
 }
 
 pub async fn handle_router_action(
-    compute: &cloudengine::ComputeClient<'_>,
+    compute: &cloudengine::ComputeClient,
     action: RouterAction,
     format: Option<OutputFormat>,
 ) {
@@ -616,13 +616,13 @@ pub async fn handle_router_action(
         }
         RouterAction::Delete { delete_opts } => {
             match commands::delete_router(compute, &delete_opts.router_id, delete_opts.r#type).await {
-                Ok(json) => {
+                Ok(outcome) => {
                     match delete_opts.format.unwrap_or(OutputFormat::Table) {
                         OutputFormat::Table => {
                             println!("Router {} ({}) deleted successfully", delete_opts.router_id, delete_opts.r#type);
                         }
                         OutputFormat::Json => {
-                            println!("{}", json);
+                            println!("{}", serde_json::to_string_pretty(&outcome).unwrap());
                         }
                     }
                 }
@@ -636,7 +636,7 @@ pub async fn handle_router_action(
 }
 
 pub async fn handle_route_table_action(
-    compute: &cloudengine::ComputeClient<'_>,
+    compute: &cloudengine::ComputeClient,
     action: RouteTableAction,
     format: Option<OutputFormat>,
 ) {
@@ -670,13 +670,13 @@ pub async fn handle_route_table_action(
         }
         RouteTableAction::Delete { delete_opts } => {
             match commands::delete_route_table(compute, &delete_opts.id).await {
-                Ok(json) => {
+                Ok(outcome) => {
                     match delete_opts.format.unwrap_or(OutputFormat::Table) {
                         OutputFormat::Table => {
                             println!("Route table {} deleted successfully", delete_opts.id);
                         }
                         OutputFormat::Json => {
-                            println!("{}", json);
+                            println!("{}", serde_json::to_string_pretty(&outcome).unwrap());
                         }
                     }
                 }
@@ -690,7 +690,7 @@ pub async fn handle_route_table_action(
 }
 
 pub async fn handle_vip_action(
-    compute: &cloudengine::ComputeClient<'_>,
+    compute: &cloudengine::ComputeClient,
     action: VipAction,
     format: Option<OutputFormat>,
 ) {
@@ -715,13 +715,13 @@ pub async fn handle_vip_action(
         },
         VipAction::Delete { delete_opts } => {
             match commands::delete_vip(compute, &delete_opts.vip_id).await {
-                Ok(json) => {
+                Ok(outcome) => {
                     match delete_opts.format.unwrap_or(OutputFormat::Table) {
                         OutputFormat::Table => {
                             println!("VIP {} deleted successfully", delete_opts.vip_id);
                         }
                         OutputFormat::Json => {
-                            println!("{}", json);
+                            println!("{}", serde_json::to_string_pretty(&outcome).unwrap());
                         }
                     }
                 }
@@ -735,7 +735,7 @@ pub async fn handle_vip_action(
 }
 
 pub async fn handle_fip_action(
-    compute: &cloudengine::ComputeClient<'_>,
+    compute: &cloudengine::ComputeClient,
     action: FipAction,
     format: Option<OutputFormat>,
 ) {
@@ -760,13 +760,13 @@ pub async fn handle_fip_action(
         },
         FipAction::Delete { delete_opts } => {
             match commands::delete_fip(compute, &delete_opts.fip_id).await {
-                Ok(json) => {
+                Ok(outcome) => {
                     match delete_opts.format.unwrap_or(OutputFormat::Table) {
                         OutputFormat::Table => {
                             println!("Floating IP {} deleted successfully", delete_opts.fip_id);
                         }
                         OutputFormat::Json => {
-                            println!("{}", json);
+                            println!("{}", serde_json::to_string_pretty(&outcome).unwrap());
                         }
                     }
                 }